@@ -0,0 +1,162 @@
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+use rangetools::{BoundedSet, Rangetools, UnboundedSet};
+use std::hint::black_box;
+
+const SIZES: [i64; 3] = [1_000, 10_000, 100_000];
+
+/// A set of `n` ranges of width `width`, starting every `stride` values from `offset`.
+fn bounded_set(n: i64, offset: i64, stride: i64, width: i64) -> BoundedSet<i64> {
+    (0..n)
+        .map(|i| offset + i * stride..offset + i * stride + width)
+        .collect()
+}
+
+/// A pair of interleaved sets with `n` components each, which partially overlap.
+fn operands(n: i64) -> (BoundedSet<i64>, BoundedSet<i64>) {
+    (bounded_set(n, 0, 10, 4), bounded_set(n, 2, 10, 5))
+}
+
+fn unbounded_set(n: i64, offset: i64) -> UnboundedSet<i64> {
+    bounded_set(n, offset, 10, 4)
+        .union(..offset - 5)
+        .union(offset + n * 10 + 5..)
+}
+
+fn union(c: &mut Criterion) {
+    let mut group = c.benchmark_group("union");
+    for n in SIZES {
+        let (a, b) = operands(n);
+        group.bench_with_input(BenchmarkId::new("bounded_set", n), &n, |bench, _| {
+            bench.iter_batched(
+                || (a.clone(), b.clone()),
+                |(a, b)| black_box(a.union(b)),
+                BatchSize::LargeInput,
+            )
+        });
+        group.bench_with_input(BenchmarkId::new("borrowed", n), &n, |bench, _| {
+            bench.iter(|| black_box((&a).union(&b)))
+        });
+        let (l, u) = (a.clone().union(n * 10..), b.clone().union(..0));
+        group.bench_with_input(BenchmarkId::new("lower_upper", n), &n, |bench, _| {
+            bench.iter(|| black_box((&l).union(&u)))
+        });
+        let (x, y) = (unbounded_set(n, 0), unbounded_set(n, 2));
+        group.bench_with_input(BenchmarkId::new("unbounded_set", n), &n, |bench, _| {
+            bench.iter(|| black_box((&x).union(&y)))
+        });
+    }
+    group.finish();
+}
+
+fn intersection(c: &mut Criterion) {
+    let mut group = c.benchmark_group("intersection");
+    for n in SIZES {
+        let (a, b) = operands(n);
+        group.bench_with_input(BenchmarkId::new("bounded_set", n), &n, |bench, _| {
+            bench.iter_batched(
+                || (a.clone(), b.clone()),
+                |(a, b)| black_box(a.intersection(b)),
+                BatchSize::LargeInput,
+            )
+        });
+        group.bench_with_input(BenchmarkId::new("borrowed", n), &n, |bench, _| {
+            bench.iter(|| black_box((&a).intersection(&b)))
+        });
+        let (x, y) = (unbounded_set(n, 0), unbounded_set(n, 2));
+        group.bench_with_input(BenchmarkId::new("unbounded_set", n), &n, |bench, _| {
+            bench.iter(|| black_box((&x).intersection(&y)))
+        });
+    }
+    group.finish();
+}
+
+fn difference(c: &mut Criterion) {
+    let mut group = c.benchmark_group("difference");
+    for n in SIZES {
+        let (a, b) = operands(n);
+        group.bench_with_input(BenchmarkId::new("bounded_set", n), &n, |bench, _| {
+            bench.iter_batched(
+                || (a.clone(), b.clone()),
+                |(a, b)| black_box(a.difference(b)),
+                BatchSize::LargeInput,
+            )
+        });
+        let (x, y) = (unbounded_set(n, 0), unbounded_set(n, 2));
+        group.bench_with_input(BenchmarkId::new("unbounded_set", n), &n, |bench, _| {
+            bench.iter_batched(
+                || (x.clone(), y.clone()),
+                |(x, y)| black_box(x.difference(y)),
+                BatchSize::LargeInput,
+            )
+        });
+    }
+    group.finish();
+}
+
+fn symmetric_difference(c: &mut Criterion) {
+    let mut group = c.benchmark_group("symmetric_difference");
+    for n in SIZES {
+        let (a, b) = operands(n);
+        group.bench_with_input(BenchmarkId::new("bounded_set", n), &n, |bench, _| {
+            bench.iter_batched(
+                || (a.clone(), b.clone()),
+                |(a, b)| black_box(a.symmetric_difference(b)),
+                BatchSize::LargeInput,
+            )
+        });
+        let (x, y) = (unbounded_set(n, 0), unbounded_set(n, 2));
+        group.bench_with_input(BenchmarkId::new("unbounded_set", n), &n, |bench, _| {
+            bench.iter_batched(
+                || (x.clone(), y.clone()),
+                |(x, y)| black_box(x.symmetric_difference(y)),
+                BatchSize::LargeInput,
+            )
+        });
+    }
+    group.finish();
+}
+
+fn complement(c: &mut Criterion) {
+    let mut group = c.benchmark_group("complement");
+    for n in SIZES {
+        let a = bounded_set(n, 0, 10, 4);
+        group.bench_with_input(BenchmarkId::new("bounded_set", n), &n, |bench, _| {
+            bench.iter_batched(
+                || a.clone(),
+                |a| black_box(a.complement()),
+                BatchSize::LargeInput,
+            )
+        });
+        let x = unbounded_set(n, 0);
+        group.bench_with_input(BenchmarkId::new("unbounded_set", n), &n, |bench, _| {
+            bench.iter(|| black_box((&x).complement()))
+        });
+    }
+    group.finish();
+}
+
+fn contains(c: &mut Criterion) {
+    let mut group = c.benchmark_group("contains");
+    for n in SIZES {
+        let a = bounded_set(n, 0, 10, 4);
+        group.bench_with_input(BenchmarkId::new("bounded_set", n), &n, |bench, &n| {
+            bench.iter(|| black_box(a.contains(black_box(n * 5 + 2))))
+        });
+        let x = unbounded_set(n, 0);
+        group.bench_with_input(BenchmarkId::new("unbounded_set", n), &n, |bench, &n| {
+            bench.iter(|| black_box(x.contains(black_box(n * 5 + 2))))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    union,
+    intersection,
+    difference,
+    symmetric_difference,
+    complement,
+    contains
+);
+criterion_main!(benches);
@@ -0,0 +1,458 @@
+use crate::{
+    merge, BoundedRange, BoundedSet, Component, Domain, Element, EmptyRange, LowerBound,
+    LowerBoundedRange, LowerBoundedSet, RangeError, RangeQuery, Rangetools, UnboundedRange,
+    UnboundedSet, UpperBound, UpperBoundedRange, UpperBoundedSet,
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::borrow::Borrow;
+use std::hash::{Hash, Hasher};
+
+/// A range or set of any of the types in this crate, chosen at runtime.
+///
+/// The output type of a range operation depends on the types of its operands, which is great
+/// when they're known statically but awkward when they depend on runtime data. `AnySet` holds
+/// any of them, and is closed under all of the range operations: combining an `AnySet` with
+/// anything (or anything with an `AnySet`) gives an `AnySet`.
+///
+/// Every range and set type converts into an `AnySet` with `From`, and an `AnySet` can be
+/// converted back with `TryFrom`.
+///
+/// # Example
+/// ```
+/// use rangetools::{AnySet, BoundedRange, Rangetools};
+///
+/// let filters: Vec<AnySet<i32>> = vec![
+///     (0..10).into(),
+///     (5..).union(-5..0).into(),
+///     (20..).complement().into(),
+/// ];
+/// let s = filters.into_iter().fold(AnySet::from(..), |acc, f| acc.intersection(f));
+/// assert_eq!(BoundedRange::try_from(s), Ok(BoundedRange::from(5..10)));
+/// ```
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub enum AnySet<T> {
+    /// An [`EmptyRange`].
+    EmptyRange(EmptyRange<T>),
+    /// A [`BoundedRange`].
+    BoundedRange(BoundedRange<T>),
+    /// A [`BoundedSet`].
+    BoundedSet(BoundedSet<T>),
+    /// A [`LowerBoundedRange`].
+    LowerBoundedRange(LowerBoundedRange<T>),
+    /// A [`LowerBoundedSet`].
+    LowerBoundedSet(LowerBoundedSet<T>),
+    /// An [`UpperBoundedRange`].
+    UpperBoundedRange(UpperBoundedRange<T>),
+    /// An [`UpperBoundedSet`].
+    UpperBoundedSet(UpperBoundedSet<T>),
+    /// An [`UnboundedRange`].
+    UnboundedRange(UnboundedRange),
+    /// An [`UnboundedSet`].
+    UnboundedSet(UnboundedSet<T>),
+}
+
+impl<T: Element> AnySet<T> {
+    fn canonical(&self) -> merge::Canonical<impl Iterator<Item = merge::OwnedPiece<T>> + '_, T> {
+        merge::Canonical::new(RangeQuery::components(self).map(Component::into_bounds))
+    }
+}
+
+// Compared and hashed by the elements contained, whichever variant holds them, so that, for
+// example, a `BoundedSet` with a single range equals that `BoundedRange`.
+impl<T: Element> PartialEq for AnySet<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.canonical().eq(other.canonical())
+    }
+}
+
+impl<T: Element> Eq for AnySet<T> {}
+
+impl<T: Element + Hash> Hash for AnySet<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for piece in self.canonical() {
+            piece.hash(state);
+        }
+    }
+}
+
+macro_rules! any_set_from_impls {
+    ($($t:ident)*) => {
+        $(
+            impl<T> From<$t<T>> for AnySet<T> {
+                fn from(r: $t<T>) -> Self {
+                    Self::$t(r)
+                }
+            }
+        )*
+    };
+}
+
+any_set_from_impls! {
+    EmptyRange BoundedRange BoundedSet LowerBoundedRange LowerBoundedSet UpperBoundedRange
+    UpperBoundedSet UnboundedSet
+}
+
+macro_rules! any_set_from_borrowed_impls {
+    ($($t:ident)*) => {
+        $(
+            impl<T: Clone> From<&$t<T>> for AnySet<T> {
+                fn from(s: &$t<T>) -> Self {
+                    Self::$t(s.clone())
+                }
+            }
+        )*
+    };
+}
+
+any_set_from_borrowed_impls! { BoundedSet LowerBoundedSet UpperBoundedSet UnboundedSet }
+
+impl<T> From<UnboundedRange> for AnySet<T> {
+    fn from(r: UnboundedRange) -> Self {
+        Self::UnboundedRange(r)
+    }
+}
+
+macro_rules! any_set_from_std_impls {
+    ($($t:ty)*) => {
+        $(
+            impl<T: Element> From<$t> for AnySet<T> {
+                fn from(r: $t) -> Self {
+                    r.to_inner().into()
+                }
+            }
+        )*
+    };
+}
+
+any_set_from_std_impls! {
+    std::ops::Range<T> std::ops::RangeFrom<T> std::ops::RangeInclusive<T> std::ops::RangeTo<T>
+    std::ops::RangeToInclusive<T>
+}
+
+impl<T> From<std::ops::RangeFull> for AnySet<T> {
+    fn from(_: std::ops::RangeFull) -> Self {
+        Self::UnboundedRange(UnboundedRange)
+    }
+}
+
+/// Converts a pair of [`std::ops::Bound`]s to the range type with the same bounds.
+///
+/// # Example
+/// ```
+/// use rangetools::{AnySet, LowerBound, LowerBoundedRange};
+/// use std::ops::Bound;
+///
+/// let s = AnySet::from((Bound::Excluded(3), Bound::Unbounded));
+/// assert_eq!(s, AnySet::from(LowerBoundedRange::new(LowerBound::excluded(3))));
+/// ```
+impl<T> From<(std::ops::Bound<T>, std::ops::Bound<T>)> for AnySet<T> {
+    fn from((start, end): (std::ops::Bound<T>, std::ops::Bound<T>)) -> Self {
+        match (LowerBound::try_from(start), UpperBound::try_from(end)) {
+            (Ok(start), Ok(end)) => BoundedRange { start, end }.into(),
+            (Ok(start), Err(_)) => LowerBoundedRange { start }.into(),
+            (Err(_), Ok(end)) => UpperBoundedRange { end }.into(),
+            (Err(_), Err(_)) => UnboundedRange.into(),
+        }
+    }
+}
+
+/// Succeeds if the set has no elements.
+///
+/// # Example
+/// ```
+/// use rangetools::{AnySet, EmptyRange, RangeError, Rangetools};
+///
+/// assert!(EmptyRange::try_from(AnySet::from(5..5)).is_ok());
+/// assert_eq!(EmptyRange::try_from(AnySet::from(0..5)), Err(RangeError::IncompatibleType));
+/// ```
+impl<T: Element> TryFrom<AnySet<T>> for EmptyRange<T> {
+    type Error = RangeError;
+    fn try_from(s: AnySet<T>) -> Result<Self, Self::Error> {
+        if s.is_empty() {
+            Ok(EmptyRange::new())
+        } else {
+            Err(RangeError::IncompatibleType)
+        }
+    }
+}
+
+/// Succeeds if the set is a non-empty `BoundedRange`, or a `BoundedSet` with exactly one range.
+///
+/// An empty `BoundedRange` fails with [`RangeError::Empty`], like the `EmptyRange` it equals.
+///
+/// # Example
+/// ```
+/// use rangetools::{AnySet, BoundedRange, RangeError, Rangetools};
+///
+/// let s = AnySet::from((0..3).union(2..5));
+/// assert_eq!(BoundedRange::try_from(s), Ok(BoundedRange::from(0..5)));
+/// assert_eq!(BoundedRange::try_from(AnySet::from(5..3)), Err(RangeError::Empty));
+/// assert_eq!(BoundedRange::try_from(AnySet::from(0..)), Err(RangeError::IncompatibleType));
+/// ```
+impl<T: PartialOrd> TryFrom<AnySet<T>> for BoundedRange<T> {
+    type Error = RangeError;
+    fn try_from(s: AnySet<T>) -> Result<Self, Self::Error> {
+        match s {
+            AnySet::EmptyRange(_) => Err(RangeError::Empty),
+            AnySet::BoundedRange(r) if r.is_empty_by_bounds() => Err(RangeError::Empty),
+            AnySet::BoundedRange(r) => Ok(r),
+            AnySet::BoundedSet(s) => s.try_into(),
+            _ => Err(RangeError::IncompatibleType),
+        }
+    }
+}
+
+/// Succeeds if the set is an `EmptyRange`, a `BoundedRange` or a `BoundedSet`.
+///
+/// # Example
+/// ```
+/// use rangetools::{AnySet, BoundedSet, EmptyRange, RangeError};
+///
+/// let s = AnySet::from(EmptyRange::new());
+/// assert_eq!(BoundedSet::<i32>::try_from(s), Ok(BoundedSet::empty()));
+/// assert_eq!(BoundedSet::try_from(AnySet::from(..5)), Err(RangeError::IncompatibleType));
+/// ```
+impl<T: Element> TryFrom<AnySet<T>> for BoundedSet<T> {
+    type Error = RangeError;
+    fn try_from(s: AnySet<T>) -> Result<Self, Self::Error> {
+        match s {
+            AnySet::EmptyRange(_) => Ok(BoundedSet::empty()),
+            AnySet::BoundedRange(r) => Ok(r.into()),
+            AnySet::BoundedSet(s) => Ok(s),
+            _ => Err(RangeError::IncompatibleType),
+        }
+    }
+}
+
+/// Succeeds if the set is a `LowerBoundedRange`, or a `LowerBoundedSet` with no bounded
+/// ranges.
+///
+/// # Example
+/// ```
+/// use rangetools::{AnySet, LowerBoundedRange, RangeError, Rangetools};
+///
+/// let s = AnySet::from((0..5).union(3..));
+/// assert_eq!(LowerBoundedRange::try_from(s), Ok(LowerBoundedRange::from(0..)));
+/// assert_eq!(LowerBoundedRange::try_from(AnySet::from(..5)), Err(RangeError::IncompatibleType));
+/// ```
+impl<T> TryFrom<AnySet<T>> for LowerBoundedRange<T> {
+    type Error = RangeError;
+    fn try_from(s: AnySet<T>) -> Result<Self, Self::Error> {
+        match s {
+            AnySet::LowerBoundedRange(r) => Ok(r),
+            AnySet::LowerBoundedSet(s) => s.try_into(),
+            _ => Err(RangeError::IncompatibleType),
+        }
+    }
+}
+
+/// Succeeds if the set is a `LowerBoundedRange` or a `LowerBoundedSet`.
+///
+/// # Example
+/// ```
+/// use rangetools::{AnySet, LowerBoundedSet, RangeError, Rangetools};
+///
+/// let s = AnySet::from(0..);
+/// assert_eq!(LowerBoundedSet::try_from(s), Ok((0..).to_set()));
+/// assert_eq!(LowerBoundedSet::try_from(AnySet::from(0..5)), Err(RangeError::IncompatibleType));
+/// ```
+impl<T> TryFrom<AnySet<T>> for LowerBoundedSet<T> {
+    type Error = RangeError;
+    fn try_from(s: AnySet<T>) -> Result<Self, Self::Error> {
+        match s {
+            AnySet::LowerBoundedRange(r) => Ok(r.into()),
+            AnySet::LowerBoundedSet(s) => Ok(s),
+            _ => Err(RangeError::IncompatibleType),
+        }
+    }
+}
+
+/// Succeeds if the set is an `UpperBoundedRange`, or an `UpperBoundedSet` with no bounded
+/// ranges.
+///
+/// # Example
+/// ```
+/// use rangetools::{AnySet, RangeError, Rangetools, UpperBoundedRange};
+///
+/// let s = AnySet::from((..5).union(3..8));
+/// assert_eq!(UpperBoundedRange::try_from(s), Ok(UpperBoundedRange::from(..8)));
+/// assert_eq!(UpperBoundedRange::try_from(AnySet::from(0..)), Err(RangeError::IncompatibleType));
+/// ```
+impl<T> TryFrom<AnySet<T>> for UpperBoundedRange<T> {
+    type Error = RangeError;
+    fn try_from(s: AnySet<T>) -> Result<Self, Self::Error> {
+        match s {
+            AnySet::UpperBoundedRange(r) => Ok(r),
+            AnySet::UpperBoundedSet(s) => s.try_into(),
+            _ => Err(RangeError::IncompatibleType),
+        }
+    }
+}
+
+/// Succeeds if the set is an `UpperBoundedRange` or an `UpperBoundedSet`.
+///
+/// # Example
+/// ```
+/// use rangetools::{AnySet, RangeError, Rangetools, UpperBoundedSet};
+///
+/// let s = AnySet::from(..5);
+/// assert_eq!(UpperBoundedSet::try_from(s), Ok((..5).to_set()));
+/// assert_eq!(UpperBoundedSet::try_from(AnySet::from(0..5)), Err(RangeError::IncompatibleType));
+/// ```
+impl<T> TryFrom<AnySet<T>> for UpperBoundedSet<T> {
+    type Error = RangeError;
+    fn try_from(s: AnySet<T>) -> Result<Self, Self::Error> {
+        match s {
+            AnySet::UpperBoundedRange(r) => Ok(r.into()),
+            AnySet::UpperBoundedSet(s) => Ok(s),
+            _ => Err(RangeError::IncompatibleType),
+        }
+    }
+}
+
+/// Succeeds if the set is an `UnboundedRange`, or an [`UnboundedSet::Full`].
+///
+/// # Example
+/// ```
+/// use rangetools::{AnySet, RangeError, Rangetools, UnboundedRange};
+///
+/// let s = AnySet::from((..5).union(3..));
+/// assert_eq!(UnboundedRange::try_from(s), Ok(UnboundedRange));
+/// assert_eq!(UnboundedRange::try_from(AnySet::from(0..)), Err(RangeError::IncompatibleType));
+/// ```
+impl<T> TryFrom<AnySet<T>> for UnboundedRange {
+    type Error = RangeError;
+    fn try_from(s: AnySet<T>) -> Result<Self, Self::Error> {
+        match s {
+            AnySet::UnboundedRange(r) => Ok(r),
+            AnySet::UnboundedSet(s) => s.try_into(),
+            _ => Err(RangeError::IncompatibleType),
+        }
+    }
+}
+
+/// Succeeds if the set is an `UnboundedRange` or an `UnboundedSet`.
+///
+/// # Example
+/// ```
+/// use rangetools::{AnySet, RangeError, UnboundedSet};
+///
+/// assert_eq!(UnboundedSet::<i32>::try_from(AnySet::from(..)), Ok(UnboundedSet::Full));
+/// assert_eq!(UnboundedSet::try_from(AnySet::from(..5)), Err(RangeError::IncompatibleType));
+/// ```
+impl<T> TryFrom<AnySet<T>> for UnboundedSet<T> {
+    type Error = RangeError;
+    fn try_from(s: AnySet<T>) -> Result<Self, Self::Error> {
+        match s {
+            AnySet::UnboundedRange(r) => Ok(r.into()),
+            AnySet::UnboundedSet(s) => Ok(s),
+            _ => Err(RangeError::IncompatibleType),
+        }
+    }
+}
+
+impl<T: Element> AnySet<T> {
+    /// Returns true if the set contains `t`, which can be passed by value or by reference.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{AnySet, Rangetools};
+    ///
+    /// let s = AnySet::from((0..5).union(10..));
+    /// assert!(s.contains(3));
+    /// assert!(s.contains(&42));
+    /// assert!(!s.contains(7));
+    /// ```
+    pub fn contains<Q: Borrow<T>>(&self, t: Q) -> bool {
+        let t = t.borrow();
+        match self {
+            Self::EmptyRange(r) => r.contains(t),
+            Self::BoundedRange(r) => r.contains(t),
+            Self::BoundedSet(s) => s.contains(t),
+            Self::LowerBoundedRange(r) => r.contains(t),
+            Self::LowerBoundedSet(s) => s.contains(t),
+            Self::UpperBoundedRange(r) => r.contains(t),
+            Self::UpperBoundedSet(s) => s.contains(t),
+            Self::UnboundedRange(r) => r.contains(t),
+            Self::UnboundedSet(s) => s.contains(t),
+        }
+    }
+}
+
+impl<T: Element + Domain> AnySet<T> {
+    /// Returns true if the set contains every value of `T`.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{AnySet, Rangetools};
+    ///
+    /// assert!(AnySet::from((..=10u8).union(11..)).is_full());
+    /// assert!(AnySet::<u8>::from(..).is_full());
+    /// assert!(!AnySet::from(0u8..255).is_full());
+    /// ```
+    pub fn is_full(&self) -> bool {
+        match self {
+            Self::EmptyRange(r) => r.is_full(),
+            Self::BoundedRange(r) => r.is_full(),
+            Self::BoundedSet(s) => s.is_full(),
+            Self::LowerBoundedRange(r) => r.is_full(),
+            Self::LowerBoundedSet(s) => s.is_full(),
+            Self::UpperBoundedRange(r) => r.is_full(),
+            Self::UpperBoundedSet(s) => s.is_full(),
+            Self::UnboundedRange(r) => r.is_full(),
+            Self::UnboundedSet(s) => s.is_full(),
+        }
+    }
+
+    /// Returns the values of `T` not contained in the set, as a normalized set.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{AnySet, Rangetools};
+    ///
+    /// let c = AnySet::from((..10u8).union(20..)).complement_in_domain();
+    /// assert_eq!(c, (10..=19).to_set());
+    /// ```
+    pub fn complement_in_domain(self) -> BoundedSet<T> {
+        match self {
+            Self::EmptyRange(r) => r.complement_in_domain(),
+            Self::BoundedRange(r) => r.complement_in_domain(),
+            Self::BoundedSet(s) => s.complement_in_domain(),
+            Self::LowerBoundedRange(r) => r.complement_in_domain(),
+            Self::LowerBoundedSet(s) => s.complement_in_domain(),
+            Self::UpperBoundedRange(r) => r.complement_in_domain(),
+            Self::UpperBoundedSet(s) => s.complement_in_domain(),
+            Self::UnboundedRange(r) => r.complement_in_domain(),
+            Self::UnboundedSet(s) => s.complement_in_domain(),
+        }
+    }
+
+    /// Returns the set as a normalized set over the values of `T`, treating it as though it
+    /// started at `T::MIN` and ended at `T::MAX`.
+    ///
+    /// Sets over the same domain compare equal exactly when they contain the same elements,
+    /// whatever the range or set type they were built from.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{AnySet, Rangetools};
+    ///
+    /// let s = AnySet::from((..5u8).union(250..));
+    /// assert_eq!(s.normalize_in_domain(), (0..=4).union(250..=255));
+    /// ```
+    pub fn normalize_in_domain(self) -> BoundedSet<T> {
+        match self {
+            Self::EmptyRange(r) => r.normalize_in_domain(),
+            Self::BoundedRange(r) => r.normalize_in_domain(),
+            Self::BoundedSet(s) => s.normalize_in_domain(),
+            Self::LowerBoundedRange(r) => r.normalize_in_domain(),
+            Self::LowerBoundedSet(s) => s.normalize_in_domain(),
+            Self::UpperBoundedRange(r) => r.normalize_in_domain(),
+            Self::UpperBoundedSet(s) => s.normalize_in_domain(),
+            Self::UnboundedRange(r) => r.normalize_in_domain(),
+            Self::UnboundedSet(s) => s.normalize_in_domain(),
+        }
+    }
+}
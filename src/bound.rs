@@ -1,3 +1,4 @@
+use crate::RangeError;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -54,6 +55,51 @@ impl<T> Bound<T> {
             Self::Included(t) => Self::Excluded(t),
         }
     }
+
+    /// Converts from `&Bound<T>` to `Bound<&T>`.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::Bound;
+    ///
+    /// let b = Bound::Included(String::from("a"));
+    /// assert_eq!(b.as_ref().map(String::len), Bound::Included(1));
+    /// ```
+    pub fn as_ref(&self) -> Bound<&T> {
+        match self {
+            Self::Excluded(t) => Bound::Excluded(t),
+            Self::Included(t) => Bound::Included(t),
+        }
+    }
+}
+
+impl<T> From<Bound<T>> for std::ops::Bound<T> {
+    fn from(b: Bound<T>) -> Self {
+        match b {
+            Bound::Excluded(t) => Self::Excluded(t),
+            Bound::Included(t) => Self::Included(t),
+        }
+    }
+}
+
+/// Succeeds unless the bound is [`std::ops::Bound::Unbounded`].
+///
+/// # Example
+/// ```
+/// use rangetools::{Bound, RangeError};
+///
+/// assert_eq!(Bound::try_from(std::ops::Bound::Excluded(3)), Ok(Bound::Excluded(3)));
+/// assert_eq!(Bound::<i32>::try_from(std::ops::Bound::Unbounded), Err(RangeError::Unbounded));
+/// ```
+impl<T> TryFrom<std::ops::Bound<T>> for Bound<T> {
+    type Error = RangeError;
+    fn try_from(b: std::ops::Bound<T>) -> Result<Self, Self::Error> {
+        match b {
+            std::ops::Bound::Excluded(t) => Ok(Self::Excluded(t)),
+            std::ops::Bound::Included(t) => Ok(Self::Included(t)),
+            std::ops::Bound::Unbounded => Err(RangeError::Unbounded),
+        }
+    }
 }
 
 /// Lower bound of a range.
@@ -69,24 +115,46 @@ impl<T> From<Bound<T>> for LowerBound<T> {
     }
 }
 
+impl<T> From<LowerBound<T>> for std::ops::Bound<T> {
+    fn from(b: LowerBound<T>) -> Self {
+        b.0.into()
+    }
+}
+
+/// Succeeds unless the bound is [`std::ops::Bound::Unbounded`].
+///
+/// # Example
+/// ```
+/// use rangetools::{LowerBound, RangeError};
+///
+/// assert_eq!(LowerBound::try_from(std::ops::Bound::Included(3)), Ok(LowerBound::included(3)));
+/// assert_eq!(LowerBound::<i32>::try_from(std::ops::Bound::Unbounded), Err(RangeError::Unbounded));
+/// ```
+impl<T> TryFrom<std::ops::Bound<T>> for LowerBound<T> {
+    type Error = RangeError;
+    fn try_from(b: std::ops::Bound<T>) -> Result<Self, Self::Error> {
+        Bound::try_from(b).map(Self)
+    }
+}
+
 impl<T: PartialOrd> PartialOrd for LowerBound<T> {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
         match (&self.0, &other.0) {
             (Bound::Included(a), Bound::Included(b)) | (Bound::Excluded(a), Bound::Excluded(b)) => {
-                a.partial_cmp(&b)
+                a.partial_cmp(b)
             }
             (Bound::Included(a), Bound::Excluded(b)) => {
                 if a == b {
                     Some(std::cmp::Ordering::Less)
                 } else {
-                    a.partial_cmp(&b)
+                    a.partial_cmp(b)
                 }
             }
             (Bound::Excluded(a), Bound::Included(b)) => {
                 if a == b {
                     Some(std::cmp::Ordering::Greater)
                 } else {
-                    a.partial_cmp(&b)
+                    a.partial_cmp(b)
                 }
             }
         }
@@ -132,10 +200,7 @@ impl<T> LowerBound<T> {
     ///
     /// Be careful when performing operations on raw bounds, as they can have different meanings
     /// depending on their original context.
-    pub fn to_bound(self) -> Bound<T>
-    where
-        T: Copy,
-    {
+    pub fn to_bound(self) -> Bound<T> {
         self.0
     }
 
@@ -170,24 +235,46 @@ impl<T> From<Bound<T>> for UpperBound<T> {
     }
 }
 
+impl<T> From<UpperBound<T>> for std::ops::Bound<T> {
+    fn from(b: UpperBound<T>) -> Self {
+        b.0.into()
+    }
+}
+
+/// Succeeds unless the bound is [`std::ops::Bound::Unbounded`].
+///
+/// # Example
+/// ```
+/// use rangetools::{UpperBound, RangeError};
+///
+/// assert_eq!(UpperBound::try_from(std::ops::Bound::Included(3)), Ok(UpperBound::included(3)));
+/// assert_eq!(UpperBound::<i32>::try_from(std::ops::Bound::Unbounded), Err(RangeError::Unbounded));
+/// ```
+impl<T> TryFrom<std::ops::Bound<T>> for UpperBound<T> {
+    type Error = RangeError;
+    fn try_from(b: std::ops::Bound<T>) -> Result<Self, Self::Error> {
+        Bound::try_from(b).map(Self)
+    }
+}
+
 impl<T: PartialOrd> PartialOrd for UpperBound<T> {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
         match (&self.0, &other.0) {
             (Bound::Included(a), Bound::Included(b)) | (Bound::Excluded(a), Bound::Excluded(b)) => {
-                a.partial_cmp(&b)
+                a.partial_cmp(b)
             }
             (Bound::Included(a), Bound::Excluded(b)) => {
                 if a == b {
                     Some(std::cmp::Ordering::Greater)
                 } else {
-                    a.partial_cmp(&b)
+                    a.partial_cmp(b)
                 }
             }
             (Bound::Excluded(a), Bound::Included(b)) => {
                 if a == b {
                     Some(std::cmp::Ordering::Less)
                 } else {
-                    a.partial_cmp(&b)
+                    a.partial_cmp(b)
                 }
             }
         }
@@ -233,10 +320,7 @@ impl<T> UpperBound<T> {
     ///
     /// Be careful when performing operations on raw bounds, as they can have different meanings
     /// depending on their original context.
-    pub fn to_bound(self) -> Bound<T>
-    where
-        T: Copy,
-    {
+    pub fn to_bound(self) -> Bound<T> {
         self.0
     }
 
@@ -1,7 +1,14 @@
-use crate::{Bound, LowerBound, Rangetools, Step, UpperBound};
+use crate::{
+    domain::domain, Bound, BoundedSet, Domain, Element, EmptyRange, LowerBound, Measure,
+    RangeError, Rangetools, Step, UnboundedSet, UpperBound,
+};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
-use std::iter::FusedIterator;
+use std::{
+    borrow::Borrow,
+    hash::{Hash, Hasher},
+    iter::FusedIterator,
+};
 
 /// A range bounded both below and above (either inclusive or exclusive).
 ///
@@ -17,10 +24,24 @@ use std::iter::FusedIterator;
 /// assert_eq!(i, BoundedRange { start: LowerBound::included(3), end: UpperBound::excluded(5) });
 /// ```
 ///
-/// The range is empty if the start bound is greater than the end bound. Note that a range
-/// with start bound `Bound::Excluded(3)` and end bound `Bound::Excluded(4)` is not considered
-/// empty even though it doesn't contain any values.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// Ranges are compared, hashed and checked for emptiness by their bounds in the canonical form
+/// given by [`Element`], so two ranges are equal exactly when they contain the same elements.
+/// For [`Step`] types, a range with start bound `Bound::Excluded(3)` and end bound
+/// `Bound::Excluded(4)` is therefore empty, and `0..3` equals `0..=2`. All empty ranges compare
+/// and hash equal, regardless of their bounds, and compare equal to [`EmptyRange`].
+/// ```
+/// use rangetools::{BoundedRange, EmptyRange, LowerBound, Rangetools, UpperBound};
+///
+/// let i = (5..3).intersection(0..1);
+/// assert_eq!(i, (2..2).to_inner());
+/// assert_eq!(i, EmptyRange::new());
+///
+/// let r = BoundedRange::new(LowerBound::excluded(3), UpperBound::excluded(4));
+/// assert!(r.is_empty());
+/// assert_eq!(r, EmptyRange::new());
+/// assert_eq!((0..3).to_inner(), (0..=2).to_inner());
+/// ```
+#[derive(Clone, Copy, Debug)]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub struct BoundedRange<T> {
     /// The lower bound of the range (can be inclusive or exclusive).
@@ -29,6 +50,51 @@ pub struct BoundedRange<T> {
     pub end: UpperBound<T>,
 }
 
+impl<T: Element> PartialEq for BoundedRange<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.canonical_bounds() == other.canonical_bounds()
+    }
+}
+
+impl<T: Element> Eq for BoundedRange<T> {}
+
+impl<T: Element + Hash> Hash for BoundedRange<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.canonical_bounds().hash(state);
+    }
+}
+
+impl<T: Element> PartialEq<EmptyRange<T>> for BoundedRange<T> {
+    fn eq(&self, _: &EmptyRange<T>) -> bool {
+        self.canonical_bounds().is_none()
+    }
+}
+
+impl<T: Element> BoundedRange<T> {
+    /// Returns the bounds of the range in canonical form, or `None` if the range doesn't contain
+    /// any elements.
+    pub(crate) fn canonical_bounds(&self) -> Option<(LowerBound<T>, UpperBound<T>)> {
+        let start = T::canonical_lower(self.start.clone());
+        let end = T::canonical_upper(self.end.clone());
+        let range = BoundedRange { start, end };
+        (!range.is_empty_by_bounds()).then_some((range.start, range.end))
+    }
+}
+
+impl<T: PartialOrd> BoundedRange<T> {
+    /// Returns true if the start bound is greater than the end bound. Unlike
+    /// [`Rangetools::is_empty`], doesn't treat a range as empty just because there are no
+    /// elements between its bounds, so only requires `T: PartialOrd`.
+    pub(crate) fn is_empty_by_bounds(&self) -> bool {
+        match (&self.start.0, &self.end.0) {
+            (Bound::Included(start), Bound::Included(end)) => start > end,
+            (Bound::Excluded(start), Bound::Included(end))
+            | (Bound::Included(start), Bound::Excluded(end))
+            | (Bound::Excluded(start), Bound::Excluded(end)) => start >= end,
+        }
+    }
+}
+
 impl<T> From<std::ops::Range<T>> for BoundedRange<T> {
     fn from(r: std::ops::Range<T>) -> Self {
         Self {
@@ -48,28 +114,37 @@ impl<T> From<std::ops::RangeInclusive<T>> for BoundedRange<T> {
     }
 }
 
-impl<T> From<BoundedRange<T>> for std::ops::Range<T>
+impl<T> TryFrom<BoundedRange<T>> for std::ops::Range<T>
 where
-    T: Copy + Step,
+    T: Clone + Step,
 {
-    fn from(r: BoundedRange<T>) -> Self {
+    type Error = RangeError;
+    fn try_from(r: BoundedRange<T>) -> Result<Self, Self::Error> {
         let start = match r.start.to_bound() {
-            Bound::Excluded(t) => Step::forward(t, 1),
+            Bound::Excluded(t) => Step::forward_checked(t, 1).ok_or(RangeError::Overflow)?,
             Bound::Included(t) => t,
         };
         let end = match r.end.to_bound() {
             Bound::Excluded(t) => t,
-            Bound::Included(t) => Step::forward(t, 1),
+            Bound::Included(t) => Step::forward_checked(t, 1).ok_or(RangeError::Overflow)?,
         };
-        start..end
+        Ok(start..end)
     }
 }
 
+/// An empty range converts to an empty `RangeInclusive`, though not necessarily one with the
+/// same bounds.
 impl<T> From<BoundedRange<T>> for std::ops::RangeInclusive<T>
 where
-    T: Copy + Step,
+    T: Clone + Step,
 {
     fn from(r: BoundedRange<T>) -> Self {
+        if r.is_empty_by_bounds() {
+            let (Bound::Excluded(t) | Bound::Included(t)) = r.start.to_bound();
+            return empty_inclusive(t);
+        }
+        // A nonempty range can't be excluded at the largest or smallest value of its type, so
+        // stepping inward can't overflow.
         let start = match r.start.to_bound() {
             Bound::Excluded(t) => Step::forward(t, 1),
             Bound::Included(t) => t,
@@ -82,26 +157,68 @@ where
     }
 }
 
+/// Returns an empty `RangeInclusive` with `t` as one of its bounds.
+///
+/// Panics if `t` has neither a successor nor a predecessor, in which case `T` has a single value
+/// and every `RangeInclusive` of it contains that value.
+pub(crate) fn empty_inclusive<T: Clone + Step>(t: T) -> std::ops::RangeInclusive<T> {
+    if let Some(next) = Step::forward_checked(t.clone(), 1) {
+        next..=t
+    } else if let Some(prev) = Step::backward_checked(t.clone(), 1) {
+        t..=prev
+    } else {
+        panic!("no empty `RangeInclusive` exists for a type with a single value")
+    }
+}
+
+/// Allows a `BoundedRange` to be used wherever the standard library accepts a range, including
+/// with an excluded lower bound.
+///
+/// # Example
+/// ```
+/// use rangetools::{BoundedRange, LowerBound, UpperBound};
+/// use std::collections::BTreeMap;
+///
+/// let map: BTreeMap<_, _> = (0..10).map(|i| (i, i * i)).collect();
+/// let r = BoundedRange::new(LowerBound::excluded(2), UpperBound::included(5));
+/// assert_eq!(map.range(r).map(|(_, v)| *v).collect::<Vec<_>>(), vec![9, 16, 25]);
+/// ```
+impl<T> std::ops::RangeBounds<T> for BoundedRange<T> {
+    fn start_bound(&self) -> std::ops::Bound<&T> {
+        self.start.0.as_ref().into()
+    }
+
+    fn end_bound(&self) -> std::ops::Bound<&T> {
+        self.end.0.as_ref().into()
+    }
+}
+
 impl<T> IntoIterator for BoundedRange<T>
 where
-    T: Copy + Ord + Step,
+    T: Element + Step,
 {
     type IntoIter = BoundedRangeIter<T>;
     type Item = T;
     fn into_iter(self) -> Self::IntoIter {
-        let current = match self.start {
-            LowerBound(Bound::Excluded(t)) => Step::forward(t, 1),
-            LowerBound(Bound::Included(t)) => t,
-        };
-        let last = match self.end {
-            UpperBound(Bound::Excluded(t)) => Step::backward(t, 1),
-            UpperBound(Bound::Included(t)) => t,
-        };
-        BoundedRangeIter { current, last }
+        match (self.first(), self.last()) {
+            (Some(current), Some(last)) => BoundedRangeIter {
+                current,
+                last,
+                exhausted: false,
+            },
+            _ => {
+                let (LowerBound(Bound::Excluded(t)) | LowerBound(Bound::Included(t))) = self.start;
+                BoundedRangeIter {
+                    current: t.clone(),
+                    last: t,
+                    exhausted: true,
+                }
+            }
+        }
     }
 }
 
-impl<T: Copy + Ord> BoundedRange<T> {
+impl<T: Element> BoundedRange<T> {
     /// Constructs a new `BoundedRange` from a lower bound and an upper bound.
     ///
     /// # Example
@@ -115,28 +232,112 @@ impl<T: Copy + Ord> BoundedRange<T> {
         Self { start, end }
     }
 
-    /// Returns true if the range contains `t`.
+    /// Returns true if the range contains `t`, which can be passed by value or by reference.
     ///
     /// # Example
     /// ```
-    /// use rangetools::Rangetools;
+    /// use rangetools::{BoundedRange, Rangetools};
     ///
     /// let r = (1..10).intersection(5..7);
     /// assert!(r.contains(5));
-    /// assert!(!r.contains(3));
+    /// assert!(!r.contains(&3));
+    ///
+    /// let r = BoundedRange::from(String::from("a")..String::from("m"));
+    /// assert!(r.contains(&String::from("hello")));
     /// ```
-    pub fn contains(&self, t: T) -> bool {
-        let start_satisfied = match self.start.0 {
+    pub fn contains<Q: Borrow<T>>(&self, t: Q) -> bool {
+        let t = t.borrow();
+        let start_satisfied = match &self.start.0 {
             Bound::Excluded(s) => t > s,
             Bound::Included(s) => t >= s,
         };
-        let end_satisfied = match self.end.0 {
+        let end_satisfied = match &self.end.0 {
             Bound::Excluded(e) => t < e,
             Bound::Included(e) => t <= e,
         };
         start_satisfied && end_satisfied
     }
 
+    /// Returns the smallest range containing every element of the range, or `None` if the
+    /// range is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{BoundedRange, Rangetools};
+    ///
+    /// assert_eq!((0..5).to_inner().span(), Some(BoundedRange::from(0..5)));
+    /// assert_eq!((5..0).to_inner().span(), None);
+    /// ```
+    #[doc(alias = "hull")]
+    pub fn span(&self) -> Option<BoundedRange<T>> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(self.clone())
+        }
+    }
+
+    /// Returns the lower bound of the range, or `None` if the range is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{LowerBound, Rangetools};
+    ///
+    /// assert_eq!((0..5).to_inner().lower_bound(), Some(LowerBound::included(0)));
+    /// assert_eq!((5..0).to_inner().lower_bound(), None);
+    /// ```
+    #[doc(alias = "infimum")]
+    pub fn lower_bound(&self) -> Option<LowerBound<T>> {
+        self.span().map(|r| r.start)
+    }
+
+    /// Returns the upper bound of the range, or `None` if the range is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{Rangetools, UpperBound};
+    ///
+    /// assert_eq!((0..5).to_inner().upper_bound(), Some(UpperBound::excluded(5)));
+    /// assert_eq!((5..0).to_inner().upper_bound(), None);
+    /// ```
+    #[doc(alias = "supremum")]
+    pub fn upper_bound(&self) -> Option<UpperBound<T>> {
+        self.span().map(|r| r.end)
+    }
+
+    /// Returns true if every element of the range is less than `t`.
+    pub(crate) fn is_below(&self, t: &T) -> bool {
+        match &self.end.0 {
+            Bound::Excluded(e) => e <= t,
+            Bound::Included(e) => e < t,
+        }
+    }
+
+    /// Returns true if every element of the range is greater than `t`.
+    pub(crate) fn is_above(&self, t: &T) -> bool {
+        match &self.start.0 {
+            Bound::Excluded(s) => s >= t,
+            Bound::Included(s) => s > t,
+        }
+    }
+
+    /// Returns the range of values lying strictly between the upper bound `end` and the lower
+    /// bound `start`. This is empty if a range ending at `end` and a range starting at `start`
+    /// overlap or touch.
+    pub(crate) fn between(end: &UpperBound<T>, start: &LowerBound<T>) -> Self {
+        Self::new(
+            LowerBound(end.0.clone().flipped()),
+            UpperBound(start.0.clone().flipped()),
+        )
+    }
+
+    /// Returns true if the union of the two ranges is itself a range, i.e. if they overlap or
+    /// touch (as with `0..3` and `3..5`).
+    pub(crate) fn is_connected(&self, other: &Self) -> bool {
+        Self::between(&self.end, &other.start).is_empty()
+            && Self::between(&other.end, &self.start).is_empty()
+    }
+
     pub(crate) fn combine(&self, other: &Self) -> Self {
         if other.is_empty() {
             return self.clone();
@@ -144,14 +345,197 @@ impl<T: Copy + Ord> BoundedRange<T> {
         if self.is_empty() {
             return other.clone();
         }
-        assert!(self.intersects(*other));
+        assert!(self.is_connected(other));
         BoundedRange::new(
-            LowerBound::min(self.start, other.start),
-            UpperBound::max(self.end, other.end),
+            LowerBound::min(self.start.clone(), other.start.clone()),
+            UpperBound::max(self.end.clone(), other.end.clone()),
         )
     }
 }
 
+impl<T: Element + Step> BoundedRange<T> {
+    /// Returns the number of elements in the range, or `None` if the number doesn't fit in a
+    /// `u128`.
+    ///
+    /// Unlike `into_iter().len()`, this doesn't panic for ranges with more than `usize::MAX`
+    /// elements.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::Rangetools;
+    ///
+    /// assert_eq!((0..10).to_inner().count(), Some(10));
+    /// assert_eq!((i64::MIN..=i64::MAX).to_inner().count(), Some(1 << 64));
+    /// assert_eq!((0u128..1 << 70).to_inner().count(), Some(1 << 70));
+    /// assert_eq!((u128::MIN..=u128::MAX).to_inner().count(), None);
+    /// assert_eq!((5..5).to_inner().count(), Some(0));
+    /// ```
+    pub fn count(&self) -> Option<u128> {
+        let first = match &self.start.0 {
+            Bound::Excluded(t) => Step::forward_checked(t.clone(), 1),
+            Bound::Included(t) => Some(t.clone()),
+        };
+        let last = match &self.end.0 {
+            Bound::Excluded(t) => Step::backward_checked(t.clone(), 1),
+            Bound::Included(t) => Some(t.clone()),
+        };
+        match (first, last) {
+            (Some(first), Some(last)) if first <= last => {
+                Step::steps_between_u128(&first, &last).and_then(|steps| steps.checked_add(1))
+            }
+            _ => Some(0),
+        }
+    }
+
+    /// Returns the smallest element of the range, or `None` if the range has no elements.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{BoundedRange, LowerBound, UpperBound};
+    ///
+    /// let r = BoundedRange::new(LowerBound::excluded(2), UpperBound::included(5));
+    /// assert_eq!(r.first(), Some(3));
+    /// let r = BoundedRange::new(LowerBound::excluded(2), UpperBound::excluded(3));
+    /// assert_eq!(r.first(), None);
+    /// ```
+    pub fn first(&self) -> Option<T> {
+        let first = match &self.start.0 {
+            Bound::Excluded(t) => Step::forward_checked(t.clone(), 1)?,
+            Bound::Included(t) => t.clone(),
+        };
+        if self.contains(&first) {
+            Some(first)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the largest element of the range, or `None` if the range has no elements.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::Rangetools;
+    ///
+    /// assert_eq!((0..5).to_inner().last(), Some(4));
+    /// assert_eq!((0..=5).to_inner().last(), Some(5));
+    /// assert_eq!((5..5).to_inner().last(), None);
+    /// ```
+    pub fn last(&self) -> Option<T> {
+        let last = match &self.end.0 {
+            Bound::Excluded(t) => Step::backward_checked(t.clone(), 1)?,
+            Bound::Included(t) => t.clone(),
+        };
+        if self.contains(&last) {
+            Some(last)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the range in canonical form, with both bounds included, or `None` if the range
+    /// has no elements.
+    ///
+    /// Two normalized ranges are equal exactly when they contain the same elements.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{BoundedRange, LowerBound, Rangetools, UpperBound};
+    ///
+    /// let r = BoundedRange::new(LowerBound::excluded(2), UpperBound::excluded(6));
+    /// assert_eq!(r.normalize(), Some((3..=5).to_inner()));
+    /// assert_eq!(r.normalize(), (3..6).to_inner().normalize());
+    /// let r = BoundedRange::new(LowerBound::excluded(2), UpperBound::excluded(3));
+    /// assert_eq!(r.normalize(), None);
+    /// ```
+    pub fn normalize(self) -> Option<Self> {
+        Some(Self::new(
+            LowerBound::included(self.first()?),
+            UpperBound::included(self.last()?),
+        ))
+    }
+
+    /// Returns true if no element lies strictly between the upper bound `end` and the lower
+    /// bound `start`, so that a range ending at `end` and a range starting at `start` can be
+    /// merged.
+    pub(crate) fn is_contiguous(end: &UpperBound<T>, start: &LowerBound<T>) -> bool {
+        Self::between(end, start).first().is_none()
+    }
+}
+
+impl<T: Element + Domain> BoundedRange<T> {
+    /// Returns true if the range contains every value of `T`.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{BoundedRange, LowerBound, Rangetools, UpperBound};
+    ///
+    /// assert!((0u8..=255).to_inner().is_full());
+    /// assert!(!(0u8..255).to_inner().is_full());
+    /// let r = BoundedRange::new(LowerBound::excluded(i8::MIN), UpperBound::included(i8::MAX));
+    /// assert!(!r.is_full());
+    /// ```
+    pub fn is_full(&self) -> bool {
+        self.first() == Some(T::MIN) && self.last() == Some(T::MAX)
+    }
+
+    /// Returns the values of `T` not contained in the range, as a normalized set.
+    ///
+    /// Unlike [`complement`](Rangetools::complement), the result doesn't include the unbounded
+    /// ranges below `T::MIN` or above `T::MAX`, so it is bounded.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::Rangetools;
+    ///
+    /// let c = (10u8..20).to_inner().complement_in_domain();
+    /// assert_eq!(c, (0..=9).union(20..=255));
+    /// assert!((0u8..=255).to_inner().complement_in_domain().is_empty());
+    /// ```
+    pub fn complement_in_domain(self) -> BoundedSet<T> {
+        let complement: UnboundedSet<T> = self.complement();
+        domain().intersection(complement).normalize()
+    }
+
+    /// Returns the range as a normalized set over the values of `T`.
+    ///
+    /// Sets over the same domain compare equal exactly when they contain the same elements,
+    /// whatever the range or set type they were built from.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::Rangetools;
+    ///
+    /// let r = (250u8..=255).to_inner();
+    /// assert_eq!(r.normalize_in_domain(), (250u8..).to_inner().normalize_in_domain());
+    /// ```
+    pub fn normalize_in_domain(self) -> BoundedSet<T> {
+        self.to_set().normalize()
+    }
+}
+
+impl<T: Measure + PartialOrd> BoundedRange<T> {
+    /// Returns the width of the range, ignoring whether its bounds are inclusive or exclusive.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::BoundedRange;
+    /// use std::time::Duration;
+    ///
+    /// let r = BoundedRange::from(Duration::from_secs(2)..Duration::from_secs(5));
+    /// assert_eq!(r.measure(), Duration::from_secs(3));
+    /// assert_eq!(BoundedRange::from(1.5..=4.0).measure(), 2.5);
+    /// ```
+    pub fn measure(&self) -> T::Length {
+        let (Bound::Excluded(start) | Bound::Included(start)) = &self.start.0;
+        let (Bound::Excluded(end) | Bound::Included(end)) = &self.end.0;
+        if start < end {
+            T::length_between(start, end)
+        } else {
+            T::Length::default()
+        }
+    }
+}
+
 /// An iterator over the values contained by a `BoundedRange`.
 ///
 /// Created by the `into_iter` method on `BoundedRange` (provided by the [`std::iter::IntoIterator`] trait).
@@ -167,40 +551,73 @@ impl<T: Copy + Ord> BoundedRange<T> {
 pub struct BoundedRangeIter<T> {
     current: T,
     last: T,
+    /// Set once `current` has passed `last`, which can't be represented by `current` itself
+    /// when `last` is the largest value of `T`.
+    exhausted: bool,
+}
+
+impl<T> BoundedRangeIter<T> {
+    pub(crate) fn is_exhausted(&self) -> bool {
+        self.exhausted
+    }
+}
+
+impl<T: Step> BoundedRangeIter<T> {
+    /// Returns the number of remaining elements, or `None` if it doesn't fit in a `usize`.
+    pub(crate) fn remaining(&self) -> Option<usize> {
+        if self.exhausted {
+            return Some(0);
+        }
+        Step::steps_between(&self.current, &self.last).and_then(|s| s.checked_add(1))
+    }
 }
 
+/// The panic message of the `count` and `len` methods of the iterators when the number of
+/// elements doesn't fit in a `usize`.
+pub(crate) const TOO_MANY_ELEMENTS: &str = concat!(
+    "element count overflowed a `usize`; ",
+    "use the `count` method of the range or set, which returns an `Option<u128>`"
+);
+
 impl<T> Iterator for BoundedRangeIter<T>
 where
-    T: Copy + Ord + Step,
+    T: Element + Step,
 {
     type Item = T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.current > self.last {
-            None
-        } else {
-            let t = self.current;
-            self.current = Step::forward(self.current, 1);
-            Some(t)
+        if self.exhausted {
+            return None;
+        }
+        let t = self.current.clone();
+        match Step::forward_checked(t.clone(), 1) {
+            Some(next) if t < self.last => self.current = next,
+            _ => self.exhausted = true,
         }
+        Some(t)
     }
 
+    /// If the number of remaining elements doesn't fit in a `usize`, the lower bound is
+    /// `usize::MAX` and there is no upper bound.
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let size = Step::steps_between(&self.current, &self.last)
-            .map(|steps| steps + 1)
-            .unwrap_or_default();
-        (size, Some(size))
+        match self.remaining() {
+            Some(size) => (size, Some(size)),
+            None => (usize::MAX, None),
+        }
     }
 
+    /// # Panics
+    /// Panics if the number of remaining elements doesn't fit in a `usize`. Use
+    /// [`BoundedRange::count`] to count them as a `u128`.
     fn count(self) -> usize {
-        self.len()
+        self.remaining().expect(TOO_MANY_ELEMENTS)
     }
 
     fn last(self) -> Option<Self::Item>
     where
         Self: Sized,
     {
-        if self.current > self.last {
+        if self.exhausted {
             None
         } else {
             Some(self.last)
@@ -208,8 +625,19 @@ where
     }
 
     fn nth(&mut self, n: usize) -> Option<Self::Item> {
-        self.current = Step::forward(self.current, n);
-        self.next()
+        if self.exhausted {
+            return None;
+        }
+        match Step::forward_checked(self.current.clone(), n) {
+            Some(current) if current <= self.last => {
+                self.current = current;
+                self.next()
+            }
+            _ => {
+                self.exhausted = true;
+                None
+            }
+        }
     }
 
     fn min(mut self) -> Option<Self::Item> {
@@ -223,24 +651,49 @@ where
 
 impl<T> DoubleEndedIterator for BoundedRangeIter<T>
 where
-    T: Copy + Ord + Step,
+    T: Element + Step,
 {
     fn next_back(&mut self) -> Option<Self::Item> {
-        if self.current > self.last {
-            None
-        } else {
-            let t = self.last;
-            self.last = Step::backward(self.last, 1);
-            Some(t)
+        if self.exhausted {
+            return None;
         }
+        let t = self.last.clone();
+        match Step::backward_checked(t.clone(), 1) {
+            Some(prev) if self.current < t => self.last = prev,
+            _ => self.exhausted = true,
+        }
+        Some(t)
     }
 
     fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
-        self.last = Step::backward(self.last, n);
-        self.next_back()
+        if self.exhausted {
+            return None;
+        }
+        match Step::backward_checked(self.last.clone(), n) {
+            Some(last) if self.current <= last => {
+                self.last = last;
+                self.next_back()
+            }
+            _ => {
+                self.exhausted = true;
+                None
+            }
+        }
     }
 }
 
-impl<T> ExactSizeIterator for BoundedRangeIter<T> where T: Copy + Ord + Step {}
+/// The length is only known for up to `usize::MAX` elements; [`BoundedRange::count`] counts wider
+/// ones exactly.
+impl<T> ExactSizeIterator for BoundedRangeIter<T>
+where
+    T: Element + Step,
+{
+    /// # Panics
+    /// Panics if the number of remaining elements doesn't fit in a `usize`. Use
+    /// [`BoundedRange::count`] to count them as a `u128`.
+    fn len(&self) -> usize {
+        self.remaining().expect(TOO_MANY_ELEMENTS)
+    }
+}
 
-impl<T> FusedIterator for BoundedRangeIter<T> where T: Copy + Ord + Step {}
+impl<T> FusedIterator for BoundedRangeIter<T> where T: Element + Step {}
@@ -1,7 +1,18 @@
-use crate::{BoundedRange, BoundedRangeIter, Rangetools, Step};
+use crate::{
+    bounded_range::TOO_MANY_ELEMENTS, domain::domain, merge, BoundedRange, BoundedRangeIter,
+    Component, Components, Domain, Element, LowerBound, Measure, OrderStatistics, RangeDifference,
+    RangeError, RangeQuery, RangeUnion, Rangetools, Step, UnboundedSet, UpperBound,
+};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
-use std::{collections::VecDeque, iter::FusedIterator};
+use std::{
+    borrow::Borrow,
+    collections::{vec_deque, VecDeque},
+    fmt,
+    hash::{Hash, Hasher},
+    iter::FusedIterator,
+    sync::OnceLock,
+};
 
 /// A set of ranges ultimately bounded both below and above.
 ///
@@ -12,26 +23,112 @@ use std::{collections::VecDeque, iter::FusedIterator};
 ///
 /// let s: BoundedSet<_> = (0..3).union(3..5);
 /// ```
-#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[derive(Clone)]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub struct BoundedSet<T> {
-    /// Kept private to enforce the invariant that the ranges be non-empty and non-overlapping.
+    /// Kept private to enforce the invariant that the ranges be non-empty, non-overlapping and
+    /// non-touching. Modified through [`ranges_mut`](Self::ranges_mut) so that `totals` stays
+    /// in sync.
     pub(crate) ranges: VecDeque<BoundedRange<T>>,
+    /// The running totals of the number of elements in the ranges, computed by the first rank
+    /// or select query and cleared whenever the ranges change.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    totals: OnceLock<Vec<u128>>,
 }
 
-impl<T: Copy + Ord> From<BoundedRange<T>> for BoundedSet<T> {
+impl<T: fmt::Debug> fmt::Debug for BoundedSet<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BoundedSet")
+            .field("ranges", &self.ranges)
+            .finish()
+    }
+}
+
+// Compared and hashed in canonical form, so that sets containing the same elements are equal.
+impl<T: Element> PartialEq for BoundedSet<T> {
+    fn eq(&self, other: &Self) -> bool {
+        merge::canonical(self).eq(merge::canonical(other))
+    }
+}
+
+impl<T: Element> Eq for BoundedSet<T> {}
+
+impl<T: Element + Hash> Hash for BoundedSet<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for piece in merge::canonical(self) {
+            piece.hash(state);
+        }
+    }
+}
+
+impl<T: Element> From<BoundedRange<T>> for BoundedSet<T> {
     fn from(r: BoundedRange<T>) -> Self {
         if r.is_empty() {
             Self::empty()
         } else {
-            Self { ranges: [r].into() }
+            Self::new([r].into())
+        }
+    }
+}
+
+/// Succeeds if the set consists of exactly one range.
+///
+/// # Example
+/// ```
+/// use rangetools::{BoundedRange, BoundedSet, RangeError, Rangetools};
+///
+/// assert_eq!(BoundedRange::try_from((0..3).union(2..5)), Ok(BoundedRange::from(0..5)));
+/// assert_eq!(BoundedRange::try_from(BoundedSet::<i32>::empty()), Err(RangeError::Empty));
+/// ```
+impl<T> TryFrom<BoundedSet<T>> for BoundedRange<T> {
+    type Error = RangeError;
+    fn try_from(s: BoundedSet<T>) -> Result<Self, Self::Error> {
+        let mut ranges = s.ranges.into_iter();
+        match (ranges.next(), ranges.next()) {
+            (None, _) => Err(RangeError::Empty),
+            (Some(range), None) => Ok(range),
+            (Some(_), Some(_)) => Err(RangeError::MultipleComponents),
+        }
+    }
+}
+
+impl<T, R> FromIterator<R> for BoundedSet<T>
+where
+    R: Rangetools<Inner = BoundedRange<T>>,
+    T: Element,
+{
+    fn from_iter<I: IntoIterator<Item = R>>(iter: I) -> Self {
+        let mut set = Self::empty();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<T, R> Extend<R> for BoundedSet<T>
+where
+    R: Rangetools<Inner = BoundedRange<T>>,
+    T: Element,
+{
+    fn extend<I: IntoIterator<Item = R>>(&mut self, iter: I) {
+        let set = self.ranges_mut();
+        let mut ranges: Vec<_> = set
+            .drain(..)
+            .chain(iter.into_iter().map(Rangetools::to_inner))
+            .filter(|r| !r.is_empty())
+            .collect();
+        ranges.sort_unstable_by(|a, b| a.start.cmp(&b.start));
+        for range in ranges {
+            match set.back_mut() {
+                Some(last) if last.is_connected(&range) => *last = last.combine(&range),
+                _ => set.push_back(range),
+            }
         }
     }
 }
 
 impl<T> IntoIterator for BoundedSet<T>
 where
-    T: Copy + Ord + Step,
+    T: Element + Step,
 {
     type IntoIter = BoundedSetIter<T>;
     type Item = T;
@@ -54,36 +151,304 @@ impl<T> BoundedSet<T> {
     /// assert!(!s.contains(5));
     /// ```
     pub fn empty() -> Self {
+        Self::new(VecDeque::new())
+    }
+
+    pub(crate) fn new(ranges: VecDeque<BoundedRange<T>>) -> Self {
         Self {
-            ranges: VecDeque::new(),
+            ranges,
+            totals: OnceLock::new(),
         }
     }
+
+    /// Returns the ranges for modification, clearing the cached running totals.
+    pub(crate) fn ranges_mut(&mut self) -> &mut VecDeque<BoundedRange<T>> {
+        self.totals.take();
+        &mut self.ranges
+    }
+
+    /// Returns an iterator over the disjoint ranges making up the set, in ascending order.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{BoundedRange, Rangetools};
+    ///
+    /// let s = (5..7).union(0..2);
+    /// let ranges: Vec<_> = s.ranges().copied().collect();
+    /// assert_eq!(ranges, vec![BoundedRange::from(0..2), BoundedRange::from(5..7)]);
+    /// ```
+    pub fn ranges(&self) -> Ranges<'_, T> {
+        Ranges::new(self)
+    }
+
+    /// Returns an iterator over the disjoint components making up the set, in ascending order.
+    ///
+    /// Every component of a `BoundedSet` is a [`Component::Bounded`](crate::Component::Bounded).
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{BoundedRange, Component, Rangetools};
+    ///
+    /// let s = (5..7).union(0..2);
+    /// let components: Vec<_> = s.components().collect();
+    /// assert_eq!(
+    ///     components,
+    ///     vec![
+    ///         Component::Bounded(BoundedRange::from(0..2)),
+    ///         Component::Bounded(BoundedRange::from(5..7)),
+    ///     ]
+    /// );
+    /// ```
+    pub fn components(&self) -> Components<'_, T>
+    where
+        T: Clone,
+    {
+        Components::new(None, self.ranges(), None)
+    }
+
+    /// Retains only the ranges making up the set for which the predicate returns true.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{BoundedRange, Rangetools};
+    ///
+    /// let mut s = (0..2).union(5..10).union(20..21);
+    /// s.retain(|r| r.contains(7));
+    /// assert_eq!(s.ranges().copied().collect::<Vec<_>>(), vec![BoundedRange::from(5..10)]);
+    /// ```
+    pub fn retain<F>(&mut self, f: F)
+    where
+        F: FnMut(&BoundedRange<T>) -> bool,
+    {
+        self.ranges_mut().retain(f);
+    }
 }
 
-impl<T: Copy + Ord> BoundedSet<T> {
+impl<T: Element> BoundedSet<T> {
     pub(crate) fn add_range(&mut self, r: BoundedRange<T>) {
-        if !r.is_empty() {
-            if let Some(index) = self.ranges.iter().position(|range| range.intersects(r)) {
-                let new_range = r.combine(&self.ranges[index]);
-                self.ranges.remove(index);
-                self.add_range(new_range);
-            } else {
-                let index = self
-                    .ranges
-                    .iter()
-                    .position(|range| range.start > r.start)
-                    .unwrap_or(self.ranges.len());
-                self.ranges.insert(index, r);
-            }
+        if r.is_empty() {
+            return;
+        }
+        // The ranges which overlap or touch `r` are contiguous, so can be found by bisection.
+        let start = self
+            .ranges
+            .partition_point(|range| !merge::is_connected(&range.end, &r.start));
+        let end = self
+            .ranges
+            .partition_point(|range| merge::is_connected(&r.end, &range.start));
+        if start == end {
+            self.ranges_mut().insert(start, r);
+        } else {
+            let first = &self.ranges[start];
+            let last = &self.ranges[end - 1];
+            let range = BoundedRange {
+                start: r.start.min(first.start.clone()),
+                end: r.end.max(last.end.clone()),
+            };
+            let ranges = self.ranges_mut();
+            ranges.drain(start + 1..end);
+            ranges[start] = range;
         }
     }
     pub(crate) fn add_set(&mut self, other: Self) {
-        for range in other.ranges.into_iter() {
-            self.add_range(range);
+        *self = merge::union((&*self).into(), (&other).into()).into();
+    }
+    pub(crate) fn remove_range(&mut self, r: BoundedRange<T>) {
+        self.remove_between(Some(r.start), Some(r.end));
+    }
+
+    /// Removes the values between `start` and `end`, where a missing bound is unbounded.
+    pub(crate) fn remove_between(
+        &mut self,
+        start: Option<LowerBound<T>>,
+        end: Option<UpperBound<T>>,
+    ) {
+        if merge::is_empty(start.as_ref(), end.as_ref()) {
+            return;
+        }
+        // The ranges which overlap the removed values are contiguous, so can be found by
+        // bisection. Only the first and last of them can keep any of their values.
+        let first = start.as_ref().map_or(0, |start| {
+            self.ranges
+                .partition_point(|range| merge::is_empty(Some(start), Some(&range.end)))
+        });
+        let last = end.as_ref().map_or(self.ranges.len(), |end| {
+            self.ranges
+                .partition_point(|range| !merge::is_empty(Some(&range.start), Some(end)))
+        });
+        if first >= last {
+            return;
+        }
+        let before = start
+            .map(|start| {
+                BoundedRange::new(
+                    self.ranges[first].start.clone(),
+                    UpperBound(start.0.flipped()),
+                )
+            })
+            .filter(|range| !range.is_empty_by_bounds());
+        let after = end
+            .map(|end| {
+                BoundedRange::new(
+                    LowerBound(end.0.flipped()),
+                    self.ranges[last - 1].end.clone(),
+                )
+            })
+            .filter(|range| !range.is_empty_by_bounds());
+        let ranges = self.ranges_mut();
+        ranges.drain(first..last);
+        if let Some(after) = after {
+            ranges.insert(first, after);
+        }
+        if let Some(before) = before {
+            ranges.insert(first, before);
         }
     }
 
-    /// Returns true if the set contains `t`.
+    /// Adds `range` to the set in place, merging it with any ranges it overlaps or touches.
+    ///
+    /// Only ranges whose union with a `BoundedSet` is still a `BoundedSet` can be inserted.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{BoundedSet, Rangetools};
+    ///
+    /// let mut s = BoundedSet::empty();
+    /// s.insert(0..5);
+    /// s.insert(3..=7);
+    /// s.insert(10..12);
+    /// assert_eq!(s, (0..=7).union(10..12));
+    /// ```
+    pub fn insert<R>(&mut self, range: R)
+    where
+        R: Rangetools,
+        R::Set: RangeQuery<T>,
+        Self: RangeUnion<R, R::Set, Output = Self>,
+    {
+        let set = range.to_set();
+        let components = RangeQuery::components(&set);
+        if components.len() > 1 {
+            let pieces: Vec<_> = components.map(Component::into_bounds).collect();
+            *self = merge::union_owned((&*self).into(), &pieces).into();
+        } else {
+            for component in components {
+                match component {
+                    Component::Bounded(r) => self.add_range(r),
+                    _ => unreachable!("only bounded ranges can be inserted into a `BoundedSet`"),
+                }
+            }
+        }
+    }
+
+    /// Removes all elements of `range` from the set in place, splitting any ranges it
+    /// partially overlaps.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{BoundedSet, Rangetools};
+    ///
+    /// let mut s = (0..10).to_set();
+    /// s.remove(3..5);
+    /// s.remove(8..);
+    /// assert_eq!(s, (0..3).union(5..8));
+    /// ```
+    pub fn remove<R>(&mut self, range: R)
+    where
+        R: Rangetools,
+        R::Set: RangeQuery<T>,
+        Self: RangeDifference<R, R::Inner, Output = Self>,
+    {
+        let set = range.to_set();
+        let components = RangeQuery::components(&set);
+        if components.len() > 1 {
+            let pieces: Vec<_> = components.map(Component::into_bounds).collect();
+            *self = merge::difference_owned((&*self).into(), &pieces).into();
+        } else {
+            for component in components {
+                let (start, end) = component.into_bounds();
+                self.remove_between(start, end);
+            }
+        }
+    }
+
+    /// Constructs a set from ranges which are already sorted and disjoint, in linear time.
+    ///
+    /// Fails at the first range which is empty, starts no later than the range before it, or
+    /// overlaps or touches the range before it. The error holds the index of that range.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{BoundedSet, RangeError, Rangetools};
+    ///
+    /// let s = BoundedSet::from_sorted_ranges([0..2, 5..7, 10..12]);
+    /// assert_eq!(s, Ok((0..2).union(5..7).union(10..12)));
+    ///
+    /// let s = BoundedSet::from_sorted_ranges([0..2, 10..12, 5..7]);
+    /// assert_eq!(s, Err(RangeError::Unsorted { index: 2 }));
+    /// let s = BoundedSet::from_sorted_ranges([0..2, 2..7]);
+    /// assert_eq!(s, Err(RangeError::Overlapping { index: 1 }));
+    /// ```
+    pub fn from_sorted_ranges<I>(ranges: I) -> Result<Self, RangeError>
+    where
+        I: IntoIterator,
+        I::Item: Rangetools<Inner = BoundedRange<T>>,
+    {
+        Self::from_sorted_ranges_after(None, ranges)
+    }
+
+    /// Constructs a set from ranges which are already sorted and disjoint, without checking them.
+    ///
+    /// The ranges must be non-empty and in ascending order, with a gap between each range and
+    /// the next, as checked by [`from_sorted_ranges`](Self::from_sorted_ranges). Otherwise the
+    /// results of operations on the set are unspecified.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{BoundedSet, Rangetools};
+    ///
+    /// let s = BoundedSet::from_sorted_ranges_unchecked([0..2, 5..7]);
+    /// assert_eq!(s, (0..2).union(5..7));
+    /// ```
+    pub fn from_sorted_ranges_unchecked<I>(ranges: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Rangetools<Inner = BoundedRange<T>>,
+    {
+        Self::new(ranges.into_iter().map(Rangetools::to_inner).collect())
+    }
+
+    /// Like [`from_sorted_ranges`](Self::from_sorted_ranges), but also checks that the first
+    /// range follows the unbounded component ending at `end`, if there is one. The ranges are
+    /// then counted from one in any error.
+    pub(crate) fn from_sorted_ranges_after<I>(
+        end: Option<&UpperBound<T>>,
+        ranges: I,
+    ) -> Result<Self, RangeError>
+    where
+        I: IntoIterator,
+        I::Item: Rangetools<Inner = BoundedRange<T>>,
+    {
+        let ranges = ranges.into_iter();
+        let mut set = Self::new(VecDeque::with_capacity(ranges.size_hint().0));
+        for (index, range) in ranges.enumerate() {
+            let index = index + usize::from(end.is_some());
+            let range = range.to_inner();
+            if range.is_empty() {
+                return Err(RangeError::EmptyComponent { index });
+            }
+            match (set.ranges.back(), end) {
+                (Some(last), _) => {
+                    check_follows(Some(&last.start), &last.end, &range.start, index)?
+                }
+                (None, Some(end)) => check_follows(None, end, &range.start, index)?,
+                (None, None) => {}
+            }
+            set.ranges_mut().push_back(range);
+        }
+        Ok(set)
+    }
+
+    /// Returns true if the set contains `t`, which can be passed by value or by reference.
     ///
     /// # Example
     /// ```
@@ -91,13 +456,572 @@ impl<T: Copy + Ord> BoundedSet<T> {
     ///
     /// let s = (1..5).union(10..20);
     /// assert!(s.contains(1));
-    /// assert!(!s.contains(42));
+    /// assert!(!s.contains(&42));
+    /// ```
+    pub fn contains<Q: Borrow<T>>(&self, t: Q) -> bool {
+        let t = t.borrow();
+        self.ranges
+            .get(self.index_not_below(t))
+            .is_some_and(|r| r.contains(t))
+    }
+
+    /// Returns the range making up the set which contains `t`, if any.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{BoundedRange, Rangetools};
+    ///
+    /// let s = (1..5).union(10..20);
+    /// assert_eq!(s.range_containing(12), Some(BoundedRange::from(10..20)));
+    /// assert_eq!(s.range_containing(7), None);
     /// ```
-    pub fn contains(&self, t: T) -> bool {
-        self.ranges.iter().any(|r| r.contains(t))
+    pub fn range_containing<Q: Borrow<T>>(&self, t: Q) -> Option<BoundedRange<T>> {
+        let t = t.borrow();
+        self.ranges
+            .get(self.index_not_below(t))
+            .filter(|r| r.contains(t))
+            .cloned()
+    }
+
+    /// Returns the index of the first range which doesn't lie entirely below `t`.
+    fn index_not_below(&self, t: &T) -> usize {
+        self.ranges.partition_point(|r| r.is_below(t))
+    }
+
+    /// Returns the index of the first range which lies entirely above `t`.
+    fn index_above(&self, t: &T) -> usize {
+        self.ranges.partition_point(|r| !r.is_above(t))
+    }
+
+    /// Returns an iterator over the gaps between the ranges making up the set, in ascending
+    /// order.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{BoundedRange, Rangetools};
+    ///
+    /// let s = (0..2).union(5..7).union(10..12);
+    /// let gaps: Vec<_> = s.gaps().collect();
+    /// assert_eq!(gaps, vec![BoundedRange::from(2..5), BoundedRange::from(7..10)]);
+    /// ```
+    pub fn gaps(&self) -> Gaps<'_, T> {
+        Gaps::new(None, self.ranges(), None)
+    }
+
+    /// Returns the smallest range containing every element of the set, or `None` if the set is
+    /// empty.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{BoundedRange, BoundedSet, Rangetools};
+    ///
+    /// let s = (0..2).union(5..=7);
+    /// assert_eq!(s.span(), Some(BoundedRange::from(0..=7)));
+    /// assert_eq!(BoundedSet::<i32>::empty().span(), None);
+    /// ```
+    #[doc(alias = "hull")]
+    pub fn span(&self) -> Option<BoundedRange<T>> {
+        let first = self.ranges.front()?;
+        let last = self.ranges.back()?;
+        Some(BoundedRange::new(first.start.clone(), last.end.clone()))
+    }
+
+    /// Returns the lower bound of the set, or `None` if the set is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{LowerBound, Rangetools};
+    ///
+    /// let s = (0..2).union(5..=7);
+    /// assert_eq!(s.lower_bound(), Some(LowerBound::included(0)));
+    /// ```
+    #[doc(alias = "infimum")]
+    pub fn lower_bound(&self) -> Option<LowerBound<T>> {
+        self.ranges.front().map(|r| r.start.clone())
+    }
+
+    /// Returns the upper bound of the set, or `None` if the set is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{Rangetools, UpperBound};
+    ///
+    /// let s = (0..2).union(5..=7);
+    /// assert_eq!(s.upper_bound(), Some(UpperBound::included(7)));
+    /// ```
+    #[doc(alias = "supremum")]
+    pub fn upper_bound(&self) -> Option<UpperBound<T>> {
+        self.ranges.back().map(|r| r.end.clone())
     }
 }
 
+impl<T: Element + Step> BoundedSet<T> {
+    /// Returns the number of elements in the set, or `None` if the number doesn't fit in a
+    /// `u128`.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::Rangetools;
+    ///
+    /// let s = (0..10).union(20..=25);
+    /// assert_eq!(s.count(), Some(16));
+    /// let s = (u128::MIN..5).union(10..=u128::MAX);
+    /// assert_eq!(s.count(), Some(u128::MAX - 4));
+    /// let s = (u128::MIN..5).union(5..=u128::MAX);
+    /// assert_eq!(s.count(), None);
+    /// ```
+    pub fn count(&self) -> Option<u128> {
+        self.ranges
+            .iter()
+            .try_fold(0u128, |count, r| count.checked_add(r.count()?))
+    }
+
+    /// Returns the smallest element of the set, or `None` if the set has no elements.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::Rangetools;
+    ///
+    /// let s = (3..5).union(10..=12);
+    /// assert_eq!(s.first(), Some(3));
+    /// ```
+    pub fn first(&self) -> Option<T> {
+        self.ranges.iter().find_map(BoundedRange::first)
+    }
+
+    /// Returns the largest element of the set, or `None` if the set has no elements.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::Rangetools;
+    ///
+    /// let s = (3..5).union(10..=12);
+    /// assert_eq!(s.last(), Some(12));
+    /// ```
+    pub fn last(&self) -> Option<T> {
+        self.ranges.iter().rev().find_map(BoundedRange::last)
+    }
+
+    /// Returns the smallest element of the set which is greater than or equal to `t`.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::Rangetools;
+    ///
+    /// let s = (1..5).union(10..20);
+    /// assert_eq!(s.next_at_or_after(3), Some(3));
+    /// assert_eq!(s.next_at_or_after(5), Some(10));
+    /// assert_eq!(s.next_at_or_after(20), None);
+    /// ```
+    pub fn next_at_or_after(&self, t: T) -> Option<T> {
+        self.ranges.range(self.index_not_below(&t)..).find_map(|r| {
+            if r.contains(&t) {
+                Some(t.clone())
+            } else {
+                r.first()
+            }
+        })
+    }
+
+    /// Returns the smallest element of the set which is strictly greater than `t`.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::Rangetools;
+    ///
+    /// let s = (1..5).union(10..20);
+    /// assert_eq!(s.next_after(3), Some(4));
+    /// assert_eq!(s.next_after(4), Some(10));
+    /// ```
+    pub fn next_after(&self, t: T) -> Option<T> {
+        Step::forward_checked(t, 1).and_then(|t| self.next_at_or_after(t))
+    }
+
+    /// Returns the largest element of the set which is less than or equal to `t`.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::Rangetools;
+    ///
+    /// let s = (1..5).union(10..20);
+    /// assert_eq!(s.prev_at_or_before(12), Some(12));
+    /// assert_eq!(s.prev_at_or_before(7), Some(4));
+    /// assert_eq!(s.prev_at_or_before(0), None);
+    /// ```
+    pub fn prev_at_or_before(&self, t: T) -> Option<T> {
+        self.ranges
+            .range(..self.index_above(&t))
+            .rev()
+            .find_map(|r| {
+                if r.contains(&t) {
+                    Some(t.clone())
+                } else {
+                    r.last()
+                }
+            })
+    }
+
+    /// Returns the largest element of the set which is strictly less than `t`.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::Rangetools;
+    ///
+    /// let s = (1..5).union(10..20);
+    /// assert_eq!(s.prev_before(12), Some(11));
+    /// assert_eq!(s.prev_before(10), Some(4));
+    /// ```
+    pub fn prev_before(&self, t: T) -> Option<T> {
+        Step::backward_checked(t, 1).and_then(|t| self.prev_at_or_before(t))
+    }
+
+    /// Returns the running totals of the number of elements in the ranges, truncated at the
+    /// first range whose running total overflows a `u128`.
+    ///
+    /// Computed on the first call after the set is modified and cached until the next change.
+    pub(crate) fn totals(&self) -> &[u128] {
+        self.totals.get_or_init(|| {
+            let mut totals = Vec::with_capacity(self.ranges.len());
+            let mut total = 0u128;
+            for range in self.ranges.iter() {
+                match range.count().and_then(|count| total.checked_add(count)) {
+                    Some(t) => total = t,
+                    None => break,
+                }
+                totals.push(total);
+            }
+            totals
+        })
+    }
+
+    /// Returns the number of elements of the set which are less than `t`.
+    ///
+    /// The running totals of the ranges making up the set are computed by the first rank or
+    /// select query, in linear time, and then kept until the set is next modified, so later
+    /// queries take logarithmic time.
+    ///
+    /// # Panics
+    /// Panics if the count overflows a `u128`, which can't happen for primitive integers. Use
+    /// [`order_statistics`](Self::order_statistics) for a checked version.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::Rangetools;
+    ///
+    /// let s = (0..10).union(100..110);
+    /// assert_eq!(s.rank(50), 10);
+    /// assert_eq!(s.rank(105), 15);
+    /// ```
+    pub fn rank(&self, t: T) -> u128 {
+        self.order_statistics()
+            .rank(t)
+            .expect("rank overflowed a `u128`")
+    }
+
+    /// Returns the `k`th smallest element of the set (counting from zero), or `None` if the
+    /// set has `k` or fewer elements.
+    ///
+    /// Takes logarithmic time, except for the first rank or select query after the set is
+    /// modified, as with [`rank`](Self::rank).
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::Rangetools;
+    ///
+    /// let s = (0..10).union(100..110);
+    /// assert_eq!(s.select(10), Some(100));
+    /// assert_eq!(s.select(20), None);
+    /// ```
+    pub fn select(&self, k: u128) -> Option<T> {
+        self.order_statistics().select(k)
+    }
+
+    /// Returns an index over the elements of the set answering [`rank`](OrderStatistics::rank)
+    /// and [`select`](OrderStatistics::select) queries in logarithmic time, returning `None`
+    /// rather than panicking when a count overflows.
+    ///
+    /// The index borrows the same cached running totals as [`rank`](Self::rank) and
+    /// [`select`](Self::select).
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::Rangetools;
+    ///
+    /// let s = (0..10).union(100..110);
+    /// let stats = s.order_statistics();
+    /// assert_eq!(stats.select(12), Some(102));
+    /// assert_eq!(stats.rank(102), Some(12));
+    /// ```
+    pub fn order_statistics(&self) -> OrderStatistics<'_, T> {
+        OrderStatistics::new(self, None)
+    }
+
+    /// Returns the set in canonical form: every range has both bounds included, ranges without
+    /// elements are dropped, and ranges with no elements between them are merged.
+    ///
+    /// Sets already compare equal when they contain the same elements, so this only changes how
+    /// the set is represented.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{BoundedRange, LowerBound, Rangetools, UpperBound};
+    ///
+    /// let s = (0..=2).union(3..=5);
+    /// assert_eq!(s, (0..6).to_set());
+    /// assert_eq!(s.normalize().ranges().count(), 1);
+    ///
+    /// let s = (0..3).union(BoundedRange::new(LowerBound::excluded(7), UpperBound::excluded(8)));
+    /// assert_eq!(s.normalize(), (0..=2).to_set());
+    /// ```
+    pub fn normalize(self) -> Self {
+        let mut ranges: VecDeque<BoundedRange<T>> = VecDeque::with_capacity(self.ranges.len());
+        for range in self.ranges.into_iter().filter_map(BoundedRange::normalize) {
+            match ranges.back_mut() {
+                Some(last) if BoundedRange::is_contiguous(&last.end, &range.start) => {
+                    last.end = range.end
+                }
+                _ => ranges.push_back(range),
+            }
+        }
+        Self::new(ranges)
+    }
+}
+
+impl<T: Element + Domain> BoundedSet<T> {
+    /// Returns true if the set contains every value of `T`.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::Rangetools;
+    ///
+    /// assert!((0u8..=10).union(11..=255).is_full());
+    /// assert!(!(0u8..=10).union(12..=255).is_full());
+    /// ```
+    pub fn is_full(&self) -> bool {
+        self.clone().complement_in_domain().ranges.is_empty()
+    }
+
+    /// Returns the values of `T` not contained in the set, as a normalized set.
+    ///
+    /// Unlike [`complement`](Rangetools::complement), the result doesn't include the unbounded
+    /// ranges below `T::MIN` or above `T::MAX`, so it is bounded.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::Rangetools;
+    ///
+    /// let c = (0u8..10).union(20..=255).complement_in_domain();
+    /// assert_eq!(c, (10..=19).to_set());
+    /// ```
+    pub fn complement_in_domain(self) -> BoundedSet<T> {
+        let complement: UnboundedSet<T> = self.complement();
+        domain().intersection(complement).normalize()
+    }
+
+    /// Returns the set in normalized form over the values of `T`.
+    ///
+    /// Sets over the same domain compare equal exactly when they contain the same elements,
+    /// whatever the range or set type they were built from. For a `BoundedSet` this is the
+    /// same as [`normalize`](Self::normalize).
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::Rangetools;
+    ///
+    /// let s = (0u8..3).union(3..=255);
+    /// assert_eq!(s.normalize_in_domain(), (0..=255).to_set());
+    /// ```
+    pub fn normalize_in_domain(self) -> BoundedSet<T> {
+        self.normalize()
+    }
+}
+
+impl<T: Measure + PartialOrd> BoundedSet<T> {
+    /// Returns the total width of the ranges making up the set.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::Rangetools;
+    /// use std::time::Duration;
+    ///
+    /// let s = (Duration::from_secs(0)..Duration::from_secs(3))
+    ///     .union(Duration::from_secs(10)..=Duration::from_secs(12));
+    /// assert_eq!(s.measure(), Duration::from_secs(5));
+    /// ```
+    pub fn measure(&self) -> T::Length {
+        self.ranges
+            .iter()
+            .fold(T::Length::default(), |length, r| length + r.measure())
+    }
+}
+
+/// Checks that a component starting at `start` can follow one with the given bounds in a set,
+/// where a missing `previous_start` is unbounded.
+pub(crate) fn check_follows<T: Ord>(
+    previous_start: Option<&LowerBound<T>>,
+    previous_end: &UpperBound<T>,
+    start: &LowerBound<T>,
+    index: usize,
+) -> Result<(), RangeError> {
+    if previous_start.is_some_and(|previous_start| start <= previous_start) {
+        Err(RangeError::Unsorted { index })
+    } else if merge::is_connected(previous_end, start) {
+        Err(RangeError::Overlapping { index })
+    } else {
+        Ok(())
+    }
+}
+
+/// A borrowing iterator over the disjoint [`BoundedRange`]s making up a set.
+///
+/// Created by the `ranges` method on [`BoundedSet`], [`LowerBoundedSet`](crate::LowerBoundedSet),
+/// [`UpperBoundedSet`](crate::UpperBoundedSet) and [`UnboundedSet`](crate::UnboundedSet).
+///
+/// # Example
+///
+/// ```
+/// # use rangetools::{BoundedSet, Ranges, Rangetools};
+/// let s: BoundedSet<i32> = (0..1).union(2..3);
+/// let iter: Ranges<i32> = s.ranges();
+/// ```
+#[derive(Clone, Debug)]
+pub struct Ranges<'a, T> {
+    iter: Option<vec_deque::Iter<'a, BoundedRange<T>>>,
+}
+
+impl<'a, T> Ranges<'a, T> {
+    pub(crate) fn new(ranges: &'a BoundedSet<T>) -> Self {
+        Self {
+            iter: Some(ranges.ranges.iter()),
+        }
+    }
+
+    pub(crate) fn empty() -> Self {
+        Self { iter: None }
+    }
+}
+
+impl<'a, T> Iterator for Ranges<'a, T> {
+    type Item = &'a BoundedRange<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.as_mut()?.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter
+            .as_ref()
+            .map(|i| i.size_hint())
+            .unwrap_or((0, Some(0)))
+    }
+}
+
+impl<T> DoubleEndedIterator for Ranges<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.as_mut()?.next_back()
+    }
+}
+
+impl<T> ExactSizeIterator for Ranges<'_, T> {}
+
+impl<T> FusedIterator for Ranges<'_, T> {}
+
+/// An iterator over the gaps between the components of a set.
+///
+/// Each gap is the [`BoundedRange`] of values lying strictly between two consecutive components.
+/// The unbounded pieces of a set's complement (before its first component or after its last
+/// one) are not gaps.
+///
+/// Created by the `gaps` method on [`BoundedSet`], [`LowerBoundedSet`](crate::LowerBoundedSet),
+/// [`UpperBoundedSet`](crate::UpperBoundedSet) and [`UnboundedSet`](crate::UnboundedSet).
+///
+/// # Example
+///
+/// ```
+/// # use rangetools::{BoundedRange, Gaps, LowerBoundedSet, Rangetools};
+/// let s: LowerBoundedSet<i32> = (0..1).union(2..3).union(5..);
+/// let gaps: Gaps<i32> = s.gaps();
+/// assert_eq!(gaps.collect::<Vec<_>>(), vec![BoundedRange::from(1..2), BoundedRange::from(3..5)]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Gaps<'a, T> {
+    /// End of the component preceding the remaining ranges, if there is one.
+    head: Option<UpperBound<T>>,
+    ranges: Ranges<'a, T>,
+    /// Start of the component following the remaining ranges, if there is one.
+    tail: Option<LowerBound<T>>,
+}
+
+impl<'a, T> Gaps<'a, T> {
+    pub(crate) fn new(
+        head: Option<UpperBound<T>>,
+        ranges: Ranges<'a, T>,
+        tail: Option<LowerBound<T>>,
+    ) -> Self {
+        Self { head, ranges, tail }
+    }
+}
+
+impl<T: Element> Iterator for Gaps<'_, T> {
+    type Item = BoundedRange<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let end = match self.head.take() {
+                Some(end) => end,
+                None => self.ranges.next()?.end.clone(),
+            };
+            let start = match self.ranges.next() {
+                Some(range) => {
+                    self.head = Some(range.end.clone());
+                    range.start.clone()
+                }
+                None => {
+                    self.head = None;
+                    self.tail.take()?
+                }
+            };
+            let gap = BoundedRange::new(LowerBound(end.0.flipped()), UpperBound(start.0.flipped()));
+            if !gap.is_empty() {
+                return Some(gap);
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let components =
+            self.ranges.len() + usize::from(self.head.is_some()) + usize::from(self.tail.is_some());
+        (0, Some(components.saturating_sub(1)))
+    }
+}
+
+impl<T: Element> DoubleEndedIterator for Gaps<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            let start = match self.tail.take() {
+                Some(start) => start,
+                None => self.ranges.next_back()?.start.clone(),
+            };
+            let end = match self.ranges.next_back() {
+                Some(range) => {
+                    self.tail = Some(range.start.clone());
+                    range.end.clone()
+                }
+                None => {
+                    self.tail = None;
+                    self.head.take()?
+                }
+            };
+            let gap = BoundedRange::new(LowerBound(end.0.flipped()), UpperBound(start.0.flipped()));
+            if !gap.is_empty() {
+                return Some(gap);
+            }
+        }
+    }
+}
+
+impl<T: Element> FusedIterator for Gaps<'_, T> {}
+
 /// An iterator over the values contained by a `BoundedSet`.
 ///
 /// Created by the `into_iter` method on `BoundedSet` (provided by the [`std::iter::IntoIterator`] trait).
@@ -114,9 +1038,18 @@ pub struct BoundedSetIter<T> {
     range_iters: VecDeque<BoundedRangeIter<T>>,
 }
 
+impl<T: Step> BoundedSetIter<T> {
+    /// Returns the number of remaining elements, or `None` if it doesn't fit in a `usize`.
+    fn remaining(&self) -> Option<usize> {
+        self.range_iters
+            .iter()
+            .try_fold(0usize, |size, i| size.checked_add(i.remaining()?))
+    }
+}
+
 impl<T> Iterator for BoundedSetIter<T>
 where
-    T: Copy + Ord + Step,
+    T: Element + Step,
 {
     type Item = T;
 
@@ -124,24 +1057,31 @@ where
         while self
             .range_iters
             .front()
-            .map(|i| i.len() == 0)
+            .map(BoundedRangeIter::is_exhausted)
             .unwrap_or(false)
         {
             self.range_iters.pop_front();
         }
-        self.range_iters.front_mut().map(|i| i.next()).flatten()
+        self.range_iters.front_mut().and_then(|i| i.next())
     }
 
+    /// If the number of remaining elements doesn't fit in a `usize`, the lower bound is
+    /// `usize::MAX` and there is no upper bound.
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let size = self.range_iters.iter().map(|i| i.len()).sum();
-        (size, Some(size))
+        match self.remaining() {
+            Some(size) => (size, Some(size)),
+            None => (usize::MAX, None),
+        }
     }
 
+    /// # Panics
+    /// Panics if the number of remaining elements doesn't fit in a `usize`. Use
+    /// [`BoundedSet::count`] to count them as a `u128`.
     fn count(self) -> usize
     where
         Self: Sized,
     {
-        self.len()
+        self.remaining().expect(TOO_MANY_ELEMENTS)
     }
 
     fn last(mut self) -> Option<Self::Item>
@@ -151,24 +1091,28 @@ where
         while self
             .range_iters
             .back()
-            .map(|i| i.len() == 0)
+            .map(BoundedRangeIter::is_exhausted)
             .unwrap_or(false)
         {
             self.range_iters.pop_back();
         }
-        self.range_iters.back_mut().map(|i| i.last()).flatten()
+        self.range_iters.back_mut().and_then(|i| i.last())
     }
 
     fn nth(&mut self, mut n: usize) -> Option<Self::Item> {
-        while let Some(len) = self.range_iters.front().map(|i| i.len()) {
+        while let Some(len) = self
+            .range_iters
+            .front()
+            .and_then(BoundedRangeIter::remaining)
+        {
             if len <= n {
                 n -= len;
-                self.range_iters.remove(0);
+                self.range_iters.pop_front();
             } else {
                 break;
             }
         }
-        self.range_iters.front_mut().map(|i| i.nth(n)).flatten()
+        self.range_iters.front_mut().and_then(|i| i.nth(n))
     }
 
     fn min(mut self) -> Option<Self::Item> {
@@ -182,22 +1126,26 @@ where
 
 impl<T> DoubleEndedIterator for BoundedSetIter<T>
 where
-    T: Copy + Ord + Step,
+    T: Element + Step,
 {
     fn next_back(&mut self) -> Option<Self::Item> {
         while self
             .range_iters
             .back()
-            .map(|i| i.len() == 0)
+            .map(BoundedRangeIter::is_exhausted)
             .unwrap_or(false)
         {
             self.range_iters.pop_back();
         }
-        self.range_iters.back_mut().map(|i| i.next_back()).flatten()
+        self.range_iters.back_mut().and_then(|i| i.next_back())
     }
 
     fn nth_back(&mut self, mut n: usize) -> Option<Self::Item> {
-        while let Some(len) = self.range_iters.back_mut().map(|i| i.len()) {
+        while let Some(len) = self
+            .range_iters
+            .back()
+            .and_then(BoundedRangeIter::remaining)
+        {
             if len <= n {
                 n -= len;
                 self.range_iters.pop_back();
@@ -205,10 +1153,22 @@ where
                 break;
             }
         }
-        self.range_iters.back_mut().map(|i| i.nth_back(n)).flatten()
+        self.range_iters.back_mut().and_then(|i| i.nth_back(n))
     }
 }
 
-impl<T> ExactSizeIterator for BoundedSetIter<T> where T: Copy + Ord + Step {}
+/// The length is only known for up to `usize::MAX` elements; [`BoundedSet::count`] counts wider
+/// ones exactly.
+impl<T> ExactSizeIterator for BoundedSetIter<T>
+where
+    T: Element + Step,
+{
+    /// # Panics
+    /// Panics if the number of remaining elements doesn't fit in a `usize`. Use
+    /// [`BoundedSet::count`] to count them as a `u128`.
+    fn len(&self) -> usize {
+        self.remaining().expect(TOO_MANY_ELEMENTS)
+    }
+}
 
-impl<T> FusedIterator for BoundedSetIter<T> where T: Copy + Ord + Step {}
+impl<T> FusedIterator for BoundedSetIter<T> where T: Element + Step {}
@@ -0,0 +1,168 @@
+use crate::{
+    BoundedRange, Element, LowerBound, LowerBoundedRange, Ranges, UnboundedRange, UpperBound,
+    UpperBoundedRange,
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::borrow::Borrow;
+use std::hash::{Hash, Hasher};
+use std::iter::FusedIterator;
+
+/// One of the disjoint ranges making up a set.
+///
+/// Yielded by the `components` method on the set types, and returned by their `range_containing`
+/// method, since the components of a set aren't all of the same range type.
+/// ```
+/// use rangetools::{BoundedRange, Component, LowerBoundedRange, Rangetools};
+///
+/// let s = (0..5).union(10..);
+/// let components: Vec<_> = s.components().collect();
+/// assert_eq!(
+///     components,
+///     vec![
+///         Component::Bounded(BoundedRange::from(0..5)),
+///         Component::LowerBounded(LowerBoundedRange::from(10..)),
+///     ]
+/// );
+/// assert_eq!(s.range_containing(3), Some(Component::Bounded(BoundedRange::from(0..5))));
+/// assert_eq!(s.range_containing(7), None);
+/// ```
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub enum Component<T> {
+    /// A component bounded both below and above.
+    Bounded(BoundedRange<T>),
+    /// The trailing component of a set with no upper bound.
+    LowerBounded(LowerBoundedRange<T>),
+    /// The leading component of a set with no lower bound.
+    UpperBounded(UpperBoundedRange<T>),
+    /// The only component of a full set.
+    Unbounded(UnboundedRange),
+}
+
+impl<T: Element> PartialEq for Component<T> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Bounded(a), Self::Bounded(b)) => a == b,
+            (Self::LowerBounded(a), Self::LowerBounded(b)) => a == b,
+            (Self::UpperBounded(a), Self::UpperBounded(b)) => a == b,
+            (Self::Unbounded(a), Self::Unbounded(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl<T: Element> Eq for Component<T> {}
+
+impl<T: Element + Hash> Hash for Component<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Self::Bounded(r) => r.hash(state),
+            Self::LowerBounded(r) => r.hash(state),
+            Self::UpperBounded(r) => r.hash(state),
+            Self::Unbounded(r) => r.hash(state),
+        }
+    }
+}
+
+impl<T> Component<T> {
+    /// Returns the bounds of the component, where `None` stands for an unbounded side.
+    pub(crate) fn into_bounds(self) -> (Option<LowerBound<T>>, Option<UpperBound<T>>) {
+        match self {
+            Self::Bounded(r) => (Some(r.start), Some(r.end)),
+            Self::LowerBounded(r) => (Some(r.start), None),
+            Self::UpperBounded(r) => (None, Some(r.end)),
+            Self::Unbounded(_) => (None, None),
+        }
+    }
+}
+
+impl<T: Element> Component<T> {
+    /// Returns true if the component contains `t`, which can be passed by value or by reference.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{Component, Rangetools};
+    ///
+    /// let c = Component::Bounded((0..5).to_inner());
+    /// assert!(c.contains(3));
+    /// assert!(!c.contains(&5));
+    /// ```
+    pub fn contains<Q: Borrow<T>>(&self, t: Q) -> bool {
+        let t = t.borrow();
+        match self {
+            Self::Bounded(r) => r.contains(t),
+            Self::LowerBounded(r) => r.contains(t),
+            Self::UpperBounded(r) => r.contains(t),
+            Self::Unbounded(r) => r.contains(t),
+        }
+    }
+}
+
+/// An iterator over the disjoint [`Component`]s of a set, in ascending order.
+///
+/// Created by the `components` method on [`BoundedSet`](crate::BoundedSet),
+/// [`LowerBoundedSet`](crate::LowerBoundedSet), [`UpperBoundedSet`](crate::UpperBoundedSet) and
+/// [`UnboundedSet`](crate::UnboundedSet), and by
+/// [`RangeQuery::components`](crate::RangeQuery::components) for any range or set. Unlike
+/// [`Ranges`], it includes the unbounded components of a set.
+///
+/// # Example
+///
+/// ```
+/// # use rangetools::{Components, Rangetools, UnboundedSet};
+/// let s: UnboundedSet<i32> = (..0).union(2..3).union(5..);
+/// let components: Components<i32> = s.components();
+/// assert_eq!(components.len(), 3);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Components<'a, T> {
+    head: Option<Component<T>>,
+    ranges: Ranges<'a, T>,
+    tail: Option<Component<T>>,
+}
+
+impl<'a, T> Components<'a, T> {
+    pub(crate) fn new(
+        head: Option<Component<T>>,
+        ranges: Ranges<'a, T>,
+        tail: Option<Component<T>>,
+    ) -> Self {
+        Self { head, ranges, tail }
+    }
+
+    pub(crate) fn single(component: Option<Component<T>>) -> Self {
+        Self::new(component, Ranges::empty(), None)
+    }
+}
+
+impl<T: Clone> Iterator for Components<'_, T> {
+    type Item = Component<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.head
+            .take()
+            .or_else(|| self.ranges.next().cloned().map(Component::Bounded))
+            .or_else(|| self.tail.take())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len =
+            self.ranges.len() + usize::from(self.head.is_some()) + usize::from(self.tail.is_some());
+        (len, Some(len))
+    }
+}
+
+impl<T: Clone> DoubleEndedIterator for Components<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.tail
+            .take()
+            .or_else(|| self.ranges.next_back().cloned().map(Component::Bounded))
+            .or_else(|| self.head.take())
+    }
+}
+
+impl<T: Clone> ExactSizeIterator for Components<'_, T> {}
+
+impl<T: Clone> FusedIterator for Components<'_, T> {}
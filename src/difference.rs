@@ -0,0 +1,14 @@
+use crate::Rangetools;
+
+/// Helper trait for performing range difference.
+///
+/// In most cases, users should import the `Rangetools` trait instead of this one, as it performs
+/// the necessary forwarding.  See [`.difference()`](crate::Rangetools::difference) for more information.
+pub trait RangeDifference<Rhs, RhsInner> {
+    /// The output type of the difference.
+    type Output;
+    /// Returns the set difference of `self` and `other`.
+    fn difference(self, other: Rhs) -> Self::Output
+    where
+        Rhs: Rangetools<Inner = RhsInner>;
+}
@@ -0,0 +1,40 @@
+use crate::{BoundedRange, Element, LowerBound, Step, UpperBound};
+
+/// Types with a smallest and a largest value.
+///
+/// Types are required to implement this trait for the domain-aware methods on ranges and sets
+/// of that type (`is_full`, `complement_in_domain` and `normalize_in_domain`), which treat a
+/// range reaching `MIN` or `MAX` as though it were unbounded on that side.
+///
+/// # Example
+/// ```
+/// use rangetools::{Domain, Rangetools};
+///
+/// assert_eq!(<u8 as Domain>::MAX, 255);
+/// assert!((0u8..).to_inner().is_full());
+/// assert!((..=10u8).union(11..=255).is_full());
+/// ```
+pub trait Domain: Step {
+    /// The smallest value of the type.
+    const MIN: Self;
+    /// The largest value of the type.
+    const MAX: Self;
+}
+
+macro_rules! domain_impls {
+    ($($t:ty)*) => {
+        $(
+            impl Domain for $t {
+                const MIN: Self = <$t>::MIN;
+                const MAX: Self = <$t>::MAX;
+            }
+        )*
+    };
+}
+
+domain_impls! { u8 i8 u16 i16 u32 i32 u64 i64 u128 i128 usize isize char }
+
+/// Returns the range of every value of `T`.
+pub(crate) fn domain<T: Element + Domain>() -> BoundedRange<T> {
+    BoundedRange::new(LowerBound::included(T::MIN), UpperBound::included(T::MAX))
+}
@@ -0,0 +1,102 @@
+use crate::{merge, Bound, Component, LowerBound, Step, TotalF32, TotalF64, UpperBound};
+use std::time::{Duration, Instant, SystemTime};
+
+/// The element type of the ranges and sets in this crate.
+///
+/// The ranges and sets compare, hash and check for emptiness in canonical form, so that two
+/// of them are equal exactly when they contain the same elements. For a [`Step`] type this means
+/// bounds are first converted to a half-open form, so that, for example, `(0..=2).union(3..=5)`
+/// equals `(0..6).to_set()` and a range from `Excluded(3)` to `Excluded(4)` is empty. Other
+/// types are treated as dense, where the bounds are already canonical.
+///
+/// Implemented for every `Step` type, for the dense types `String`, `&str`, `Vec<T>`,
+/// `Duration`, `Instant`, `SystemTime`, [`TotalF32`] and [`TotalF64`], and for tuples of up to
+/// twelve `Ord` types. Any other `Ord` type can implement it without overriding any methods,
+/// which compares its ranges and sets by their bounds.
+///
+/// # Example
+/// ```
+/// use rangetools::{Element, Rangetools};
+///
+/// #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+/// struct Name(String);
+/// impl Element for Name {}
+///
+/// let s = (Name("a".into())..Name("c".into())).union(Name("c".into())..Name("e".into()));
+/// assert_eq!(s, (Name("a".into())..Name("e".into())).to_set());
+/// ```
+pub trait Element: Clone + Ord {
+    /// Returns the lower bound in canonical form. Leaves the bound unchanged by default.
+    fn canonical_lower(bound: LowerBound<Self>) -> LowerBound<Self> {
+        bound
+    }
+
+    /// Returns the upper bound in canonical form. Leaves the bound unchanged by default.
+    fn canonical_upper(bound: UpperBound<Self>) -> UpperBound<Self> {
+        bound
+    }
+}
+
+/// Lower bounds are included and upper bounds excluded wherever the neighbouring value exists,
+/// so that components with no elements between them touch.
+impl<T: Clone + Ord + Step> Element for T {
+    fn canonical_lower(bound: LowerBound<Self>) -> LowerBound<Self> {
+        match bound.0 {
+            Bound::Excluded(t) => match Step::forward_checked(t.clone(), 1) {
+                Some(next) => LowerBound::included(next),
+                None => LowerBound::excluded(t),
+            },
+            Bound::Included(t) => LowerBound::included(t),
+        }
+    }
+
+    fn canonical_upper(bound: UpperBound<Self>) -> UpperBound<Self> {
+        match bound.0 {
+            Bound::Included(t) => match Step::forward_checked(t.clone(), 1) {
+                Some(next) => UpperBound::excluded(next),
+                None => UpperBound::included(t),
+            },
+            Bound::Excluded(t) => UpperBound::excluded(t),
+        }
+    }
+}
+
+impl Element for String {}
+impl Element for &str {}
+impl<T: Clone + Ord> Element for Vec<T> {}
+impl Element for Duration {}
+impl Element for Instant {}
+impl Element for SystemTime {}
+impl Element for TotalF32 {}
+impl Element for TotalF64 {}
+
+macro_rules! tuple_element {
+    ($($t:ident)+) => {
+        impl<$($t: Clone + Ord),+> Element for ($($t,)+) {}
+    };
+}
+
+tuple_element! { A }
+tuple_element! { A B }
+tuple_element! { A B C }
+tuple_element! { A B C D }
+tuple_element! { A B C D E }
+tuple_element! { A B C D E F }
+tuple_element! { A B C D E F G }
+tuple_element! { A B C D E F G H }
+tuple_element! { A B C D E F G H I }
+tuple_element! { A B C D E F G H I J }
+tuple_element! { A B C D E F G H I J K }
+tuple_element! { A B C D E F G H I J K L }
+
+/// Returns true if the component contains at least one element, once its bounds are in
+/// canonical form.
+pub(crate) fn has_elements<T: Element>(component: Component<T>) -> bool {
+    match component {
+        Component::Bounded(r) => !merge::is_empty(
+            Some(&T::canonical_lower(r.start)),
+            Some(&T::canonical_upper(r.end)),
+        ),
+        _ => true,
+    }
+}
@@ -1,6 +1,10 @@
-use crate::{BoundedRange, LowerBound, Step, UpperBound};
+use crate::{
+    bounded_range, domain::domain, BoundedRange, BoundedSet, Domain, Element, Measure, Rangetools,
+    Step,
+};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+use std::borrow::Borrow;
 use std::marker::PhantomData;
 
 /// A range with no elements.
@@ -30,14 +34,16 @@ where
 
 impl<T> From<EmptyRange<T>> for std::ops::RangeInclusive<T>
 where
-    T: Copy + Default + Step,
+    T: Clone + Default + Step,
 {
     fn from(_: EmptyRange<T>) -> Self {
-        let r = BoundedRange {
-            start: LowerBound::included(T::default()),
-            end: UpperBound::excluded(T::default()),
-        };
-        r.into()
+        bounded_range::empty_inclusive(T::default())
+    }
+}
+
+impl<T: Element> PartialEq<BoundedRange<T>> for EmptyRange<T> {
+    fn eq(&self, other: &BoundedRange<T>) -> bool {
+        other == self
     }
 }
 
@@ -60,9 +66,7 @@ impl<T> EmptyRange<T> {
     /// assert!(r.is_empty());
     /// ```
     pub fn new() -> Self {
-        Self {
-            t: PhantomData::default(),
-        }
+        Self { t: PhantomData }
     }
 
     /// Returns true if the range contains the given item.
@@ -77,8 +81,111 @@ impl<T> EmptyRange<T> {
     ///
     /// let r = EmptyRange::new();
     /// assert!(!r.contains(5));
+    /// assert!(!r.contains(&5));
+    /// ```
+    pub fn contains<Q: Borrow<T>>(&self, _: Q) -> bool {
+        false
+    }
+
+    /// Returns the number of elements in the range, which is always zero.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::EmptyRange;
+    ///
+    /// assert_eq!(EmptyRange::<i32>::new().count(), Some(0));
+    /// ```
+    pub fn count(&self) -> Option<u128> {
+        Some(0)
+    }
+
+    /// Returns the smallest range containing every element of the range, ie the range itself.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::EmptyRange;
+    ///
+    /// let r = EmptyRange::<i32>::new();
+    /// assert_eq!(r.span(), r);
+    /// ```
+    #[doc(alias = "hull")]
+    pub fn span(&self) -> EmptyRange<T> {
+        Self::new()
+    }
+
+    /// Returns the smallest element of the range, which is always `None`.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::EmptyRange;
+    ///
+    /// assert_eq!(EmptyRange::<i32>::new().first(), None);
+    /// ```
+    pub fn first(&self) -> Option<T> {
+        None
+    }
+
+    /// Returns the largest element of the range, which is always `None`.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::EmptyRange;
+    ///
+    /// assert_eq!(EmptyRange::<i32>::new().last(), None);
+    /// ```
+    pub fn last(&self) -> Option<T> {
+        None
+    }
+}
+
+impl<T: Element + Domain> EmptyRange<T> {
+    /// Returns true if the range contains every value of `T`, which is never the case.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::EmptyRange;
+    ///
+    /// assert!(!EmptyRange::<u8>::new().is_full());
     /// ```
-    pub fn contains(&self, _: T) -> bool {
+    pub fn is_full(&self) -> bool {
         false
     }
+
+    /// Returns every value of `T`, as a normalized set.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{EmptyRange, Rangetools};
+    ///
+    /// assert_eq!(EmptyRange::<u8>::new().complement_in_domain(), (0..=255).to_set());
+    /// ```
+    pub fn complement_in_domain(self) -> BoundedSet<T> {
+        domain().to_set().normalize()
+    }
+
+    /// Returns the range as a normalized set over the values of `T`, which is always empty.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{BoundedSet, EmptyRange};
+    ///
+    /// assert_eq!(EmptyRange::<u8>::new().normalize_in_domain(), BoundedSet::empty());
+    /// ```
+    pub fn normalize_in_domain(self) -> BoundedSet<T> {
+        BoundedSet::empty()
+    }
+}
+
+impl<T: Measure> EmptyRange<T> {
+    /// Returns the width of the range, which is always zero.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::EmptyRange;
+    ///
+    /// assert_eq!(EmptyRange::<f64>::new().measure(), 0.0);
+    /// ```
+    pub fn measure(&self) -> T::Length {
+        T::Length::default()
+    }
 }
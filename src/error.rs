@@ -0,0 +1,79 @@
+use std::fmt;
+
+/// An error returned by the fallible conversions and constructors in this crate.
+///
+/// # Example
+/// ```
+/// use rangetools::{BoundedRange, RangeError, Rangetools, UpperBoundedRange};
+///
+/// let r = UpperBoundedRange::from(..=u32::MAX);
+/// assert_eq!(std::ops::RangeTo::try_from(r), Err(RangeError::Overflow));
+///
+/// let s = (0..3).union(5..8);
+/// assert_eq!(BoundedRange::try_from(s), Err(RangeError::MultipleComponents));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RangeError {
+    /// Converting a bound between its inclusive and exclusive forms would step past the
+    /// smallest or largest value of the type.
+    Overflow,
+    /// The set has no components, so can't be converted to a range.
+    Empty,
+    /// The set has more than one component, so can't be converted to a single range.
+    MultipleComponents,
+    /// The floating point value is NaN, so has no place in a total order.
+    NotANumber,
+    /// The [`AnySet`](crate::AnySet) holds a range or set which can't be converted to the
+    /// requested type.
+    IncompatibleType,
+    /// The [`std::ops::Bound`] is `Unbounded`, so has no value to convert.
+    Unbounded,
+    /// The component at `index` of a set being constructed is empty.
+    EmptyComponent {
+        /// The position of the component in the set, in ascending order.
+        index: usize,
+    },
+    /// The component at `index` of a set being constructed starts no later than the component
+    /// before it.
+    Unsorted {
+        /// The position of the component in the set, in ascending order.
+        index: usize,
+    },
+    /// The component at `index` of a set being constructed overlaps or touches the component
+    /// before it.
+    Overlapping {
+        /// The position of the component in the set, in ascending order.
+        index: usize,
+    },
+}
+
+impl fmt::Display for RangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Overflow => f.write_str("range bound overflows its type"),
+            Self::Empty => f.write_str("set has no components"),
+            Self::MultipleComponents => f.write_str("set has more than one component"),
+            Self::NotANumber => f.write_str("value is NaN"),
+            Self::IncompatibleType => f.write_str("set can't be converted to the requested type"),
+            Self::Unbounded => f.write_str("bound is unbounded"),
+            Self::EmptyComponent { index } => write!(f, "component {} is empty", index),
+            Self::Unsorted { index } => {
+                write!(
+                    f,
+                    "component {} starts no later than the previous component",
+                    index
+                )
+            }
+            Self::Overlapping { index } => {
+                write!(
+                    f,
+                    "component {} overlaps or touches the previous component",
+                    index
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for RangeError {}
@@ -0,0 +1,144 @@
+use crate::{Measure, RangeError};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+};
+
+macro_rules! total_float {
+    ($(#[$attr:meta])* $name:ident, $t:ident, $t_str:literal) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, Debug, Default, PartialEq)]
+        #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+        #[cfg_attr(feature = "serde", serde(try_from = $t_str, into = $t_str))]
+        pub struct $name($t);
+
+        impl $name {
+            /// Wraps `t`, or returns `None` if `t` is NaN. Negative zero is stored as positive zero.
+            ///
+            /// # Example
+            /// ```
+            #[doc = concat!("use rangetools::", stringify!($name), ";")]
+            ///
+            #[doc = concat!("assert!(", stringify!($name), "::new(1.5).is_some());")]
+            #[doc = concat!("assert!(", stringify!($name), "::new(", stringify!($t), "::NAN).is_none());")]
+            #[doc = concat!("assert_eq!(", stringify!($name), "::new(-0.0), ", stringify!($name), "::new(0.0));")]
+            /// ```
+            pub fn new(t: $t) -> Option<Self> {
+                if t.is_nan() {
+                    None
+                } else {
+                    // Adding positive zero turns negative zero into positive zero and leaves
+                    // every other value unchanged.
+                    Some(Self(t + 0.0))
+                }
+            }
+
+            /// Returns the wrapped value.
+            ///
+            /// # Example
+            /// ```
+            #[doc = concat!("use rangetools::", stringify!($name), ";")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($name), "::new(2.5).unwrap().get(), 2.5);")]
+            /// ```
+            pub fn get(self) -> $t {
+                self.0
+            }
+        }
+
+        impl Eq for $name {}
+
+        impl PartialOrd for $name {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for $name {
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.0.total_cmp(&other.0)
+            }
+        }
+
+        impl Hash for $name {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.0.to_bits().hash(state);
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(&self.0, f)
+            }
+        }
+
+        impl TryFrom<$t> for $name {
+            type Error = RangeError;
+
+            fn try_from(t: $t) -> Result<Self, Self::Error> {
+                Self::new(t).ok_or(RangeError::NotANumber)
+            }
+        }
+
+        impl From<$name> for $t {
+            fn from(t: $name) -> Self {
+                t.0
+            }
+        }
+
+        impl Measure for $name {
+            type Length = $t;
+
+            #[inline]
+            fn length_between(start: &Self, end: &Self) -> Self::Length {
+                end.0 - start.0
+            }
+        }
+    };
+}
+
+total_float! {
+    /// An `f32` which is never NaN, so is totally ordered and can be used as the element type
+    /// of this crate's ranges and sets.
+    ///
+    /// Negative zero is stored as positive zero, so the two compare (and hash) equal as they
+    /// do for `f32`. Infinities are ordinary values, below or above every finite value.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{Rangetools, TotalF32};
+    ///
+    /// let f = |t| TotalF32::new(t).unwrap();
+    /// let r = (f(0.0)..f(1.0)).intersection(f(0.5)..);
+    /// assert!(r.contains(f(0.75)));
+    /// assert!(r.contains(f(0.5)));
+    /// assert!(!r.contains(f(1.0)));
+    /// ```
+    TotalF32, f32, "f32"
+}
+
+total_float! {
+    /// An `f64` which is never NaN, so is totally ordered and can be used as the element type
+    /// of this crate's ranges and sets.
+    ///
+    /// Negative zero is stored as positive zero, so the two compare (and hash) equal as they
+    /// do for `f64`. Infinities are ordinary values, below or above every finite value.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{Rangetools, TotalF64};
+    ///
+    /// let f = |t| TotalF64::new(t).unwrap();
+    /// let bands = (f(0.0)..f(10.0)).union(f(20.0)..=f(f64::INFINITY));
+    /// assert!(bands.contains(f(5.0)));
+    /// assert!(bands.contains(f(f64::INFINITY)));
+    /// assert!(!bands.contains(f(15.0)));
+    ///
+    /// // NaN can't be wrapped, so is never contained in a range.
+    /// assert!(TotalF64::new(f64::NAN).map_or(true, |t| !bands.contains(t)));
+    /// ```
+    TotalF64, f64, "f64"
+}
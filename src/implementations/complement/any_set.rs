@@ -0,0 +1,50 @@
+use crate::{
+    AnySet, BoundedSet, Element, EmptyRange, LowerBoundedRange, LowerBoundedSet, RangeComplement,
+    UnboundedRange, UnboundedSet, UpperBoundedRange, UpperBoundedSet,
+};
+
+impl<T> RangeComplement<AnySet<T>> for AnySet<T>
+where
+    T: Element,
+{
+    fn complement(self) -> AnySet<T> {
+        match self {
+            Self::EmptyRange(r) => {
+                let complement: UnboundedRange = RangeComplement::complement(r);
+                complement.into()
+            }
+            Self::BoundedRange(r) => {
+                let complement: UnboundedSet<T> = RangeComplement::complement(r);
+                complement.into()
+            }
+            Self::BoundedSet(s) => {
+                let complement: UnboundedSet<T> = RangeComplement::complement(s);
+                complement.into()
+            }
+            Self::LowerBoundedRange(r) => {
+                let complement: UpperBoundedRange<T> = RangeComplement::complement(r);
+                complement.into()
+            }
+            Self::LowerBoundedSet(s) => {
+                let complement: UpperBoundedSet<T> = RangeComplement::complement(s);
+                complement.into()
+            }
+            Self::UpperBoundedRange(r) => {
+                let complement: LowerBoundedRange<T> = RangeComplement::complement(r);
+                complement.into()
+            }
+            Self::UpperBoundedSet(s) => {
+                let complement: LowerBoundedSet<T> = RangeComplement::complement(s);
+                complement.into()
+            }
+            Self::UnboundedRange(r) => {
+                let complement: EmptyRange<T> = RangeComplement::complement(r);
+                complement.into()
+            }
+            Self::UnboundedSet(s) => {
+                let complement: BoundedSet<T> = RangeComplement::complement(s);
+                complement.into()
+            }
+        }
+    }
+}
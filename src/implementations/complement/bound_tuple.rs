@@ -0,0 +1,10 @@
+use crate::{AnySet, Element, RangeComplement, Rangetools};
+
+impl<T> RangeComplement<AnySet<T>> for (std::ops::Bound<T>, std::ops::Bound<T>)
+where
+    T: Element,
+{
+    fn complement(self) -> AnySet<T> {
+        RangeComplement::complement(self.to_inner())
+    }
+}
@@ -1,10 +1,11 @@
 use crate::{
-    BoundedRange, LowerBoundedRange, RangeComplement, Rangetools, UnboundedSet, UpperBoundedRange,
+    BoundedRange, Element, LowerBoundedRange, RangeComplement, Rangetools, UnboundedSet,
+    UpperBoundedRange,
 };
 
 impl<T> RangeComplement<UnboundedSet<T>> for BoundedRange<T>
 where
-    T: Copy + Ord,
+    T: Element,
 {
     fn complement(self) -> UnboundedSet<T> {
         UpperBoundedRange::new(self.start.0.flipped().into())
@@ -1,14 +1,19 @@
-use crate::{BoundedSet, RangeComplement, RangeIntersection, UnboundedSet};
+use crate::{merge, BoundedSet, Element, RangeComplement, UnboundedSet};
 
 impl<T> RangeComplement<UnboundedSet<T>> for BoundedSet<T>
 where
-    T: Copy + Ord,
+    T: Element,
 {
     fn complement(self) -> UnboundedSet<T> {
-        self.ranges
-            .into_iter()
-            .fold(UnboundedSet::Full, |acc, range| {
-                acc.intersection(range.complement())
-            })
+        RangeComplement::complement(&self)
+    }
+}
+
+impl<T> RangeComplement<UnboundedSet<T>> for &BoundedSet<T>
+where
+    T: Element,
+{
+    fn complement(self) -> UnboundedSet<T> {
+        merge::complement(self.into()).into()
     }
 }
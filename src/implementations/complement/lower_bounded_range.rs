@@ -1,8 +1,8 @@
-use crate::{LowerBoundedRange, RangeComplement, UpperBoundedRange};
+use crate::{Element, LowerBoundedRange, RangeComplement, UpperBoundedRange};
 
 impl<T> RangeComplement<UpperBoundedRange<T>> for LowerBoundedRange<T>
 where
-    T: Copy + Ord,
+    T: Element,
 {
     fn complement(self) -> UpperBoundedRange<T> {
         UpperBoundedRange::new(self.start.0.flipped().into())
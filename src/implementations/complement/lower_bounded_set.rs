@@ -1,13 +1,19 @@
-use crate::{LowerBoundedSet, RangeComplement, RangeIntersection, Rangetools, UpperBoundedSet};
+use crate::{merge, Element, LowerBoundedSet, RangeComplement, UpperBoundedSet};
 
 impl<T> RangeComplement<UpperBoundedSet<T>> for LowerBoundedSet<T>
 where
-    T: Copy + Ord,
+    T: Element,
 {
     fn complement(self) -> UpperBoundedSet<T> {
-        self.ranges.ranges.into_iter().fold(
-            RangeComplement::complement(self.lower_bounded_range).to_set(),
-            |acc, range| RangeIntersection::intersection(acc, RangeComplement::complement(range)),
-        )
+        RangeComplement::complement(&self)
+    }
+}
+
+impl<T> RangeComplement<UpperBoundedSet<T>> for &LowerBoundedSet<T>
+where
+    T: Element,
+{
+    fn complement(self) -> UpperBoundedSet<T> {
+        merge::complement(self.into()).into()
     }
 }
@@ -1,8 +1,8 @@
-use crate::{RangeComplement, Rangetools, UnboundedSet};
+use crate::{Element, RangeComplement, Rangetools, UnboundedSet};
 
 impl<T> RangeComplement<UnboundedSet<T>> for std::ops::Range<T>
 where
-    T: Copy + Ord,
+    T: Element,
 {
     fn complement(self) -> UnboundedSet<T> {
         RangeComplement::complement(self.to_inner())
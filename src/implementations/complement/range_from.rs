@@ -1,8 +1,8 @@
-use crate::{RangeComplement, Rangetools, UpperBoundedRange};
+use crate::{Element, RangeComplement, Rangetools, UpperBoundedRange};
 
 impl<T> RangeComplement<UpperBoundedRange<T>> for std::ops::RangeFrom<T>
 where
-    T: Copy + Ord,
+    T: Element,
 {
     fn complement(self) -> UpperBoundedRange<T> {
         RangeComplement::complement(self.to_inner())
@@ -1,8 +1,8 @@
-use crate::{LowerBoundedRange, RangeComplement, Rangetools};
+use crate::{Element, LowerBoundedRange, RangeComplement, Rangetools};
 
 impl<T> RangeComplement<LowerBoundedRange<T>> for std::ops::RangeToInclusive<T>
 where
-    T: Copy + Ord,
+    T: Element,
 {
     fn complement(self) -> LowerBoundedRange<T> {
         RangeComplement::complement(self.to_inner())
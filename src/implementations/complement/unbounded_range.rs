@@ -1,4 +1,4 @@
-use crate::{EmptyRange, UnboundedRange, RangeComplement};
+use crate::{EmptyRange, RangeComplement, UnboundedRange};
 
 impl<T> RangeComplement<EmptyRange<T>> for UnboundedRange {
     fn complement(self) -> EmptyRange<T> {
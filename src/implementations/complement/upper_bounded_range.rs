@@ -1,8 +1,8 @@
-use crate::{LowerBoundedRange, RangeComplement, UpperBoundedRange};
+use crate::{Element, LowerBoundedRange, RangeComplement, UpperBoundedRange};
 
 impl<T> RangeComplement<LowerBoundedRange<T>> for UpperBoundedRange<T>
 where
-    T: Copy + Ord,
+    T: Element,
 {
     fn complement(self) -> LowerBoundedRange<T> {
         LowerBoundedRange::new(self.end.0.flipped().into())
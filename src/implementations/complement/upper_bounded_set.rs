@@ -1,13 +1,19 @@
-use crate::{LowerBoundedSet, RangeComplement, RangeIntersection, Rangetools, UpperBoundedSet};
+use crate::{merge, Element, LowerBoundedSet, RangeComplement, UpperBoundedSet};
 
 impl<T> RangeComplement<LowerBoundedSet<T>> for UpperBoundedSet<T>
 where
-    T: Copy + Ord,
+    T: Element,
 {
     fn complement(self) -> LowerBoundedSet<T> {
-        self.ranges.ranges.into_iter().fold(
-            RangeComplement::complement(self.upper_bounded_range).to_set(),
-            |acc, range| RangeIntersection::intersection(acc, RangeComplement::complement(range)),
-        )
+        RangeComplement::complement(&self)
+    }
+}
+
+impl<T> RangeComplement<LowerBoundedSet<T>> for &UpperBoundedSet<T>
+where
+    T: Element,
+{
+    fn complement(self) -> LowerBoundedSet<T> {
+        merge::complement(self.into()).into()
     }
 }
@@ -0,0 +1,44 @@
+use crate::{
+    AnySet, BoundedRange, BoundedSet, EmptyRange, LowerBoundedRange, LowerBoundedSet,
+    RangeDifference, Rangetools, UnboundedSet, UpperBoundedRange, UpperBoundedSet,
+};
+
+impl<T, R, RhsInner> RangeDifference<R, RhsInner> for AnySet<T>
+where
+    R: Rangetools<Inner = RhsInner>,
+    EmptyRange<T>: RangeDifference<R, RhsInner>,
+    BoundedRange<T>: RangeDifference<R, RhsInner>,
+    BoundedSet<T>: RangeDifference<R, RhsInner>,
+    LowerBoundedRange<T>: RangeDifference<R, RhsInner>,
+    LowerBoundedSet<T>: RangeDifference<R, RhsInner>,
+    UpperBoundedRange<T>: RangeDifference<R, RhsInner>,
+    UpperBoundedSet<T>: RangeDifference<R, RhsInner>,
+    UnboundedSet<T>: RangeDifference<R, RhsInner>,
+    AnySet<T>: From<<EmptyRange<T> as RangeDifference<R, RhsInner>>::Output>,
+    AnySet<T>: From<<BoundedRange<T> as RangeDifference<R, RhsInner>>::Output>,
+    AnySet<T>: From<<BoundedSet<T> as RangeDifference<R, RhsInner>>::Output>,
+    AnySet<T>: From<<LowerBoundedRange<T> as RangeDifference<R, RhsInner>>::Output>,
+    AnySet<T>: From<<LowerBoundedSet<T> as RangeDifference<R, RhsInner>>::Output>,
+    AnySet<T>: From<<UpperBoundedRange<T> as RangeDifference<R, RhsInner>>::Output>,
+    AnySet<T>: From<<UpperBoundedSet<T> as RangeDifference<R, RhsInner>>::Output>,
+    AnySet<T>: From<<UnboundedSet<T> as RangeDifference<R, RhsInner>>::Output>,
+{
+    type Output = AnySet<T>;
+    fn difference(self, other: R) -> Self::Output {
+        match self {
+            Self::EmptyRange(r) => RangeDifference::difference(r, other).into(),
+            Self::BoundedRange(r) => RangeDifference::difference(r, other).into(),
+            Self::BoundedSet(r) => RangeDifference::difference(r, other).into(),
+            Self::LowerBoundedRange(r) => RangeDifference::difference(r, other).into(),
+            Self::LowerBoundedSet(r) => RangeDifference::difference(r, other).into(),
+            Self::UpperBoundedRange(r) => RangeDifference::difference(r, other).into(),
+            Self::UpperBoundedSet(r) => RangeDifference::difference(r, other).into(),
+            // With no element type, `UnboundedRange` can't be combined with another
+            // `UnboundedRange`, so go through the equivalent `UnboundedSet`.
+            Self::UnboundedRange(r) => {
+                RangeDifference::difference(UnboundedSet::from(r), other).into()
+            }
+            Self::UnboundedSet(r) => RangeDifference::difference(r, other).into(),
+        }
+    }
+}
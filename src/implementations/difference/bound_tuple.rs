@@ -0,0 +1,13 @@
+use crate::{AnySet, Element, RangeDifference, Rangetools};
+
+impl<T, R, RhsInner> RangeDifference<R, RhsInner> for (std::ops::Bound<T>, std::ops::Bound<T>)
+where
+    R: Rangetools<Inner = RhsInner>,
+    T: Element,
+    AnySet<T>: RangeDifference<R, RhsInner>,
+{
+    type Output = <AnySet<T> as RangeDifference<R, RhsInner>>::Output;
+    fn difference(self, other: R) -> Self::Output {
+        RangeDifference::difference(self.to_inner(), other)
+    }
+}
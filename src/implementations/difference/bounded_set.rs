@@ -0,0 +1,322 @@
+use crate::{
+    merge, AnySet, BoundedRange, BoundedSet, Element, EmptyRange, LowerBoundedRange,
+    LowerBoundedSet, RangeComplement, RangeDifference, RangeIntersection, Rangetools,
+    UnboundedRange, UnboundedSet, UpperBoundedRange, UpperBoundedSet,
+};
+
+impl<T, R> RangeDifference<R, BoundedRange<T>> for BoundedSet<T>
+where
+    R: Rangetools<Inner = BoundedRange<T>>,
+    T: Element,
+{
+    type Output = BoundedSet<T>;
+    fn difference(mut self, other: R) -> Self::Output {
+        self.remove_range(other.to_inner());
+        self
+    }
+}
+
+impl<T, R> RangeDifference<R, BoundedSet<T>> for BoundedSet<T>
+where
+    R: Rangetools<Inner = BoundedSet<T>>,
+    T: Element,
+{
+    type Output = BoundedSet<T>;
+    fn difference(self, other: R) -> Self::Output {
+        merge::difference((&self).into(), (&other.to_inner()).into()).into()
+    }
+}
+
+impl<T, R> RangeDifference<R, LowerBoundedRange<T>> for BoundedSet<T>
+where
+    R: Rangetools<Inner = LowerBoundedRange<T>>,
+    T: Element,
+{
+    type Output = BoundedSet<T>;
+    fn difference(self, other: R) -> Self::Output {
+        let complement: UpperBoundedRange<T> = RangeComplement::complement(other.to_inner());
+        RangeIntersection::intersection(self, complement)
+    }
+}
+
+impl<T, R> RangeDifference<R, LowerBoundedSet<T>> for BoundedSet<T>
+where
+    R: Rangetools<Inner = LowerBoundedSet<T>>,
+    T: Element,
+{
+    type Output = BoundedSet<T>;
+    fn difference(self, other: R) -> Self::Output {
+        let complement: UpperBoundedSet<T> = RangeComplement::complement(other.to_inner());
+        RangeIntersection::intersection(self, complement)
+    }
+}
+
+impl<T, R> RangeDifference<R, UpperBoundedRange<T>> for BoundedSet<T>
+where
+    R: Rangetools<Inner = UpperBoundedRange<T>>,
+    T: Element,
+{
+    type Output = BoundedSet<T>;
+    fn difference(self, other: R) -> Self::Output {
+        let complement: LowerBoundedRange<T> = RangeComplement::complement(other.to_inner());
+        RangeIntersection::intersection(self, complement)
+    }
+}
+
+impl<T, R> RangeDifference<R, UpperBoundedSet<T>> for BoundedSet<T>
+where
+    R: Rangetools<Inner = UpperBoundedSet<T>>,
+    T: Element,
+{
+    type Output = BoundedSet<T>;
+    fn difference(self, other: R) -> Self::Output {
+        let complement: LowerBoundedSet<T> = RangeComplement::complement(other.to_inner());
+        RangeIntersection::intersection(self, complement)
+    }
+}
+
+impl<T, R> RangeDifference<R, UnboundedRange> for BoundedSet<T>
+where
+    R: Rangetools<Inner = UnboundedRange>,
+    T: Element,
+{
+    type Output = EmptyRange<T>;
+    fn difference(self, other: R) -> Self::Output {
+        let complement: EmptyRange<T> = RangeComplement::complement(other.to_inner());
+        RangeIntersection::intersection(self, complement)
+    }
+}
+
+impl<T, R> RangeDifference<R, UnboundedSet<T>> for BoundedSet<T>
+where
+    R: Rangetools<Inner = UnboundedSet<T>>,
+    T: Element,
+{
+    type Output = BoundedSet<T>;
+    fn difference(self, other: R) -> Self::Output {
+        let complement: BoundedSet<T> = RangeComplement::complement(other.to_inner());
+        RangeIntersection::intersection(self, complement)
+    }
+}
+
+impl<T, R> RangeDifference<R, EmptyRange<T>> for BoundedSet<T>
+where
+    R: Rangetools<Inner = EmptyRange<T>>,
+    T: Element,
+{
+    type Output = BoundedSet<T>;
+    fn difference(self, other: R) -> Self::Output {
+        let complement: UnboundedRange = RangeComplement::complement(other.to_inner());
+        RangeIntersection::intersection(self, complement)
+    }
+}
+
+impl<T, R> RangeDifference<R, AnySet<T>> for BoundedSet<T>
+where
+    R: Rangetools<Inner = AnySet<T>>,
+    T: Element,
+{
+    type Output = AnySet<T>;
+    fn difference(self, other: R) -> Self::Output {
+        let complement: AnySet<T> = RangeComplement::complement(other.to_inner());
+        RangeIntersection::intersection(complement, self)
+    }
+}
+
+impl<'b, T, R> RangeDifference<R, &'b BoundedSet<T>> for BoundedSet<T>
+where
+    R: Rangetools<Inner = &'b BoundedSet<T>>,
+    T: Element,
+{
+    type Output = BoundedSet<T>;
+    fn difference(self, other: R) -> Self::Output {
+        RangeDifference::difference(&self, other)
+    }
+}
+
+impl<'b, T, R> RangeDifference<R, &'b LowerBoundedSet<T>> for BoundedSet<T>
+where
+    R: Rangetools<Inner = &'b LowerBoundedSet<T>>,
+    T: Element,
+{
+    type Output = BoundedSet<T>;
+    fn difference(self, other: R) -> Self::Output {
+        RangeDifference::difference(&self, other)
+    }
+}
+
+impl<'b, T, R> RangeDifference<R, &'b UpperBoundedSet<T>> for BoundedSet<T>
+where
+    R: Rangetools<Inner = &'b UpperBoundedSet<T>>,
+    T: Element,
+{
+    type Output = BoundedSet<T>;
+    fn difference(self, other: R) -> Self::Output {
+        RangeDifference::difference(&self, other)
+    }
+}
+
+impl<'b, T, R> RangeDifference<R, &'b UnboundedSet<T>> for BoundedSet<T>
+where
+    R: Rangetools<Inner = &'b UnboundedSet<T>>,
+    T: Element,
+{
+    type Output = BoundedSet<T>;
+    fn difference(self, other: R) -> Self::Output {
+        RangeDifference::difference(&self, other)
+    }
+}
+
+impl<T, R> RangeDifference<R, BoundedRange<T>> for &BoundedSet<T>
+where
+    R: Rangetools<Inner = BoundedRange<T>>,
+    T: Element,
+{
+    type Output = BoundedSet<T>;
+    fn difference(self, other: R) -> Self::Output {
+        merge::difference(self.into(), (&other.to_inner()).into()).into()
+    }
+}
+
+impl<T, R> RangeDifference<R, BoundedSet<T>> for &BoundedSet<T>
+where
+    R: Rangetools<Inner = BoundedSet<T>>,
+    T: Element,
+{
+    type Output = BoundedSet<T>;
+    fn difference(self, other: R) -> Self::Output {
+        merge::difference(self.into(), (&other.to_inner()).into()).into()
+    }
+}
+
+impl<T, R> RangeDifference<R, LowerBoundedRange<T>> for &BoundedSet<T>
+where
+    R: Rangetools<Inner = LowerBoundedRange<T>>,
+    T: Element,
+{
+    type Output = BoundedSet<T>;
+    fn difference(self, other: R) -> Self::Output {
+        merge::difference(self.into(), (&other.to_inner()).into()).into()
+    }
+}
+
+impl<T, R> RangeDifference<R, LowerBoundedSet<T>> for &BoundedSet<T>
+where
+    R: Rangetools<Inner = LowerBoundedSet<T>>,
+    T: Element,
+{
+    type Output = BoundedSet<T>;
+    fn difference(self, other: R) -> Self::Output {
+        merge::difference(self.into(), (&other.to_inner()).into()).into()
+    }
+}
+
+impl<T, R> RangeDifference<R, UpperBoundedRange<T>> for &BoundedSet<T>
+where
+    R: Rangetools<Inner = UpperBoundedRange<T>>,
+    T: Element,
+{
+    type Output = BoundedSet<T>;
+    fn difference(self, other: R) -> Self::Output {
+        merge::difference(self.into(), (&other.to_inner()).into()).into()
+    }
+}
+
+impl<T, R> RangeDifference<R, UpperBoundedSet<T>> for &BoundedSet<T>
+where
+    R: Rangetools<Inner = UpperBoundedSet<T>>,
+    T: Element,
+{
+    type Output = BoundedSet<T>;
+    fn difference(self, other: R) -> Self::Output {
+        merge::difference(self.into(), (&other.to_inner()).into()).into()
+    }
+}
+
+impl<T, R> RangeDifference<R, UnboundedRange> for &BoundedSet<T>
+where
+    R: Rangetools<Inner = UnboundedRange>,
+    T: Element,
+{
+    type Output = EmptyRange<T>;
+    fn difference(self, _: R) -> Self::Output {
+        EmptyRange::new()
+    }
+}
+
+impl<T, R> RangeDifference<R, UnboundedSet<T>> for &BoundedSet<T>
+where
+    R: Rangetools<Inner = UnboundedSet<T>>,
+    T: Element,
+{
+    type Output = BoundedSet<T>;
+    fn difference(self, other: R) -> Self::Output {
+        merge::difference(self.into(), (&other.to_inner()).into()).into()
+    }
+}
+
+impl<T, R> RangeDifference<R, EmptyRange<T>> for &BoundedSet<T>
+where
+    R: Rangetools<Inner = EmptyRange<T>>,
+    T: Element,
+{
+    type Output = BoundedSet<T>;
+    fn difference(self, other: R) -> Self::Output {
+        merge::difference(self.into(), (&other.to_inner()).into()).into()
+    }
+}
+
+impl<T, R> RangeDifference<R, AnySet<T>> for &BoundedSet<T>
+where
+    R: Rangetools<Inner = AnySet<T>>,
+    T: Element,
+{
+    type Output = AnySet<T>;
+    fn difference(self, other: R) -> Self::Output {
+        merge::difference(self.into(), (&other.to_inner()).into()).into()
+    }
+}
+
+impl<'b, T, R> RangeDifference<R, &'b BoundedSet<T>> for &BoundedSet<T>
+where
+    R: Rangetools<Inner = &'b BoundedSet<T>>,
+    T: Element,
+{
+    type Output = BoundedSet<T>;
+    fn difference(self, other: R) -> Self::Output {
+        merge::difference(self.into(), other.to_inner().into()).into()
+    }
+}
+
+impl<'b, T, R> RangeDifference<R, &'b LowerBoundedSet<T>> for &BoundedSet<T>
+where
+    R: Rangetools<Inner = &'b LowerBoundedSet<T>>,
+    T: Element,
+{
+    type Output = BoundedSet<T>;
+    fn difference(self, other: R) -> Self::Output {
+        merge::difference(self.into(), other.to_inner().into()).into()
+    }
+}
+
+impl<'b, T, R> RangeDifference<R, &'b UpperBoundedSet<T>> for &BoundedSet<T>
+where
+    R: Rangetools<Inner = &'b UpperBoundedSet<T>>,
+    T: Element,
+{
+    type Output = BoundedSet<T>;
+    fn difference(self, other: R) -> Self::Output {
+        merge::difference(self.into(), other.to_inner().into()).into()
+    }
+}
+
+impl<'b, T, R> RangeDifference<R, &'b UnboundedSet<T>> for &BoundedSet<T>
+where
+    R: Rangetools<Inner = &'b UnboundedSet<T>>,
+    T: Element,
+{
+    type Output = BoundedSet<T>;
+    fn difference(self, other: R) -> Self::Output {
+        merge::difference(self.into(), other.to_inner().into()).into()
+    }
+}
@@ -0,0 +1,11 @@
+use crate::{EmptyRange, RangeDifference, Rangetools};
+
+impl<T, Rhs, RhsInner> RangeDifference<Rhs, RhsInner> for EmptyRange<T>
+where
+    Rhs: Rangetools<Inner = RhsInner>,
+{
+    type Output = EmptyRange<T>;
+    fn difference(self, _: Rhs) -> Self::Output {
+        self
+    }
+}
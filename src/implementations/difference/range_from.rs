@@ -0,0 +1,160 @@
+use crate::{
+    AnySet, BoundedRange, BoundedSet, Element, EmptyRange, LowerBoundedRange, LowerBoundedSet,
+    RangeComplement, RangeDifference, RangeIntersection, Rangetools, UnboundedRange, UnboundedSet,
+    UpperBoundedRange, UpperBoundedSet,
+};
+
+impl<T, R> RangeDifference<R, BoundedRange<T>> for std::ops::RangeFrom<T>
+where
+    R: Rangetools<Inner = BoundedRange<T>>,
+    T: Element,
+{
+    type Output = LowerBoundedSet<T>;
+    fn difference(self, other: R) -> Self::Output {
+        RangeDifference::difference(self.to_inner(), other)
+    }
+}
+
+impl<T, R> RangeDifference<R, BoundedSet<T>> for std::ops::RangeFrom<T>
+where
+    R: Rangetools<Inner = BoundedSet<T>>,
+    T: Element,
+{
+    type Output = LowerBoundedSet<T>;
+    fn difference(self, other: R) -> Self::Output {
+        RangeDifference::difference(self.to_inner(), other)
+    }
+}
+
+impl<T, R> RangeDifference<R, LowerBoundedRange<T>> for std::ops::RangeFrom<T>
+where
+    R: Rangetools<Inner = LowerBoundedRange<T>>,
+    T: Element,
+{
+    type Output = BoundedRange<T>;
+    fn difference(self, other: R) -> Self::Output {
+        RangeDifference::difference(self.to_inner(), other)
+    }
+}
+
+impl<T, R> RangeDifference<R, LowerBoundedSet<T>> for std::ops::RangeFrom<T>
+where
+    R: Rangetools<Inner = LowerBoundedSet<T>>,
+    T: Element,
+{
+    type Output = BoundedSet<T>;
+    fn difference(self, other: R) -> Self::Output {
+        RangeDifference::difference(self.to_inner(), other)
+    }
+}
+
+impl<T, R> RangeDifference<R, UpperBoundedRange<T>> for std::ops::RangeFrom<T>
+where
+    R: Rangetools<Inner = UpperBoundedRange<T>>,
+    T: Element,
+{
+    type Output = LowerBoundedRange<T>;
+    fn difference(self, other: R) -> Self::Output {
+        RangeDifference::difference(self.to_inner(), other)
+    }
+}
+
+impl<T, R> RangeDifference<R, UpperBoundedSet<T>> for std::ops::RangeFrom<T>
+where
+    R: Rangetools<Inner = UpperBoundedSet<T>>,
+    T: Element,
+{
+    type Output = LowerBoundedSet<T>;
+    fn difference(self, other: R) -> Self::Output {
+        RangeDifference::difference(self.to_inner(), other)
+    }
+}
+
+impl<T, R> RangeDifference<R, UnboundedRange> for std::ops::RangeFrom<T>
+where
+    R: Rangetools<Inner = UnboundedRange>,
+    T: Element,
+{
+    type Output = EmptyRange<T>;
+    fn difference(self, other: R) -> Self::Output {
+        RangeDifference::difference(self.to_inner(), other)
+    }
+}
+
+impl<T, R> RangeDifference<R, UnboundedSet<T>> for std::ops::RangeFrom<T>
+where
+    R: Rangetools<Inner = UnboundedSet<T>>,
+    T: Element,
+{
+    type Output = BoundedSet<T>;
+    fn difference(self, other: R) -> Self::Output {
+        RangeDifference::difference(self.to_inner(), other)
+    }
+}
+
+impl<T, R> RangeDifference<R, EmptyRange<T>> for std::ops::RangeFrom<T>
+where
+    R: Rangetools<Inner = EmptyRange<T>>,
+    T: Element,
+{
+    type Output = LowerBoundedRange<T>;
+    fn difference(self, other: R) -> Self::Output {
+        RangeDifference::difference(self.to_inner(), other)
+    }
+}
+
+impl<T, R> RangeDifference<R, AnySet<T>> for std::ops::RangeFrom<T>
+where
+    R: Rangetools<Inner = AnySet<T>>,
+    T: Element,
+{
+    type Output = AnySet<T>;
+    fn difference(self, other: R) -> Self::Output {
+        let complement: AnySet<T> = RangeComplement::complement(other.to_inner());
+        RangeIntersection::intersection(complement, self)
+    }
+}
+
+impl<'b, T, R> RangeDifference<R, &'b BoundedSet<T>> for std::ops::RangeFrom<T>
+where
+    R: Rangetools<Inner = &'b BoundedSet<T>>,
+    T: Element,
+{
+    type Output = LowerBoundedSet<T>;
+    fn difference(self, other: R) -> Self::Output {
+        RangeDifference::difference(self.to_inner(), other)
+    }
+}
+
+impl<'b, T, R> RangeDifference<R, &'b LowerBoundedSet<T>> for std::ops::RangeFrom<T>
+where
+    R: Rangetools<Inner = &'b LowerBoundedSet<T>>,
+    T: Element,
+{
+    type Output = BoundedSet<T>;
+    fn difference(self, other: R) -> Self::Output {
+        RangeDifference::difference(self.to_inner(), other)
+    }
+}
+
+impl<'b, T, R> RangeDifference<R, &'b UpperBoundedSet<T>> for std::ops::RangeFrom<T>
+where
+    R: Rangetools<Inner = &'b UpperBoundedSet<T>>,
+    T: Element,
+{
+    type Output = LowerBoundedSet<T>;
+    fn difference(self, other: R) -> Self::Output {
+        RangeDifference::difference(self.to_inner(), other)
+    }
+}
+
+impl<'b, T, R> RangeDifference<R, &'b UnboundedSet<T>> for std::ops::RangeFrom<T>
+where
+    R: Rangetools<Inner = &'b UnboundedSet<T>>,
+    T: Element,
+{
+    type Output = BoundedSet<T>;
+    fn difference(self, other: R) -> Self::Output {
+        RangeDifference::difference(self.to_inner(), other)
+    }
+}
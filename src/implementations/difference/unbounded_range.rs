@@ -0,0 +1,149 @@
+use crate::{
+    AnySet, BoundedRange, BoundedSet, Element, EmptyRange, LowerBoundedRange, LowerBoundedSet,
+    RangeComplement, RangeDifference, RangeIntersection, Rangetools, UnboundedRange, UnboundedSet,
+    UpperBoundedRange, UpperBoundedSet,
+};
+
+impl<T, R> RangeDifference<R, BoundedRange<T>> for UnboundedRange
+where
+    R: Rangetools<Inner = BoundedRange<T>>,
+    T: Element,
+{
+    type Output = UnboundedSet<T>;
+    fn difference(self, other: R) -> Self::Output {
+        RangeComplement::complement(other.to_inner())
+    }
+}
+
+impl<T, R> RangeDifference<R, BoundedSet<T>> for UnboundedRange
+where
+    R: Rangetools<Inner = BoundedSet<T>>,
+    T: Element,
+{
+    type Output = UnboundedSet<T>;
+    fn difference(self, other: R) -> Self::Output {
+        RangeComplement::complement(other.to_inner())
+    }
+}
+
+impl<T, R> RangeDifference<R, LowerBoundedRange<T>> for UnboundedRange
+where
+    R: Rangetools<Inner = LowerBoundedRange<T>>,
+    T: Element,
+{
+    type Output = UpperBoundedRange<T>;
+    fn difference(self, other: R) -> Self::Output {
+        RangeComplement::complement(other.to_inner())
+    }
+}
+
+impl<T, R> RangeDifference<R, LowerBoundedSet<T>> for UnboundedRange
+where
+    R: Rangetools<Inner = LowerBoundedSet<T>>,
+    T: Element,
+{
+    type Output = UpperBoundedSet<T>;
+    fn difference(self, other: R) -> Self::Output {
+        RangeComplement::complement(other.to_inner())
+    }
+}
+
+impl<T, R> RangeDifference<R, UpperBoundedRange<T>> for UnboundedRange
+where
+    R: Rangetools<Inner = UpperBoundedRange<T>>,
+    T: Element,
+{
+    type Output = LowerBoundedRange<T>;
+    fn difference(self, other: R) -> Self::Output {
+        RangeComplement::complement(other.to_inner())
+    }
+}
+
+impl<T, R> RangeDifference<R, UpperBoundedSet<T>> for UnboundedRange
+where
+    R: Rangetools<Inner = UpperBoundedSet<T>>,
+    T: Element,
+{
+    type Output = LowerBoundedSet<T>;
+    fn difference(self, other: R) -> Self::Output {
+        RangeComplement::complement(other.to_inner())
+    }
+}
+
+impl<T, R> RangeDifference<R, UnboundedSet<T>> for UnboundedRange
+where
+    R: Rangetools<Inner = UnboundedSet<T>>,
+    T: Element,
+{
+    type Output = BoundedSet<T>;
+    fn difference(self, other: R) -> Self::Output {
+        RangeComplement::complement(other.to_inner())
+    }
+}
+
+impl<T, R> RangeDifference<R, EmptyRange<T>> for UnboundedRange
+where
+    R: Rangetools<Inner = EmptyRange<T>>,
+    T: Element,
+{
+    type Output = UnboundedRange;
+    fn difference(self, other: R) -> Self::Output {
+        RangeComplement::complement(other.to_inner())
+    }
+}
+
+impl<T, R> RangeDifference<R, AnySet<T>> for UnboundedRange
+where
+    R: Rangetools<Inner = AnySet<T>>,
+    T: Element,
+{
+    type Output = AnySet<T>;
+    fn difference(self, other: R) -> Self::Output {
+        let complement: AnySet<T> = RangeComplement::complement(other.to_inner());
+        RangeIntersection::intersection(complement, self)
+    }
+}
+
+impl<'b, T, R> RangeDifference<R, &'b BoundedSet<T>> for UnboundedRange
+where
+    R: Rangetools<Inner = &'b BoundedSet<T>>,
+    T: Element,
+{
+    type Output = UnboundedSet<T>;
+    fn difference(self, other: R) -> Self::Output {
+        RangeComplement::complement(other.to_inner())
+    }
+}
+
+impl<'b, T, R> RangeDifference<R, &'b LowerBoundedSet<T>> for UnboundedRange
+where
+    R: Rangetools<Inner = &'b LowerBoundedSet<T>>,
+    T: Element,
+{
+    type Output = UpperBoundedSet<T>;
+    fn difference(self, other: R) -> Self::Output {
+        RangeComplement::complement(other.to_inner())
+    }
+}
+
+impl<'b, T, R> RangeDifference<R, &'b UpperBoundedSet<T>> for UnboundedRange
+where
+    R: Rangetools<Inner = &'b UpperBoundedSet<T>>,
+    T: Element,
+{
+    type Output = LowerBoundedSet<T>;
+    fn difference(self, other: R) -> Self::Output {
+        RangeComplement::complement(other.to_inner())
+    }
+}
+
+impl<'b, T, R> RangeDifference<R, &'b UnboundedSet<T>> for UnboundedRange
+where
+    R: Rangetools<Inner = &'b UnboundedSet<T>>,
+    T: Element,
+{
+    type Output = BoundedSet<T>;
+    fn difference(self, other: R) -> Self::Output {
+        RangeComplement::complement(other.to_inner())
+    }
+}
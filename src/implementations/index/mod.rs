@@ -3,3 +3,7 @@ mod empty_range;
 mod lower_bounded_range;
 mod unbounded_range;
 mod upper_bounded_range;
+
+/// Panic message for indexing with a range whose bound can't be converted to the equivalent
+/// `std::ops` range, which would be out of bounds for any slice anyway.
+const OVERFLOW: &str = "range bound overflows usize";
@@ -1,53 +1,54 @@
+use super::OVERFLOW;
 use crate::UpperBoundedRange;
 
 impl std::ops::Index<UpperBoundedRange<usize>> for String {
     type Output = str;
     fn index(&self, r: UpperBoundedRange<usize>) -> &Self::Output {
-        &self[std::ops::RangeTo::from(r)]
+        &self[std::ops::RangeTo::try_from(r).expect(OVERFLOW)]
     }
 }
 
 impl std::ops::IndexMut<UpperBoundedRange<usize>> for String {
     fn index_mut(&mut self, r: UpperBoundedRange<usize>) -> &mut Self::Output {
-        &mut self[std::ops::RangeTo::from(r)]
+        &mut self[std::ops::RangeTo::try_from(r).expect(OVERFLOW)]
     }
 }
 
 impl std::ops::Index<UpperBoundedRange<usize>> for str {
     type Output = str;
     fn index(&self, r: UpperBoundedRange<usize>) -> &Self::Output {
-        &self[std::ops::RangeTo::from(r)]
+        &self[std::ops::RangeTo::try_from(r).expect(OVERFLOW)]
     }
 }
 
 impl std::ops::IndexMut<UpperBoundedRange<usize>> for str {
     fn index_mut(&mut self, r: UpperBoundedRange<usize>) -> &mut Self::Output {
-        &mut self[std::ops::RangeTo::from(r)]
+        &mut self[std::ops::RangeTo::try_from(r).expect(OVERFLOW)]
     }
 }
 
 impl<T> std::ops::Index<UpperBoundedRange<usize>> for [T] {
     type Output = [T];
     fn index(&self, r: UpperBoundedRange<usize>) -> &Self::Output {
-        &self[std::ops::RangeTo::from(r)]
+        &self[std::ops::RangeTo::try_from(r).expect(OVERFLOW)]
     }
 }
 
 impl<T> std::ops::IndexMut<UpperBoundedRange<usize>> for [T] {
     fn index_mut(&mut self, r: UpperBoundedRange<usize>) -> &mut Self::Output {
-        &mut self[std::ops::RangeTo::from(r)]
+        &mut self[std::ops::RangeTo::try_from(r).expect(OVERFLOW)]
     }
 }
 
 impl<T> std::ops::Index<UpperBoundedRange<usize>> for Vec<T> {
     type Output = [T];
     fn index(&self, r: UpperBoundedRange<usize>) -> &Self::Output {
-        &self[std::ops::RangeTo::from(r)]
+        &self[std::ops::RangeTo::try_from(r).expect(OVERFLOW)]
     }
 }
 
 impl<T> std::ops::IndexMut<UpperBoundedRange<usize>> for Vec<T> {
     fn index_mut(&mut self, r: UpperBoundedRange<usize>) -> &mut Self::Output {
-        &mut self[std::ops::RangeTo::from(r)]
+        &mut self[std::ops::RangeTo::try_from(r).expect(OVERFLOW)]
     }
 }
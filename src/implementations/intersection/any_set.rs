@@ -0,0 +1,43 @@
+use crate::{
+    AnySet, BoundedRange, BoundedSet, EmptyRange, LowerBoundedRange, LowerBoundedSet,
+    RangeIntersection, Rangetools, UnboundedRange, UnboundedSet, UpperBoundedRange,
+    UpperBoundedSet,
+};
+
+impl<T, R, RhsInner> RangeIntersection<R, RhsInner> for AnySet<T>
+where
+    R: Rangetools<Inner = RhsInner>,
+    EmptyRange<T>: RangeIntersection<R, RhsInner>,
+    BoundedRange<T>: RangeIntersection<R, RhsInner>,
+    BoundedSet<T>: RangeIntersection<R, RhsInner>,
+    LowerBoundedRange<T>: RangeIntersection<R, RhsInner>,
+    LowerBoundedSet<T>: RangeIntersection<R, RhsInner>,
+    UpperBoundedRange<T>: RangeIntersection<R, RhsInner>,
+    UpperBoundedSet<T>: RangeIntersection<R, RhsInner>,
+    UnboundedRange: RangeIntersection<R, RhsInner>,
+    UnboundedSet<T>: RangeIntersection<R, RhsInner>,
+    AnySet<T>: From<<EmptyRange<T> as RangeIntersection<R, RhsInner>>::Output>,
+    AnySet<T>: From<<BoundedRange<T> as RangeIntersection<R, RhsInner>>::Output>,
+    AnySet<T>: From<<BoundedSet<T> as RangeIntersection<R, RhsInner>>::Output>,
+    AnySet<T>: From<<LowerBoundedRange<T> as RangeIntersection<R, RhsInner>>::Output>,
+    AnySet<T>: From<<LowerBoundedSet<T> as RangeIntersection<R, RhsInner>>::Output>,
+    AnySet<T>: From<<UpperBoundedRange<T> as RangeIntersection<R, RhsInner>>::Output>,
+    AnySet<T>: From<<UpperBoundedSet<T> as RangeIntersection<R, RhsInner>>::Output>,
+    AnySet<T>: From<<UnboundedRange as RangeIntersection<R, RhsInner>>::Output>,
+    AnySet<T>: From<<UnboundedSet<T> as RangeIntersection<R, RhsInner>>::Output>,
+{
+    type Output = AnySet<T>;
+    fn intersection(self, other: R) -> Self::Output {
+        match self {
+            Self::EmptyRange(r) => RangeIntersection::intersection(r, other).into(),
+            Self::BoundedRange(r) => RangeIntersection::intersection(r, other).into(),
+            Self::BoundedSet(r) => RangeIntersection::intersection(r, other).into(),
+            Self::LowerBoundedRange(r) => RangeIntersection::intersection(r, other).into(),
+            Self::LowerBoundedSet(r) => RangeIntersection::intersection(r, other).into(),
+            Self::UpperBoundedRange(r) => RangeIntersection::intersection(r, other).into(),
+            Self::UpperBoundedSet(r) => RangeIntersection::intersection(r, other).into(),
+            Self::UnboundedRange(r) => RangeIntersection::intersection(r, other).into(),
+            Self::UnboundedSet(r) => RangeIntersection::intersection(r, other).into(),
+        }
+    }
+}
@@ -0,0 +1,13 @@
+use crate::{AnySet, Element, RangeIntersection, Rangetools};
+
+impl<T, R, RhsInner> RangeIntersection<R, RhsInner> for (std::ops::Bound<T>, std::ops::Bound<T>)
+where
+    R: Rangetools<Inner = RhsInner>,
+    T: Element,
+    AnySet<T>: RangeIntersection<R, RhsInner>,
+{
+    type Output = <AnySet<T> as RangeIntersection<R, RhsInner>>::Output;
+    fn intersection(self, other: R) -> Self::Output {
+        RangeIntersection::intersection(self.to_inner(), other)
+    }
+}
@@ -1,12 +1,13 @@
 use crate::{
-    BoundedRange, BoundedSet, EmptyRange, LowerBoundedRange, LowerBoundedSet, RangeIntersection,
-    Rangetools, UnboundedRange, UnboundedSet, UpperBoundedRange, UpperBoundedSet,
+    AnySet, BoundedRange, BoundedSet, Element, EmptyRange, LowerBoundedRange, LowerBoundedSet,
+    RangeIntersection, Rangetools, UnboundedRange, UnboundedSet, UpperBoundedRange,
+    UpperBoundedSet,
 };
 
 impl<T, R> RangeIntersection<R, BoundedRange<T>> for BoundedRange<T>
 where
     R: Rangetools<Inner = BoundedRange<T>>,
-    T: Copy + Ord,
+    T: Element,
 {
     type Output = BoundedRange<T>;
     fn intersection(self, other: R) -> Self::Output {
@@ -18,7 +19,7 @@ where
 impl<T, R> RangeIntersection<R, BoundedSet<T>> for BoundedRange<T>
 where
     R: Rangetools<Inner = BoundedSet<T>>,
-    T: Copy + Ord,
+    T: Element,
 {
     type Output = BoundedSet<T>;
     fn intersection(self, other: R) -> Self::Output {
@@ -29,7 +30,7 @@ where
 impl<T, R> RangeIntersection<R, LowerBoundedRange<T>> for BoundedRange<T>
 where
     R: Rangetools<Inner = LowerBoundedRange<T>>,
-    T: Copy + Ord,
+    T: Element,
 {
     type Output = BoundedRange<T>;
     fn intersection(self, other: R) -> Self::Output {
@@ -40,7 +41,7 @@ where
 impl<T, R> RangeIntersection<R, LowerBoundedSet<T>> for BoundedRange<T>
 where
     R: Rangetools<Inner = LowerBoundedSet<T>>,
-    T: Copy + Ord,
+    T: Element,
 {
     type Output = BoundedSet<T>;
     fn intersection(self, other: R) -> Self::Output {
@@ -51,7 +52,7 @@ where
 impl<T, R> RangeIntersection<R, UpperBoundedRange<T>> for BoundedRange<T>
 where
     R: Rangetools<Inner = UpperBoundedRange<T>>,
-    T: Copy + Ord,
+    T: Element,
 {
     type Output = BoundedRange<T>;
     fn intersection(self, other: R) -> Self::Output {
@@ -62,7 +63,7 @@ where
 impl<T, R> RangeIntersection<R, UpperBoundedSet<T>> for BoundedRange<T>
 where
     R: Rangetools<Inner = UpperBoundedSet<T>>,
-    T: Copy + Ord,
+    T: Element,
 {
     type Output = BoundedSet<T>;
     fn intersection(self, other: R) -> Self::Output {
@@ -73,7 +74,7 @@ where
 impl<T, R> RangeIntersection<R, UnboundedRange> for BoundedRange<T>
 where
     R: Rangetools<Inner = UnboundedRange>,
-    T: Copy + Ord,
+    T: Element,
 {
     type Output = BoundedRange<T>;
     fn intersection(self, other: R) -> Self::Output {
@@ -84,7 +85,7 @@ where
 impl<T, R> RangeIntersection<R, UnboundedSet<T>> for BoundedRange<T>
 where
     R: Rangetools<Inner = UnboundedSet<T>>,
-    T: Copy + Ord,
+    T: Element,
 {
     type Output = BoundedSet<T>;
     fn intersection(self, other: R) -> Self::Output {
@@ -95,10 +96,65 @@ where
 impl<T, R> RangeIntersection<R, EmptyRange<T>> for BoundedRange<T>
 where
     R: Rangetools<Inner = EmptyRange<T>>,
-    T: Copy + Ord,
+    T: Element,
 {
     type Output = EmptyRange<T>;
     fn intersection(self, other: R) -> Self::Output {
         RangeIntersection::intersection(other.to_inner(), self)
     }
 }
+
+impl<T, R> RangeIntersection<R, AnySet<T>> for BoundedRange<T>
+where
+    R: Rangetools<Inner = AnySet<T>>,
+    T: Element,
+{
+    type Output = AnySet<T>;
+    fn intersection(self, other: R) -> Self::Output {
+        RangeIntersection::intersection(other.to_inner(), self)
+    }
+}
+
+impl<'b, T, R> RangeIntersection<R, &'b BoundedSet<T>> for BoundedRange<T>
+where
+    R: Rangetools<Inner = &'b BoundedSet<T>>,
+    T: Element,
+{
+    type Output = BoundedSet<T>;
+    fn intersection(self, other: R) -> Self::Output {
+        RangeIntersection::intersection(other.to_inner(), self)
+    }
+}
+
+impl<'b, T, R> RangeIntersection<R, &'b LowerBoundedSet<T>> for BoundedRange<T>
+where
+    R: Rangetools<Inner = &'b LowerBoundedSet<T>>,
+    T: Element,
+{
+    type Output = BoundedSet<T>;
+    fn intersection(self, other: R) -> Self::Output {
+        RangeIntersection::intersection(other.to_inner(), self)
+    }
+}
+
+impl<'b, T, R> RangeIntersection<R, &'b UpperBoundedSet<T>> for BoundedRange<T>
+where
+    R: Rangetools<Inner = &'b UpperBoundedSet<T>>,
+    T: Element,
+{
+    type Output = BoundedSet<T>;
+    fn intersection(self, other: R) -> Self::Output {
+        RangeIntersection::intersection(other.to_inner(), self)
+    }
+}
+
+impl<'b, T, R> RangeIntersection<R, &'b UnboundedSet<T>> for BoundedRange<T>
+where
+    R: Rangetools<Inner = &'b UnboundedSet<T>>,
+    T: Element,
+{
+    type Output = BoundedSet<T>;
+    fn intersection(self, other: R) -> Self::Output {
+        RangeIntersection::intersection(other.to_inner(), self)
+    }
+}
@@ -1,12 +1,13 @@
 use crate::{
-    BoundedRange, BoundedSet, EmptyRange, LowerBoundedRange, LowerBoundedSet, RangeIntersection,
-    Rangetools, UnboundedRange, UnboundedSet, UpperBoundedRange, UpperBoundedSet,
+    AnySet, BoundedRange, BoundedSet, Element, EmptyRange, LowerBoundedRange, LowerBoundedSet,
+    RangeIntersection, Rangetools, UnboundedRange, UnboundedSet, UpperBoundedRange,
+    UpperBoundedSet,
 };
 
 impl<T, R> RangeIntersection<R, BoundedRange<T>> for LowerBoundedRange<T>
 where
     R: Rangetools<Inner = BoundedRange<T>>,
-    T: Copy + Ord,
+    T: Element,
 {
     type Output = BoundedRange<T>;
     fn intersection(self, other: R) -> Self::Output {
@@ -17,7 +18,7 @@ where
 impl<T, R> RangeIntersection<R, BoundedSet<T>> for LowerBoundedRange<T>
 where
     R: Rangetools<Inner = BoundedSet<T>>,
-    T: Copy + Ord,
+    T: Element,
 {
     type Output = BoundedSet<T>;
     fn intersection(self, other: R) -> Self::Output {
@@ -28,7 +29,7 @@ where
 impl<T, R> RangeIntersection<R, LowerBoundedRange<T>> for LowerBoundedRange<T>
 where
     R: Rangetools<Inner = LowerBoundedRange<T>>,
-    T: Copy + Ord,
+    T: Element,
 {
     type Output = LowerBoundedRange<T>;
     fn intersection(self, other: R) -> Self::Output {
@@ -39,7 +40,7 @@ where
 impl<T, R> RangeIntersection<R, LowerBoundedSet<T>> for LowerBoundedRange<T>
 where
     R: Rangetools<Inner = LowerBoundedSet<T>>,
-    T: Copy + Ord,
+    T: Element,
 {
     type Output = LowerBoundedSet<T>;
     fn intersection(self, other: R) -> Self::Output {
@@ -50,7 +51,7 @@ where
 impl<T, R> RangeIntersection<R, UpperBoundedRange<T>> for LowerBoundedRange<T>
 where
     R: Rangetools<Inner = UpperBoundedRange<T>>,
-    T: Copy + Ord,
+    T: Element,
 {
     type Output = BoundedRange<T>;
     fn intersection(self, other: R) -> Self::Output {
@@ -61,7 +62,7 @@ where
 impl<T, R> RangeIntersection<R, UpperBoundedSet<T>> for LowerBoundedRange<T>
 where
     R: Rangetools<Inner = UpperBoundedSet<T>>,
-    T: Copy + Ord,
+    T: Element,
 {
     type Output = BoundedSet<T>;
     fn intersection(self, other: R) -> Self::Output {
@@ -82,7 +83,7 @@ where
 impl<T, R> RangeIntersection<R, UnboundedSet<T>> for LowerBoundedRange<T>
 where
     R: Rangetools<Inner = UnboundedSet<T>>,
-    T: Copy + Ord,
+    T: Element,
 {
     type Output = LowerBoundedSet<T>;
     fn intersection(self, other: R) -> Self::Output {
@@ -93,10 +94,65 @@ where
 impl<T, R> RangeIntersection<R, EmptyRange<T>> for LowerBoundedRange<T>
 where
     R: Rangetools<Inner = EmptyRange<T>>,
-    T: Copy + Ord,
+    T: Element,
 {
     type Output = EmptyRange<T>;
     fn intersection(self, other: R) -> Self::Output {
         other.to_inner()
     }
 }
+
+impl<T, R> RangeIntersection<R, AnySet<T>> for LowerBoundedRange<T>
+where
+    R: Rangetools<Inner = AnySet<T>>,
+    T: Element,
+{
+    type Output = AnySet<T>;
+    fn intersection(self, other: R) -> Self::Output {
+        RangeIntersection::intersection(other.to_inner(), self)
+    }
+}
+
+impl<'b, T, R> RangeIntersection<R, &'b BoundedSet<T>> for LowerBoundedRange<T>
+where
+    R: Rangetools<Inner = &'b BoundedSet<T>>,
+    T: Element,
+{
+    type Output = BoundedSet<T>;
+    fn intersection(self, other: R) -> Self::Output {
+        RangeIntersection::intersection(other.to_inner(), self)
+    }
+}
+
+impl<'b, T, R> RangeIntersection<R, &'b LowerBoundedSet<T>> for LowerBoundedRange<T>
+where
+    R: Rangetools<Inner = &'b LowerBoundedSet<T>>,
+    T: Element,
+{
+    type Output = LowerBoundedSet<T>;
+    fn intersection(self, other: R) -> Self::Output {
+        RangeIntersection::intersection(other.to_inner(), self)
+    }
+}
+
+impl<'b, T, R> RangeIntersection<R, &'b UpperBoundedSet<T>> for LowerBoundedRange<T>
+where
+    R: Rangetools<Inner = &'b UpperBoundedSet<T>>,
+    T: Element,
+{
+    type Output = BoundedSet<T>;
+    fn intersection(self, other: R) -> Self::Output {
+        RangeIntersection::intersection(other.to_inner(), self)
+    }
+}
+
+impl<'b, T, R> RangeIntersection<R, &'b UnboundedSet<T>> for LowerBoundedRange<T>
+where
+    R: Rangetools<Inner = &'b UnboundedSet<T>>,
+    T: Element,
+{
+    type Output = LowerBoundedSet<T>;
+    fn intersection(self, other: R) -> Self::Output {
+        RangeIntersection::intersection(other.to_inner(), self)
+    }
+}
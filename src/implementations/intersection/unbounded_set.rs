@@ -1,13 +1,13 @@
 use crate::{
-    BoundedRange, BoundedSet, EmptyRange, LowerBoundedRange, LowerBoundedSet,
-    PiecewiseUnboundedSet, RangeIntersection, RangeUnion, Rangetools, UnboundedRange, UnboundedSet,
+    merge, AnySet, BoundedRange, BoundedSet, Element, EmptyRange, LowerBoundedRange,
+    LowerBoundedSet, RangeIntersection, Rangetools, UnboundedRange, UnboundedSet,
     UpperBoundedRange, UpperBoundedSet,
 };
 
 impl<T, R> RangeIntersection<R, BoundedRange<T>> for UnboundedSet<T>
 where
     R: Rangetools<Inner = BoundedRange<T>>,
-    T: Copy + Ord,
+    T: Element,
 {
     type Output = BoundedSet<T>;
     fn intersection(self, other: R) -> Self::Output {
@@ -18,18 +18,18 @@ where
 impl<T, R> RangeIntersection<R, BoundedSet<T>> for UnboundedSet<T>
 where
     R: Rangetools<Inner = BoundedSet<T>>,
-    T: Copy + Ord,
+    T: Element,
 {
     type Output = BoundedSet<T>;
     fn intersection(self, other: R) -> Self::Output {
-        RangeIntersection::intersection(other.to_inner(), self)
+        RangeIntersection::intersection(&self, &other.to_inner())
     }
 }
 
 impl<T, R> RangeIntersection<R, LowerBoundedRange<T>> for UnboundedSet<T>
 where
     R: Rangetools<Inner = LowerBoundedRange<T>>,
-    T: Copy + Ord,
+    T: Element,
 {
     type Output = LowerBoundedSet<T>;
     fn intersection(self, other: R) -> Self::Output {
@@ -40,18 +40,18 @@ where
 impl<T, R> RangeIntersection<R, LowerBoundedSet<T>> for UnboundedSet<T>
 where
     R: Rangetools<Inner = LowerBoundedSet<T>>,
-    T: Copy + Ord,
+    T: Element,
 {
     type Output = LowerBoundedSet<T>;
     fn intersection(self, other: R) -> Self::Output {
-        RangeIntersection::intersection(other.to_inner(), self)
+        RangeIntersection::intersection(&self, &other.to_inner())
     }
 }
 
 impl<T, R> RangeIntersection<R, UpperBoundedRange<T>> for UnboundedSet<T>
 where
     R: Rangetools<Inner = UpperBoundedRange<T>>,
-    T: Copy + Ord,
+    T: Element,
 {
     type Output = UpperBoundedSet<T>;
     fn intersection(self, other: R) -> Self::Output {
@@ -62,11 +62,11 @@ where
 impl<T, R> RangeIntersection<R, UpperBoundedSet<T>> for UnboundedSet<T>
 where
     R: Rangetools<Inner = UpperBoundedSet<T>>,
-    T: Copy + Ord,
+    T: Element,
 {
     type Output = UpperBoundedSet<T>;
     fn intersection(self, other: R) -> Self::Output {
-        RangeIntersection::intersection(other.to_inner(), self)
+        RangeIntersection::intersection(&self, &other.to_inner())
     }
 }
 
@@ -83,37 +83,230 @@ where
 impl<T, R> RangeIntersection<R, UnboundedSet<T>> for UnboundedSet<T>
 where
     R: Rangetools<Inner = UnboundedSet<T>>,
-    T: Copy + Ord,
+    T: Element,
 {
     type Output = UnboundedSet<T>;
     fn intersection(self, other: R) -> Self::Output {
-        match (self, other.to_inner()) {
-            (UnboundedSet::Full, other) => other,
-            (s, UnboundedSet::Full) => s,
-            (
-                s,
-                UnboundedSet::Piecewise(PiecewiseUnboundedSet {
-                    upper_bounded_range: u2,
-                    ranges: r2,
-                    lower_bounded_range: l2,
-                }),
-            ) => {
-                let a = RangeIntersection::intersection(s.clone(), u2);
-                let b = RangeIntersection::intersection(s.clone(), l2);
-                let c = RangeIntersection::intersection(s, r2);
-                RangeUnion::union(a, RangeUnion::union(b, c))
-            }
-        }
+        RangeIntersection::intersection(&self, &other.to_inner())
     }
 }
 
 impl<T, R> RangeIntersection<R, EmptyRange<T>> for UnboundedSet<T>
 where
     R: Rangetools<Inner = EmptyRange<T>>,
-    T: Copy + Ord,
+    T: Element,
 {
     type Output = EmptyRange<T>;
     fn intersection(self, other: R) -> Self::Output {
         other.to_inner()
     }
 }
+
+impl<T, R> RangeIntersection<R, AnySet<T>> for UnboundedSet<T>
+where
+    R: Rangetools<Inner = AnySet<T>>,
+    T: Element,
+{
+    type Output = AnySet<T>;
+    fn intersection(self, other: R) -> Self::Output {
+        RangeIntersection::intersection(other.to_inner(), self)
+    }
+}
+
+impl<'b, T, R> RangeIntersection<R, &'b BoundedSet<T>> for UnboundedSet<T>
+where
+    R: Rangetools<Inner = &'b BoundedSet<T>>,
+    T: Element,
+{
+    type Output = BoundedSet<T>;
+    fn intersection(self, other: R) -> Self::Output {
+        RangeIntersection::intersection(&self, other)
+    }
+}
+
+impl<'b, T, R> RangeIntersection<R, &'b LowerBoundedSet<T>> for UnboundedSet<T>
+where
+    R: Rangetools<Inner = &'b LowerBoundedSet<T>>,
+    T: Element,
+{
+    type Output = LowerBoundedSet<T>;
+    fn intersection(self, other: R) -> Self::Output {
+        RangeIntersection::intersection(&self, other)
+    }
+}
+
+impl<'b, T, R> RangeIntersection<R, &'b UpperBoundedSet<T>> for UnboundedSet<T>
+where
+    R: Rangetools<Inner = &'b UpperBoundedSet<T>>,
+    T: Element,
+{
+    type Output = UpperBoundedSet<T>;
+    fn intersection(self, other: R) -> Self::Output {
+        RangeIntersection::intersection(&self, other)
+    }
+}
+
+impl<'b, T, R> RangeIntersection<R, &'b UnboundedSet<T>> for UnboundedSet<T>
+where
+    R: Rangetools<Inner = &'b UnboundedSet<T>>,
+    T: Element,
+{
+    type Output = UnboundedSet<T>;
+    fn intersection(self, other: R) -> Self::Output {
+        RangeIntersection::intersection(&self, other)
+    }
+}
+
+impl<T, R> RangeIntersection<R, BoundedRange<T>> for &UnboundedSet<T>
+where
+    R: Rangetools<Inner = BoundedRange<T>>,
+    T: Element,
+{
+    type Output = BoundedSet<T>;
+    fn intersection(self, other: R) -> Self::Output {
+        merge::intersection(self.into(), (&other.to_inner()).into()).into()
+    }
+}
+
+impl<T, R> RangeIntersection<R, BoundedSet<T>> for &UnboundedSet<T>
+where
+    R: Rangetools<Inner = BoundedSet<T>>,
+    T: Element,
+{
+    type Output = BoundedSet<T>;
+    fn intersection(self, other: R) -> Self::Output {
+        merge::intersection(self.into(), (&other.to_inner()).into()).into()
+    }
+}
+
+impl<T, R> RangeIntersection<R, LowerBoundedRange<T>> for &UnboundedSet<T>
+where
+    R: Rangetools<Inner = LowerBoundedRange<T>>,
+    T: Element,
+{
+    type Output = LowerBoundedSet<T>;
+    fn intersection(self, other: R) -> Self::Output {
+        merge::intersection(self.into(), (&other.to_inner()).into()).into()
+    }
+}
+
+impl<T, R> RangeIntersection<R, LowerBoundedSet<T>> for &UnboundedSet<T>
+where
+    R: Rangetools<Inner = LowerBoundedSet<T>>,
+    T: Element,
+{
+    type Output = LowerBoundedSet<T>;
+    fn intersection(self, other: R) -> Self::Output {
+        merge::intersection(self.into(), (&other.to_inner()).into()).into()
+    }
+}
+
+impl<T, R> RangeIntersection<R, UpperBoundedRange<T>> for &UnboundedSet<T>
+where
+    R: Rangetools<Inner = UpperBoundedRange<T>>,
+    T: Element,
+{
+    type Output = UpperBoundedSet<T>;
+    fn intersection(self, other: R) -> Self::Output {
+        merge::intersection(self.into(), (&other.to_inner()).into()).into()
+    }
+}
+
+impl<T, R> RangeIntersection<R, UpperBoundedSet<T>> for &UnboundedSet<T>
+where
+    R: Rangetools<Inner = UpperBoundedSet<T>>,
+    T: Element,
+{
+    type Output = UpperBoundedSet<T>;
+    fn intersection(self, other: R) -> Self::Output {
+        merge::intersection(self.into(), (&other.to_inner()).into()).into()
+    }
+}
+
+impl<T, R> RangeIntersection<R, UnboundedRange> for &UnboundedSet<T>
+where
+    R: Rangetools<Inner = UnboundedRange>,
+    T: Element,
+{
+    type Output = UnboundedSet<T>;
+    fn intersection(self, other: R) -> Self::Output {
+        merge::intersection(self.into(), (&other.to_inner()).into()).into()
+    }
+}
+
+impl<T, R> RangeIntersection<R, UnboundedSet<T>> for &UnboundedSet<T>
+where
+    R: Rangetools<Inner = UnboundedSet<T>>,
+    T: Element,
+{
+    type Output = UnboundedSet<T>;
+    fn intersection(self, other: R) -> Self::Output {
+        merge::intersection(self.into(), (&other.to_inner()).into()).into()
+    }
+}
+
+impl<T, R> RangeIntersection<R, EmptyRange<T>> for &UnboundedSet<T>
+where
+    R: Rangetools<Inner = EmptyRange<T>>,
+    T: Element,
+{
+    type Output = EmptyRange<T>;
+    fn intersection(self, other: R) -> Self::Output {
+        other.to_inner()
+    }
+}
+
+impl<T, R> RangeIntersection<R, AnySet<T>> for &UnboundedSet<T>
+where
+    R: Rangetools<Inner = AnySet<T>>,
+    T: Element,
+{
+    type Output = AnySet<T>;
+    fn intersection(self, other: R) -> Self::Output {
+        merge::intersection(self.into(), (&other.to_inner()).into()).into()
+    }
+}
+
+impl<'b, T, R> RangeIntersection<R, &'b BoundedSet<T>> for &UnboundedSet<T>
+where
+    R: Rangetools<Inner = &'b BoundedSet<T>>,
+    T: Element,
+{
+    type Output = BoundedSet<T>;
+    fn intersection(self, other: R) -> Self::Output {
+        merge::intersection(self.into(), other.to_inner().into()).into()
+    }
+}
+
+impl<'b, T, R> RangeIntersection<R, &'b LowerBoundedSet<T>> for &UnboundedSet<T>
+where
+    R: Rangetools<Inner = &'b LowerBoundedSet<T>>,
+    T: Element,
+{
+    type Output = LowerBoundedSet<T>;
+    fn intersection(self, other: R) -> Self::Output {
+        merge::intersection(self.into(), other.to_inner().into()).into()
+    }
+}
+
+impl<'b, T, R> RangeIntersection<R, &'b UpperBoundedSet<T>> for &UnboundedSet<T>
+where
+    R: Rangetools<Inner = &'b UpperBoundedSet<T>>,
+    T: Element,
+{
+    type Output = UpperBoundedSet<T>;
+    fn intersection(self, other: R) -> Self::Output {
+        merge::intersection(self.into(), other.to_inner().into()).into()
+    }
+}
+
+impl<'b, T, R> RangeIntersection<R, &'b UnboundedSet<T>> for &UnboundedSet<T>
+where
+    R: Rangetools<Inner = &'b UnboundedSet<T>>,
+    T: Element,
+{
+    type Output = UnboundedSet<T>;
+    fn intersection(self, other: R) -> Self::Output {
+        merge::intersection(self.into(), other.to_inner().into()).into()
+    }
+}
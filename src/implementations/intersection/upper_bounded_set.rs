@@ -1,19 +1,20 @@
 use crate::{
-    BoundedRange, BoundedSet, EmptyRange, LowerBoundedRange, LowerBoundedSet,
-    PiecewiseUnboundedSet, RangeIntersection, Rangetools, UnboundedRange, UnboundedSet,
+    merge, AnySet, BoundedRange, BoundedSet, Element, EmptyRange, LowerBoundedRange,
+    LowerBoundedSet, RangeIntersection, Rangetools, UnboundedRange, UnboundedSet,
     UpperBoundedRange, UpperBoundedSet,
 };
 
 impl<T, R> RangeIntersection<R, BoundedRange<T>> for UpperBoundedSet<T>
 where
     R: Rangetools<Inner = BoundedRange<T>>,
-    T: Copy + Ord,
+    T: Element,
 {
     type Output = BoundedSet<T>;
     fn intersection(self, other: R) -> Self::Output {
         let other = other.to_inner();
-        let mut set = RangeIntersection::intersection(self.upper_bounded_range, other).to_set();
-        set.ranges.retain(|r| !r.is_empty());
+        let mut set =
+            RangeIntersection::intersection(self.upper_bounded_range, other.clone()).to_set();
+        set.retain(|r| !r.is_empty());
         set.add_set(RangeIntersection::intersection(self.ranges, other));
         set
     }
@@ -22,24 +23,25 @@ where
 impl<T, R> RangeIntersection<R, BoundedSet<T>> for UpperBoundedSet<T>
 where
     R: Rangetools<Inner = BoundedSet<T>>,
-    T: Copy + Ord,
+    T: Element,
 {
     type Output = BoundedSet<T>;
     fn intersection(self, other: R) -> Self::Output {
-        RangeIntersection::intersection(other.to_inner(), self)
+        RangeIntersection::intersection(&self, &other.to_inner())
     }
 }
 
 impl<T, R> RangeIntersection<R, LowerBoundedRange<T>> for UpperBoundedSet<T>
 where
     R: Rangetools<Inner = LowerBoundedRange<T>>,
-    T: Copy + Ord,
+    T: Element,
 {
     type Output = BoundedSet<T>;
     fn intersection(self, other: R) -> Self::Output {
         let other = other.to_inner();
-        let mut set = RangeIntersection::intersection(self.upper_bounded_range, other).to_set();
-        set.ranges.retain(|r| !r.is_empty());
+        let mut set =
+            RangeIntersection::intersection(self.upper_bounded_range, other.clone()).to_set();
+        set.retain(|r| !r.is_empty());
         set.add_set(RangeIntersection::intersection(self.ranges, other));
         set
     }
@@ -48,23 +50,24 @@ where
 impl<T, R> RangeIntersection<R, LowerBoundedSet<T>> for UpperBoundedSet<T>
 where
     R: Rangetools<Inner = LowerBoundedSet<T>>,
-    T: Copy + Ord,
+    T: Element,
 {
     type Output = BoundedSet<T>;
     fn intersection(self, other: R) -> Self::Output {
-        RangeIntersection::intersection(other.to_inner(), self)
+        RangeIntersection::intersection(&self, &other.to_inner())
     }
 }
 
 impl<T, R> RangeIntersection<R, UpperBoundedRange<T>> for UpperBoundedSet<T>
 where
     R: Rangetools<Inner = UpperBoundedRange<T>>,
-    T: Copy + Ord,
+    T: Element,
 {
     type Output = UpperBoundedSet<T>;
     fn intersection(self, other: R) -> Self::Output {
         let other = other.to_inner();
-        let mut set = RangeIntersection::intersection(self.upper_bounded_range, other).to_set();
+        let mut set =
+            RangeIntersection::intersection(self.upper_bounded_range, other.clone()).to_set();
         set.add_set(RangeIntersection::intersection(self.ranges, other));
         set
     }
@@ -73,14 +76,11 @@ where
 impl<T, R> RangeIntersection<R, UpperBoundedSet<T>> for UpperBoundedSet<T>
 where
     R: Rangetools<Inner = UpperBoundedSet<T>>,
-    T: Copy + Ord,
+    T: Element,
 {
     type Output = UpperBoundedSet<T>;
     fn intersection(self, other: R) -> Self::Output {
-        let other = other.to_inner();
-        let mut set = RangeIntersection::intersection(self.clone(), other.upper_bounded_range);
-        set.add_set(RangeIntersection::intersection(self, other.ranges));
-        set
+        RangeIntersection::intersection(&self, &other.to_inner())
     }
 }
 
@@ -96,37 +96,231 @@ where
 
 impl<T, R> RangeIntersection<R, UnboundedSet<T>> for UpperBoundedSet<T>
 where
-    R: Rangetools<Inner = UnboundedSet<T>> + Clone,
-    T: Copy + Ord,
+    R: Rangetools<Inner = UnboundedSet<T>>,
+    T: Element,
 {
     type Output = UpperBoundedSet<T>;
     fn intersection(self, other: R) -> Self::Output {
-        match other.to_inner() {
-            UnboundedSet::Full => self,
-            UnboundedSet::Piecewise(PiecewiseUnboundedSet {
-                lower_bounded_range,
-                ranges,
-                upper_bounded_range,
-            }) => {
-                let mut set = RangeIntersection::intersection(self.clone(), upper_bounded_range);
-                set.add_set(RangeIntersection::intersection(
-                    self.clone(),
-                    lower_bounded_range,
-                ));
-                set.add_set(RangeIntersection::intersection(self.clone(), ranges));
-                set
-            }
-        }
+        RangeIntersection::intersection(&self, &other.to_inner())
     }
 }
 
 impl<T, R> RangeIntersection<R, EmptyRange<T>> for UpperBoundedSet<T>
 where
     R: Rangetools<Inner = EmptyRange<T>>,
-    T: Copy + Ord,
+    T: Element,
 {
     type Output = EmptyRange<T>;
     fn intersection(self, other: R) -> Self::Output {
         other.to_inner()
     }
 }
+
+impl<T, R> RangeIntersection<R, AnySet<T>> for UpperBoundedSet<T>
+where
+    R: Rangetools<Inner = AnySet<T>>,
+    T: Element,
+{
+    type Output = AnySet<T>;
+    fn intersection(self, other: R) -> Self::Output {
+        RangeIntersection::intersection(other.to_inner(), self)
+    }
+}
+
+impl<'b, T, R> RangeIntersection<R, &'b BoundedSet<T>> for UpperBoundedSet<T>
+where
+    R: Rangetools<Inner = &'b BoundedSet<T>>,
+    T: Element,
+{
+    type Output = BoundedSet<T>;
+    fn intersection(self, other: R) -> Self::Output {
+        RangeIntersection::intersection(&self, other)
+    }
+}
+
+impl<'b, T, R> RangeIntersection<R, &'b LowerBoundedSet<T>> for UpperBoundedSet<T>
+where
+    R: Rangetools<Inner = &'b LowerBoundedSet<T>>,
+    T: Element,
+{
+    type Output = BoundedSet<T>;
+    fn intersection(self, other: R) -> Self::Output {
+        RangeIntersection::intersection(&self, other)
+    }
+}
+
+impl<'b, T, R> RangeIntersection<R, &'b UpperBoundedSet<T>> for UpperBoundedSet<T>
+where
+    R: Rangetools<Inner = &'b UpperBoundedSet<T>>,
+    T: Element,
+{
+    type Output = UpperBoundedSet<T>;
+    fn intersection(self, other: R) -> Self::Output {
+        RangeIntersection::intersection(&self, other)
+    }
+}
+
+impl<'b, T, R> RangeIntersection<R, &'b UnboundedSet<T>> for UpperBoundedSet<T>
+where
+    R: Rangetools<Inner = &'b UnboundedSet<T>>,
+    T: Element,
+{
+    type Output = UpperBoundedSet<T>;
+    fn intersection(self, other: R) -> Self::Output {
+        RangeIntersection::intersection(&self, other)
+    }
+}
+
+impl<T, R> RangeIntersection<R, BoundedRange<T>> for &UpperBoundedSet<T>
+where
+    R: Rangetools<Inner = BoundedRange<T>>,
+    T: Element,
+{
+    type Output = BoundedSet<T>;
+    fn intersection(self, other: R) -> Self::Output {
+        merge::intersection(self.into(), (&other.to_inner()).into()).into()
+    }
+}
+
+impl<T, R> RangeIntersection<R, BoundedSet<T>> for &UpperBoundedSet<T>
+where
+    R: Rangetools<Inner = BoundedSet<T>>,
+    T: Element,
+{
+    type Output = BoundedSet<T>;
+    fn intersection(self, other: R) -> Self::Output {
+        merge::intersection(self.into(), (&other.to_inner()).into()).into()
+    }
+}
+
+impl<T, R> RangeIntersection<R, LowerBoundedRange<T>> for &UpperBoundedSet<T>
+where
+    R: Rangetools<Inner = LowerBoundedRange<T>>,
+    T: Element,
+{
+    type Output = BoundedSet<T>;
+    fn intersection(self, other: R) -> Self::Output {
+        merge::intersection(self.into(), (&other.to_inner()).into()).into()
+    }
+}
+
+impl<T, R> RangeIntersection<R, LowerBoundedSet<T>> for &UpperBoundedSet<T>
+where
+    R: Rangetools<Inner = LowerBoundedSet<T>>,
+    T: Element,
+{
+    type Output = BoundedSet<T>;
+    fn intersection(self, other: R) -> Self::Output {
+        merge::intersection(self.into(), (&other.to_inner()).into()).into()
+    }
+}
+
+impl<T, R> RangeIntersection<R, UpperBoundedRange<T>> for &UpperBoundedSet<T>
+where
+    R: Rangetools<Inner = UpperBoundedRange<T>>,
+    T: Element,
+{
+    type Output = UpperBoundedSet<T>;
+    fn intersection(self, other: R) -> Self::Output {
+        merge::intersection(self.into(), (&other.to_inner()).into()).into()
+    }
+}
+
+impl<T, R> RangeIntersection<R, UpperBoundedSet<T>> for &UpperBoundedSet<T>
+where
+    R: Rangetools<Inner = UpperBoundedSet<T>>,
+    T: Element,
+{
+    type Output = UpperBoundedSet<T>;
+    fn intersection(self, other: R) -> Self::Output {
+        merge::intersection(self.into(), (&other.to_inner()).into()).into()
+    }
+}
+
+impl<T, R> RangeIntersection<R, UnboundedRange> for &UpperBoundedSet<T>
+where
+    R: Rangetools<Inner = UnboundedRange>,
+    T: Element,
+{
+    type Output = UpperBoundedSet<T>;
+    fn intersection(self, other: R) -> Self::Output {
+        merge::intersection(self.into(), (&other.to_inner()).into()).into()
+    }
+}
+
+impl<T, R> RangeIntersection<R, UnboundedSet<T>> for &UpperBoundedSet<T>
+where
+    R: Rangetools<Inner = UnboundedSet<T>>,
+    T: Element,
+{
+    type Output = UpperBoundedSet<T>;
+    fn intersection(self, other: R) -> Self::Output {
+        merge::intersection(self.into(), (&other.to_inner()).into()).into()
+    }
+}
+
+impl<T, R> RangeIntersection<R, EmptyRange<T>> for &UpperBoundedSet<T>
+where
+    R: Rangetools<Inner = EmptyRange<T>>,
+    T: Element,
+{
+    type Output = EmptyRange<T>;
+    fn intersection(self, other: R) -> Self::Output {
+        other.to_inner()
+    }
+}
+
+impl<T, R> RangeIntersection<R, AnySet<T>> for &UpperBoundedSet<T>
+where
+    R: Rangetools<Inner = AnySet<T>>,
+    T: Element,
+{
+    type Output = AnySet<T>;
+    fn intersection(self, other: R) -> Self::Output {
+        merge::intersection(self.into(), (&other.to_inner()).into()).into()
+    }
+}
+
+impl<'b, T, R> RangeIntersection<R, &'b BoundedSet<T>> for &UpperBoundedSet<T>
+where
+    R: Rangetools<Inner = &'b BoundedSet<T>>,
+    T: Element,
+{
+    type Output = BoundedSet<T>;
+    fn intersection(self, other: R) -> Self::Output {
+        merge::intersection(self.into(), other.to_inner().into()).into()
+    }
+}
+
+impl<'b, T, R> RangeIntersection<R, &'b LowerBoundedSet<T>> for &UpperBoundedSet<T>
+where
+    R: Rangetools<Inner = &'b LowerBoundedSet<T>>,
+    T: Element,
+{
+    type Output = BoundedSet<T>;
+    fn intersection(self, other: R) -> Self::Output {
+        merge::intersection(self.into(), other.to_inner().into()).into()
+    }
+}
+
+impl<'b, T, R> RangeIntersection<R, &'b UpperBoundedSet<T>> for &UpperBoundedSet<T>
+where
+    R: Rangetools<Inner = &'b UpperBoundedSet<T>>,
+    T: Element,
+{
+    type Output = UpperBoundedSet<T>;
+    fn intersection(self, other: R) -> Self::Output {
+        merge::intersection(self.into(), other.to_inner().into()).into()
+    }
+}
+
+impl<'b, T, R> RangeIntersection<R, &'b UnboundedSet<T>> for &UpperBoundedSet<T>
+where
+    R: Rangetools<Inner = &'b UnboundedSet<T>>,
+    T: Element,
+{
+    type Output = UpperBoundedSet<T>;
+    fn intersection(self, other: R) -> Self::Output {
+        merge::intersection(self.into(), other.to_inner().into()).into()
+    }
+}
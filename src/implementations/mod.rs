@@ -1,5 +1,9 @@
 mod complement;
+mod difference;
 mod index;
 mod intersection;
+mod ops;
+mod query;
 mod rangetools;
+mod symmetric_difference;
 mod union;
@@ -0,0 +1,58 @@
+use crate::{
+    BoundedRange, Element, RangeComplement, RangeDifference, RangeIntersection,
+    RangeSymmetricDifference, RangeUnion, Rangetools, UnboundedSet,
+};
+
+impl<T, R> std::ops::BitAnd<R> for BoundedRange<T>
+where
+    R: Rangetools,
+    Self: RangeIntersection<R, R::Inner>,
+{
+    type Output = <Self as RangeIntersection<R, R::Inner>>::Output;
+    fn bitand(self, rhs: R) -> Self::Output {
+        RangeIntersection::intersection(self, rhs)
+    }
+}
+
+impl<T, R> std::ops::BitOr<R> for BoundedRange<T>
+where
+    R: Rangetools,
+    Self: RangeUnion<R, R::Set>,
+{
+    type Output = <Self as RangeUnion<R, R::Set>>::Output;
+    fn bitor(self, rhs: R) -> Self::Output {
+        RangeUnion::union(self, rhs)
+    }
+}
+
+impl<T, R> std::ops::Sub<R> for BoundedRange<T>
+where
+    R: Rangetools,
+    Self: RangeDifference<R, R::Inner>,
+{
+    type Output = <Self as RangeDifference<R, R::Inner>>::Output;
+    fn sub(self, rhs: R) -> Self::Output {
+        RangeDifference::difference(self, rhs)
+    }
+}
+
+impl<T, R> std::ops::BitXor<R> for BoundedRange<T>
+where
+    R: Rangetools,
+    Self: RangeSymmetricDifference<R, R::Set>,
+{
+    type Output = <Self as RangeSymmetricDifference<R, R::Set>>::Output;
+    fn bitxor(self, rhs: R) -> Self::Output {
+        RangeSymmetricDifference::symmetric_difference(self, rhs)
+    }
+}
+
+impl<T> std::ops::Not for BoundedRange<T>
+where
+    T: Element,
+{
+    type Output = UnboundedSet<T>;
+    fn not(self) -> Self::Output {
+        RangeComplement::complement(self)
+    }
+}
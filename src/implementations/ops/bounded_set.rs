@@ -0,0 +1,112 @@
+use crate::{
+    BoundedSet, Element, RangeComplement, RangeDifference, RangeIntersection,
+    RangeSymmetricDifference, RangeUnion, Rangetools, UnboundedSet,
+};
+
+impl<T, R> std::ops::BitAnd<R> for BoundedSet<T>
+where
+    R: Rangetools,
+    Self: RangeIntersection<R, R::Inner>,
+{
+    type Output = <Self as RangeIntersection<R, R::Inner>>::Output;
+    fn bitand(self, rhs: R) -> Self::Output {
+        RangeIntersection::intersection(self, rhs)
+    }
+}
+
+impl<T, R> std::ops::BitOr<R> for BoundedSet<T>
+where
+    R: Rangetools,
+    Self: RangeUnion<R, R::Set>,
+{
+    type Output = <Self as RangeUnion<R, R::Set>>::Output;
+    fn bitor(self, rhs: R) -> Self::Output {
+        RangeUnion::union(self, rhs)
+    }
+}
+
+impl<T, R> std::ops::Sub<R> for BoundedSet<T>
+where
+    R: Rangetools,
+    Self: RangeDifference<R, R::Inner>,
+{
+    type Output = <Self as RangeDifference<R, R::Inner>>::Output;
+    fn sub(self, rhs: R) -> Self::Output {
+        RangeDifference::difference(self, rhs)
+    }
+}
+
+impl<T, R> std::ops::BitXor<R> for BoundedSet<T>
+where
+    R: Rangetools,
+    Self: RangeSymmetricDifference<R, R::Set>,
+{
+    type Output = <Self as RangeSymmetricDifference<R, R::Set>>::Output;
+    fn bitxor(self, rhs: R) -> Self::Output {
+        RangeSymmetricDifference::symmetric_difference(self, rhs)
+    }
+}
+
+impl<T> std::ops::Not for BoundedSet<T>
+where
+    T: Element,
+{
+    type Output = UnboundedSet<T>;
+    fn not(self) -> Self::Output {
+        RangeComplement::complement(self)
+    }
+}
+
+impl<T, R> std::ops::BitAnd<R> for &BoundedSet<T>
+where
+    R: Rangetools,
+    Self: RangeIntersection<R, R::Inner>,
+{
+    type Output = <Self as RangeIntersection<R, R::Inner>>::Output;
+    fn bitand(self, rhs: R) -> Self::Output {
+        RangeIntersection::intersection(self, rhs)
+    }
+}
+
+impl<T, R> std::ops::BitOr<R> for &BoundedSet<T>
+where
+    R: Rangetools,
+    Self: RangeUnion<R, R::Set>,
+{
+    type Output = <Self as RangeUnion<R, R::Set>>::Output;
+    fn bitor(self, rhs: R) -> Self::Output {
+        RangeUnion::union(self, rhs)
+    }
+}
+
+impl<T, R> std::ops::Sub<R> for &BoundedSet<T>
+where
+    R: Rangetools,
+    Self: RangeDifference<R, R::Inner>,
+{
+    type Output = <Self as RangeDifference<R, R::Inner>>::Output;
+    fn sub(self, rhs: R) -> Self::Output {
+        RangeDifference::difference(self, rhs)
+    }
+}
+
+impl<T, R> std::ops::BitXor<R> for &BoundedSet<T>
+where
+    R: Rangetools,
+    Self: RangeSymmetricDifference<R, R::Set>,
+{
+    type Output = <Self as RangeSymmetricDifference<R, R::Set>>::Output;
+    fn bitxor(self, rhs: R) -> Self::Output {
+        RangeSymmetricDifference::symmetric_difference(self, rhs)
+    }
+}
+
+impl<T> std::ops::Not for &BoundedSet<T>
+where
+    T: Element,
+{
+    type Output = UnboundedSet<T>;
+    fn not(self) -> Self::Output {
+        RangeComplement::complement(self)
+    }
+}
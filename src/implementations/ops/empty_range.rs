@@ -0,0 +1,55 @@
+use crate::{
+    EmptyRange, RangeComplement, RangeDifference, RangeIntersection, RangeSymmetricDifference,
+    RangeUnion, Rangetools, UnboundedRange,
+};
+
+impl<T, R> std::ops::BitAnd<R> for EmptyRange<T>
+where
+    R: Rangetools,
+    Self: RangeIntersection<R, R::Inner>,
+{
+    type Output = <Self as RangeIntersection<R, R::Inner>>::Output;
+    fn bitand(self, rhs: R) -> Self::Output {
+        RangeIntersection::intersection(self, rhs)
+    }
+}
+
+impl<T, R> std::ops::BitOr<R> for EmptyRange<T>
+where
+    R: Rangetools,
+    Self: RangeUnion<R, R::Set>,
+{
+    type Output = <Self as RangeUnion<R, R::Set>>::Output;
+    fn bitor(self, rhs: R) -> Self::Output {
+        RangeUnion::union(self, rhs)
+    }
+}
+
+impl<T, R> std::ops::Sub<R> for EmptyRange<T>
+where
+    R: Rangetools,
+    Self: RangeDifference<R, R::Inner>,
+{
+    type Output = <Self as RangeDifference<R, R::Inner>>::Output;
+    fn sub(self, rhs: R) -> Self::Output {
+        RangeDifference::difference(self, rhs)
+    }
+}
+
+impl<T, R> std::ops::BitXor<R> for EmptyRange<T>
+where
+    R: Rangetools,
+    Self: RangeSymmetricDifference<R, R::Set>,
+{
+    type Output = <Self as RangeSymmetricDifference<R, R::Set>>::Output;
+    fn bitxor(self, rhs: R) -> Self::Output {
+        RangeSymmetricDifference::symmetric_difference(self, rhs)
+    }
+}
+
+impl<T> std::ops::Not for EmptyRange<T> {
+    type Output = UnboundedRange;
+    fn not(self) -> Self::Output {
+        RangeComplement::complement(self)
+    }
+}
@@ -0,0 +1,11 @@
+mod bounded_range;
+mod empty_range;
+mod lower_bounded_range;
+mod unbounded_range;
+mod upper_bounded_range;
+
+mod any_set;
+mod bounded_set;
+mod lower_bounded_set;
+mod unbounded_set;
+mod upper_bounded_set;
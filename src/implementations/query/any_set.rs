@@ -0,0 +1,62 @@
+use crate::{AnySet, Components, Element, LowerBound, RangeQuery, UpperBound};
+
+impl<T> RangeQuery<T> for AnySet<T>
+where
+    T: Element,
+{
+    fn contains(&self, t: &T) -> bool {
+        match self {
+            Self::EmptyRange(r) => RangeQuery::contains(r, t),
+            Self::BoundedRange(r) => RangeQuery::contains(r, t),
+            Self::BoundedSet(s) => RangeQuery::contains(s, t),
+            Self::LowerBoundedRange(r) => RangeQuery::contains(r, t),
+            Self::LowerBoundedSet(s) => RangeQuery::contains(s, t),
+            Self::UpperBoundedRange(r) => RangeQuery::contains(r, t),
+            Self::UpperBoundedSet(s) => RangeQuery::contains(s, t),
+            Self::UnboundedRange(r) => RangeQuery::<T>::contains(r, t),
+            Self::UnboundedSet(s) => RangeQuery::contains(s, t),
+        }
+    }
+
+    fn lower_bound(&self) -> Option<LowerBound<T>> {
+        match self {
+            Self::EmptyRange(r) => RangeQuery::lower_bound(r),
+            Self::BoundedRange(r) => RangeQuery::lower_bound(r),
+            Self::BoundedSet(s) => RangeQuery::lower_bound(s),
+            Self::LowerBoundedRange(r) => RangeQuery::lower_bound(r),
+            Self::LowerBoundedSet(s) => RangeQuery::lower_bound(s),
+            Self::UpperBoundedRange(r) => RangeQuery::lower_bound(r),
+            Self::UpperBoundedSet(s) => RangeQuery::lower_bound(s),
+            Self::UnboundedRange(r) => RangeQuery::<T>::lower_bound(r),
+            Self::UnboundedSet(s) => RangeQuery::lower_bound(s),
+        }
+    }
+
+    fn upper_bound(&self) -> Option<UpperBound<T>> {
+        match self {
+            Self::EmptyRange(r) => RangeQuery::upper_bound(r),
+            Self::BoundedRange(r) => RangeQuery::upper_bound(r),
+            Self::BoundedSet(s) => RangeQuery::upper_bound(s),
+            Self::LowerBoundedRange(r) => RangeQuery::upper_bound(r),
+            Self::LowerBoundedSet(s) => RangeQuery::upper_bound(s),
+            Self::UpperBoundedRange(r) => RangeQuery::upper_bound(r),
+            Self::UpperBoundedSet(s) => RangeQuery::upper_bound(s),
+            Self::UnboundedRange(r) => RangeQuery::<T>::upper_bound(r),
+            Self::UnboundedSet(s) => RangeQuery::upper_bound(s),
+        }
+    }
+
+    fn components(&self) -> Components<'_, T> {
+        match self {
+            Self::EmptyRange(r) => RangeQuery::components(r),
+            Self::BoundedRange(r) => RangeQuery::components(r),
+            Self::BoundedSet(s) => RangeQuery::components(s),
+            Self::LowerBoundedRange(r) => RangeQuery::components(r),
+            Self::LowerBoundedSet(s) => RangeQuery::components(s),
+            Self::UpperBoundedRange(r) => RangeQuery::components(r),
+            Self::UpperBoundedSet(s) => RangeQuery::components(s),
+            Self::UnboundedRange(r) => RangeQuery::<T>::components(r),
+            Self::UnboundedSet(s) => RangeQuery::components(s),
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use crate::{AnySet, Components, Element, LowerBound, RangeQuery, UpperBound};
+
+impl<T> RangeQuery<T> for (std::ops::Bound<T>, std::ops::Bound<T>)
+where
+    T: Element,
+{
+    fn contains(&self, t: &T) -> bool {
+        std::ops::RangeBounds::contains(self, t)
+    }
+
+    fn lower_bound(&self) -> Option<LowerBound<T>> {
+        RangeQuery::lower_bound(&AnySet::from(self.clone()))
+    }
+
+    fn upper_bound(&self) -> Option<UpperBound<T>> {
+        RangeQuery::upper_bound(&AnySet::from(self.clone()))
+    }
+
+    fn components(&self) -> Components<'_, T> {
+        // The bounds describe a single range, so there's at most one component.
+        Components::single(AnySet::from(self.clone()).components().next())
+    }
+}
@@ -0,0 +1,22 @@
+use crate::{BoundedRange, Component, Components, Element, LowerBound, RangeQuery, UpperBound};
+
+impl<T> RangeQuery<T> for BoundedRange<T>
+where
+    T: Element,
+{
+    fn contains(&self, t: &T) -> bool {
+        self.contains(t)
+    }
+
+    fn lower_bound(&self) -> Option<LowerBound<T>> {
+        self.lower_bound()
+    }
+
+    fn upper_bound(&self) -> Option<UpperBound<T>> {
+        self.upper_bound()
+    }
+
+    fn components(&self) -> Components<'_, T> {
+        Components::single(self.span().map(Component::Bounded))
+    }
+}
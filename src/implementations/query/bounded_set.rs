@@ -0,0 +1,22 @@
+use crate::{BoundedSet, Components, Element, LowerBound, RangeQuery, UpperBound};
+
+impl<T> RangeQuery<T> for BoundedSet<T>
+where
+    T: Element,
+{
+    fn contains(&self, t: &T) -> bool {
+        self.contains(t)
+    }
+
+    fn lower_bound(&self) -> Option<LowerBound<T>> {
+        self.lower_bound()
+    }
+
+    fn upper_bound(&self) -> Option<UpperBound<T>> {
+        self.upper_bound()
+    }
+
+    fn components(&self) -> Components<'_, T> {
+        self.components()
+    }
+}
@@ -0,0 +1,22 @@
+use crate::{Components, Element, EmptyRange, LowerBound, RangeQuery, UpperBound};
+
+impl<T> RangeQuery<T> for EmptyRange<T>
+where
+    T: Element,
+{
+    fn contains(&self, _: &T) -> bool {
+        false
+    }
+
+    fn lower_bound(&self) -> Option<LowerBound<T>> {
+        None
+    }
+
+    fn upper_bound(&self) -> Option<UpperBound<T>> {
+        None
+    }
+
+    fn components(&self) -> Components<'_, T> {
+        Components::single(None)
+    }
+}
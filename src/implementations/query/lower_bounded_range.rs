@@ -0,0 +1,24 @@
+use crate::{
+    Component, Components, Element, LowerBound, LowerBoundedRange, RangeQuery, UpperBound,
+};
+
+impl<T> RangeQuery<T> for LowerBoundedRange<T>
+where
+    T: Element,
+{
+    fn contains(&self, t: &T) -> bool {
+        self.contains(t)
+    }
+
+    fn lower_bound(&self) -> Option<LowerBound<T>> {
+        Some(self.lower_bound())
+    }
+
+    fn upper_bound(&self) -> Option<UpperBound<T>> {
+        None
+    }
+
+    fn components(&self) -> Components<'_, T> {
+        Components::single(Some(Component::LowerBounded(self.clone())))
+    }
+}
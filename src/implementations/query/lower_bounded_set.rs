@@ -0,0 +1,22 @@
+use crate::{Components, Element, LowerBound, LowerBoundedSet, RangeQuery, UpperBound};
+
+impl<T> RangeQuery<T> for LowerBoundedSet<T>
+where
+    T: Element,
+{
+    fn contains(&self, t: &T) -> bool {
+        self.contains(t)
+    }
+
+    fn lower_bound(&self) -> Option<LowerBound<T>> {
+        Some(self.lower_bound())
+    }
+
+    fn upper_bound(&self) -> Option<UpperBound<T>> {
+        None
+    }
+
+    fn components(&self) -> Components<'_, T> {
+        self.components()
+    }
+}
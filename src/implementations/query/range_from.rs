@@ -0,0 +1,22 @@
+use crate::{Component, Components, Element, LowerBound, RangeQuery, Rangetools, UpperBound};
+
+impl<T> RangeQuery<T> for std::ops::RangeFrom<T>
+where
+    T: Element,
+{
+    fn contains(&self, t: &T) -> bool {
+        std::ops::RangeFrom::contains(self, t)
+    }
+
+    fn lower_bound(&self) -> Option<LowerBound<T>> {
+        RangeQuery::lower_bound(&self.clone().to_inner())
+    }
+
+    fn upper_bound(&self) -> Option<UpperBound<T>> {
+        RangeQuery::upper_bound(&self.clone().to_inner())
+    }
+
+    fn components(&self) -> Components<'_, T> {
+        Components::single(Some(Component::LowerBounded(self.clone().to_inner())))
+    }
+}
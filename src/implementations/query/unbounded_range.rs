@@ -0,0 +1,22 @@
+use crate::{Component, Components, Element, LowerBound, RangeQuery, UnboundedRange, UpperBound};
+
+impl<T> RangeQuery<T> for UnboundedRange
+where
+    T: Element,
+{
+    fn contains(&self, _: &T) -> bool {
+        true
+    }
+
+    fn lower_bound(&self) -> Option<LowerBound<T>> {
+        None
+    }
+
+    fn upper_bound(&self) -> Option<UpperBound<T>> {
+        None
+    }
+
+    fn components(&self) -> Components<'_, T> {
+        Components::single(Some(Component::Unbounded(*self)))
+    }
+}
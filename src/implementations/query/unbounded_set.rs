@@ -0,0 +1,22 @@
+use crate::{Components, Element, LowerBound, RangeQuery, UnboundedSet, UpperBound};
+
+impl<T> RangeQuery<T> for UnboundedSet<T>
+where
+    T: Element,
+{
+    fn contains(&self, t: &T) -> bool {
+        self.contains(t)
+    }
+
+    fn lower_bound(&self) -> Option<LowerBound<T>> {
+        None
+    }
+
+    fn upper_bound(&self) -> Option<UpperBound<T>> {
+        None
+    }
+
+    fn components(&self) -> Components<'_, T> {
+        self.components()
+    }
+}
@@ -0,0 +1,24 @@
+use crate::{
+    Component, Components, Element, LowerBound, RangeQuery, UpperBound, UpperBoundedRange,
+};
+
+impl<T> RangeQuery<T> for UpperBoundedRange<T>
+where
+    T: Element,
+{
+    fn contains(&self, t: &T) -> bool {
+        self.contains(t)
+    }
+
+    fn lower_bound(&self) -> Option<LowerBound<T>> {
+        None
+    }
+
+    fn upper_bound(&self) -> Option<UpperBound<T>> {
+        Some(self.upper_bound())
+    }
+
+    fn components(&self) -> Components<'_, T> {
+        Components::single(Some(Component::UpperBounded(self.clone())))
+    }
+}
@@ -0,0 +1,22 @@
+use crate::{Components, Element, LowerBound, RangeQuery, UpperBound, UpperBoundedSet};
+
+impl<T> RangeQuery<T> for UpperBoundedSet<T>
+where
+    T: Element,
+{
+    fn contains(&self, t: &T) -> bool {
+        self.contains(t)
+    }
+
+    fn lower_bound(&self) -> Option<LowerBound<T>> {
+        None
+    }
+
+    fn upper_bound(&self) -> Option<UpperBound<T>> {
+        Some(self.upper_bound())
+    }
+
+    fn components(&self) -> Components<'_, T> {
+        self.components()
+    }
+}
@@ -0,0 +1,27 @@
+use crate::{AnySet, Element, Rangetools};
+
+impl<T: Element> Rangetools for AnySet<T> {
+    fn is_empty(&self) -> bool {
+        match self {
+            Self::EmptyRange(r) => r.is_empty(),
+            Self::BoundedRange(r) => r.is_empty(),
+            Self::BoundedSet(s) => s.is_empty(),
+            Self::LowerBoundedRange(r) => r.is_empty(),
+            Self::LowerBoundedSet(s) => s.is_empty(),
+            Self::UpperBoundedRange(r) => r.is_empty(),
+            Self::UpperBoundedSet(s) => s.is_empty(),
+            Self::UnboundedRange(r) => r.is_empty(),
+            Self::UnboundedSet(s) => s.is_empty(),
+        }
+    }
+
+    type Inner = Self;
+    fn to_inner(self) -> Self::Inner {
+        self
+    }
+
+    type Set = Self;
+    fn to_set(self) -> Self::Set {
+        self
+    }
+}
@@ -0,0 +1,17 @@
+use crate::{AnySet, Element, Rangetools};
+
+impl<T: Element> Rangetools for (std::ops::Bound<T>, std::ops::Bound<T>) {
+    fn is_empty(&self) -> bool {
+        self.clone().to_inner().is_empty()
+    }
+
+    type Inner = AnySet<T>;
+    fn to_inner(self) -> Self::Inner {
+        self.into()
+    }
+
+    type Set = AnySet<T>;
+    fn to_set(self) -> Self::Set {
+        self.into()
+    }
+}
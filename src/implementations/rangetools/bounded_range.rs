@@ -1,13 +1,8 @@
-use crate::{Bound, BoundedRange, BoundedSet, Rangetools};
+use crate::{BoundedRange, BoundedSet, Element, Rangetools};
 
-impl<T: Copy + Ord> Rangetools for BoundedRange<T> {
+impl<T: Element> Rangetools for BoundedRange<T> {
     fn is_empty(&self) -> bool {
-        match (self.start.0, self.end.0) {
-            (Bound::Included(start), Bound::Included(end)) => start > end,
-            (Bound::Excluded(start), Bound::Included(end))
-            | (Bound::Included(start), Bound::Excluded(end))
-            | (Bound::Excluded(start), Bound::Excluded(end)) => start >= end,
-        }
+        self.canonical_bounds().is_none()
     }
 
     type Inner = Self;
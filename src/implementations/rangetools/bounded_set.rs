@@ -1,6 +1,6 @@
-use crate::{BoundedSet, Rangetools};
+use crate::{BoundedSet, Element, Rangetools};
 
-impl<T: Copy + Ord> Rangetools for BoundedSet<T> {
+impl<T: Element> Rangetools for BoundedSet<T> {
     fn is_empty(&self) -> bool {
         self.ranges.iter().all(|r| r.is_empty())
     }
@@ -15,3 +15,19 @@ impl<T: Copy + Ord> Rangetools for BoundedSet<T> {
         self
     }
 }
+
+impl<T: Element> Rangetools for &BoundedSet<T> {
+    fn is_empty(&self) -> bool {
+        Rangetools::is_empty(*self)
+    }
+
+    type Inner = Self;
+    fn to_inner(self) -> Self::Inner {
+        self
+    }
+
+    type Set = Self;
+    fn to_set(self) -> Self::Set {
+        self
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{LowerBoundedSet, Rangetools};
+use crate::{Element, LowerBoundedSet, Rangetools};
 
 impl<T> Rangetools for LowerBoundedSet<T> {
     fn is_empty(&self) -> bool {
@@ -15,3 +15,19 @@ impl<T> Rangetools for LowerBoundedSet<T> {
         self
     }
 }
+
+impl<T: Element> Rangetools for &LowerBoundedSet<T> {
+    fn is_empty(&self) -> bool {
+        Rangetools::is_empty(*self)
+    }
+
+    type Inner = Self;
+    fn to_inner(self) -> Self::Inner {
+        self
+    }
+
+    type Set = Self;
+    fn to_set(self) -> Self::Set {
+        self
+    }
+}
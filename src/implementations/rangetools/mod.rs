@@ -1,3 +1,4 @@
+mod bound_tuple;
 mod range;
 mod range_from;
 mod range_full;
@@ -11,6 +12,7 @@ mod lower_bounded_range;
 mod unbounded_range;
 mod upper_bounded_range;
 
+mod any_set;
 mod bounded_set;
 mod lower_bounded_set;
 mod unbounded_set;
@@ -1,6 +1,6 @@
-use crate::{LowerBoundedRange, LowerBoundedSet, Rangetools};
+use crate::{Element, LowerBoundedRange, LowerBoundedSet, Rangetools};
 
-impl<T: Copy + Ord> Rangetools for std::ops::RangeFrom<T> {
+impl<T: Element> Rangetools for std::ops::RangeFrom<T> {
     fn is_empty(&self) -> bool {
         false
     }
@@ -1,6 +1,6 @@
-use crate::{BoundedRange, BoundedSet, Rangetools};
+use crate::{BoundedRange, BoundedSet, Element, Rangetools};
 
-impl<T: Copy + Ord> Rangetools for std::ops::RangeInclusive<T> {
+impl<T: Element> Rangetools for std::ops::RangeInclusive<T> {
     fn is_empty(&self) -> bool {
         self.start() > self.end()
     }
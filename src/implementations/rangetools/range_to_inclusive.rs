@@ -1,6 +1,6 @@
-use crate::{Rangetools, UpperBoundedRange, UpperBoundedSet};
+use crate::{Element, Rangetools, UpperBoundedRange, UpperBoundedSet};
 
-impl<T: Copy + Ord> Rangetools for std::ops::RangeToInclusive<T> {
+impl<T: Element> Rangetools for std::ops::RangeToInclusive<T> {
     fn is_empty(&self) -> bool {
         false
     }
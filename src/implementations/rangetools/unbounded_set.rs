@@ -1,4 +1,4 @@
-use crate::{Rangetools, UnboundedSet};
+use crate::{Element, Rangetools, UnboundedSet};
 
 impl<T> Rangetools for UnboundedSet<T> {
     fn is_empty(&self) -> bool {
@@ -15,3 +15,19 @@ impl<T> Rangetools for UnboundedSet<T> {
         self
     }
 }
+
+impl<T: Element> Rangetools for &UnboundedSet<T> {
+    fn is_empty(&self) -> bool {
+        Rangetools::is_empty(*self)
+    }
+
+    type Inner = Self;
+    fn to_inner(self) -> Self::Inner {
+        self
+    }
+
+    type Set = Self;
+    fn to_set(self) -> Self::Set {
+        self
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{Rangetools, UpperBoundedSet};
+use crate::{Element, Rangetools, UpperBoundedSet};
 
 impl<T> Rangetools for UpperBoundedSet<T> {
     fn is_empty(&self) -> bool {
@@ -15,3 +15,19 @@ impl<T> Rangetools for UpperBoundedSet<T> {
         self
     }
 }
+
+impl<T: Element> Rangetools for &UpperBoundedSet<T> {
+    fn is_empty(&self) -> bool {
+        Rangetools::is_empty(*self)
+    }
+
+    type Inner = Self;
+    fn to_inner(self) -> Self::Inner {
+        self
+    }
+
+    type Set = Self;
+    fn to_set(self) -> Self::Set {
+        self
+    }
+}
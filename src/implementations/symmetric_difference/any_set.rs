@@ -0,0 +1,56 @@
+use crate::{
+    AnySet, BoundedRange, BoundedSet, EmptyRange, LowerBoundedRange, LowerBoundedSet,
+    RangeSymmetricDifference, Rangetools, UnboundedSet, UpperBoundedRange, UpperBoundedSet,
+};
+
+impl<T, R, RhsSet> RangeSymmetricDifference<R, RhsSet> for AnySet<T>
+where
+    R: Rangetools<Set = RhsSet>,
+    EmptyRange<T>: RangeSymmetricDifference<R, RhsSet>,
+    BoundedRange<T>: RangeSymmetricDifference<R, RhsSet>,
+    BoundedSet<T>: RangeSymmetricDifference<R, RhsSet>,
+    LowerBoundedRange<T>: RangeSymmetricDifference<R, RhsSet>,
+    LowerBoundedSet<T>: RangeSymmetricDifference<R, RhsSet>,
+    UpperBoundedRange<T>: RangeSymmetricDifference<R, RhsSet>,
+    UpperBoundedSet<T>: RangeSymmetricDifference<R, RhsSet>,
+    UnboundedSet<T>: RangeSymmetricDifference<R, RhsSet>,
+    AnySet<T>: From<<EmptyRange<T> as RangeSymmetricDifference<R, RhsSet>>::Output>,
+    AnySet<T>: From<<BoundedRange<T> as RangeSymmetricDifference<R, RhsSet>>::Output>,
+    AnySet<T>: From<<BoundedSet<T> as RangeSymmetricDifference<R, RhsSet>>::Output>,
+    AnySet<T>: From<<LowerBoundedRange<T> as RangeSymmetricDifference<R, RhsSet>>::Output>,
+    AnySet<T>: From<<LowerBoundedSet<T> as RangeSymmetricDifference<R, RhsSet>>::Output>,
+    AnySet<T>: From<<UpperBoundedRange<T> as RangeSymmetricDifference<R, RhsSet>>::Output>,
+    AnySet<T>: From<<UpperBoundedSet<T> as RangeSymmetricDifference<R, RhsSet>>::Output>,
+    AnySet<T>: From<<UnboundedSet<T> as RangeSymmetricDifference<R, RhsSet>>::Output>,
+{
+    type Output = AnySet<T>;
+    fn symmetric_difference(self, other: R) -> Self::Output {
+        match self {
+            Self::EmptyRange(r) => RangeSymmetricDifference::symmetric_difference(r, other).into(),
+            Self::BoundedRange(r) => {
+                RangeSymmetricDifference::symmetric_difference(r, other).into()
+            }
+            Self::BoundedSet(r) => RangeSymmetricDifference::symmetric_difference(r, other).into(),
+            Self::LowerBoundedRange(r) => {
+                RangeSymmetricDifference::symmetric_difference(r, other).into()
+            }
+            Self::LowerBoundedSet(r) => {
+                RangeSymmetricDifference::symmetric_difference(r, other).into()
+            }
+            Self::UpperBoundedRange(r) => {
+                RangeSymmetricDifference::symmetric_difference(r, other).into()
+            }
+            Self::UpperBoundedSet(r) => {
+                RangeSymmetricDifference::symmetric_difference(r, other).into()
+            }
+            // With no element type, `UnboundedRange` can't be combined with another
+            // `UnboundedRange`, so go through the equivalent `UnboundedSet`.
+            Self::UnboundedRange(r) => {
+                RangeSymmetricDifference::symmetric_difference(UnboundedSet::from(r), other).into()
+            }
+            Self::UnboundedSet(r) => {
+                RangeSymmetricDifference::symmetric_difference(r, other).into()
+            }
+        }
+    }
+}
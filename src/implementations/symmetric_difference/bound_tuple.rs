@@ -0,0 +1,13 @@
+use crate::{AnySet, Element, RangeSymmetricDifference, Rangetools};
+
+impl<T, R, RhsSet> RangeSymmetricDifference<R, RhsSet> for (std::ops::Bound<T>, std::ops::Bound<T>)
+where
+    R: Rangetools<Set = RhsSet>,
+    T: Element,
+    AnySet<T>: RangeSymmetricDifference<R, RhsSet>,
+{
+    type Output = <AnySet<T> as RangeSymmetricDifference<R, RhsSet>>::Output;
+    fn symmetric_difference(self, other: R) -> Self::Output {
+        RangeSymmetricDifference::symmetric_difference(self.to_set(), other)
+    }
+}
@@ -0,0 +1,246 @@
+use crate::{
+    merge, AnySet, BoundedSet, Element, EmptyRange, LowerBoundedSet, RangeComplement,
+    RangeSymmetricDifference, Rangetools, UnboundedRange, UnboundedSet, UpperBoundedSet,
+};
+
+impl<T, R> RangeSymmetricDifference<R, BoundedSet<T>> for BoundedSet<T>
+where
+    R: Rangetools<Set = BoundedSet<T>>,
+    T: Element,
+{
+    type Output = BoundedSet<T>;
+    fn symmetric_difference(self, other: R) -> Self::Output {
+        merge::symmetric_difference((&self).into(), (&other.to_set()).into()).into()
+    }
+}
+
+impl<T, R> RangeSymmetricDifference<R, LowerBoundedSet<T>> for BoundedSet<T>
+where
+    R: Rangetools<Set = LowerBoundedSet<T>>,
+    T: Element,
+{
+    type Output = LowerBoundedSet<T>;
+    fn symmetric_difference(self, other: R) -> Self::Output {
+        merge::symmetric_difference((&self).into(), (&other.to_set()).into()).into()
+    }
+}
+
+impl<T, R> RangeSymmetricDifference<R, UpperBoundedSet<T>> for BoundedSet<T>
+where
+    R: Rangetools<Set = UpperBoundedSet<T>>,
+    T: Element,
+{
+    type Output = UpperBoundedSet<T>;
+    fn symmetric_difference(self, other: R) -> Self::Output {
+        merge::symmetric_difference((&self).into(), (&other.to_set()).into()).into()
+    }
+}
+
+impl<T, R> RangeSymmetricDifference<R, UnboundedSet<T>> for BoundedSet<T>
+where
+    R: Rangetools<Set = UnboundedSet<T>>,
+    T: Element,
+{
+    type Output = UnboundedSet<T>;
+    fn symmetric_difference(self, other: R) -> Self::Output {
+        merge::symmetric_difference((&self).into(), (&other.to_set()).into()).into()
+    }
+}
+
+impl<T, R> RangeSymmetricDifference<R, UnboundedRange> for BoundedSet<T>
+where
+    R: Rangetools<Set = UnboundedRange>,
+    T: Element,
+{
+    type Output = UnboundedSet<T>;
+    fn symmetric_difference(self, _: R) -> Self::Output {
+        RangeComplement::complement(self)
+    }
+}
+
+impl<T, R> RangeSymmetricDifference<R, EmptyRange<T>> for BoundedSet<T>
+where
+    R: Rangetools<Set = EmptyRange<T>>,
+    T: Element,
+{
+    type Output = BoundedSet<T>;
+    fn symmetric_difference(self, other: R) -> Self::Output {
+        RangeSymmetricDifference::symmetric_difference(other.to_set(), self)
+    }
+}
+
+impl<T, R> RangeSymmetricDifference<R, AnySet<T>> for BoundedSet<T>
+where
+    R: Rangetools<Set = AnySet<T>>,
+    T: Element,
+{
+    type Output = AnySet<T>;
+    fn symmetric_difference(self, other: R) -> Self::Output {
+        RangeSymmetricDifference::symmetric_difference(other.to_set(), self)
+    }
+}
+
+impl<'b, T, R> RangeSymmetricDifference<R, &'b BoundedSet<T>> for BoundedSet<T>
+where
+    R: Rangetools<Set = &'b BoundedSet<T>>,
+    T: Element,
+{
+    type Output = BoundedSet<T>;
+    fn symmetric_difference(self, other: R) -> Self::Output {
+        RangeSymmetricDifference::symmetric_difference(&self, other)
+    }
+}
+
+impl<'b, T, R> RangeSymmetricDifference<R, &'b LowerBoundedSet<T>> for BoundedSet<T>
+where
+    R: Rangetools<Set = &'b LowerBoundedSet<T>>,
+    T: Element,
+{
+    type Output = LowerBoundedSet<T>;
+    fn symmetric_difference(self, other: R) -> Self::Output {
+        RangeSymmetricDifference::symmetric_difference(&self, other)
+    }
+}
+
+impl<'b, T, R> RangeSymmetricDifference<R, &'b UpperBoundedSet<T>> for BoundedSet<T>
+where
+    R: Rangetools<Set = &'b UpperBoundedSet<T>>,
+    T: Element,
+{
+    type Output = UpperBoundedSet<T>;
+    fn symmetric_difference(self, other: R) -> Self::Output {
+        RangeSymmetricDifference::symmetric_difference(&self, other)
+    }
+}
+
+impl<'b, T, R> RangeSymmetricDifference<R, &'b UnboundedSet<T>> for BoundedSet<T>
+where
+    R: Rangetools<Set = &'b UnboundedSet<T>>,
+    T: Element,
+{
+    type Output = UnboundedSet<T>;
+    fn symmetric_difference(self, other: R) -> Self::Output {
+        RangeSymmetricDifference::symmetric_difference(&self, other)
+    }
+}
+
+impl<T, R> RangeSymmetricDifference<R, BoundedSet<T>> for &BoundedSet<T>
+where
+    R: Rangetools<Set = BoundedSet<T>>,
+    T: Element,
+{
+    type Output = BoundedSet<T>;
+    fn symmetric_difference(self, other: R) -> Self::Output {
+        merge::symmetric_difference(self.into(), (&other.to_set()).into()).into()
+    }
+}
+
+impl<T, R> RangeSymmetricDifference<R, LowerBoundedSet<T>> for &BoundedSet<T>
+where
+    R: Rangetools<Set = LowerBoundedSet<T>>,
+    T: Element,
+{
+    type Output = LowerBoundedSet<T>;
+    fn symmetric_difference(self, other: R) -> Self::Output {
+        merge::symmetric_difference(self.into(), (&other.to_set()).into()).into()
+    }
+}
+
+impl<T, R> RangeSymmetricDifference<R, UpperBoundedSet<T>> for &BoundedSet<T>
+where
+    R: Rangetools<Set = UpperBoundedSet<T>>,
+    T: Element,
+{
+    type Output = UpperBoundedSet<T>;
+    fn symmetric_difference(self, other: R) -> Self::Output {
+        merge::symmetric_difference(self.into(), (&other.to_set()).into()).into()
+    }
+}
+
+impl<T, R> RangeSymmetricDifference<R, UnboundedSet<T>> for &BoundedSet<T>
+where
+    R: Rangetools<Set = UnboundedSet<T>>,
+    T: Element,
+{
+    type Output = UnboundedSet<T>;
+    fn symmetric_difference(self, other: R) -> Self::Output {
+        merge::symmetric_difference(self.into(), (&other.to_set()).into()).into()
+    }
+}
+
+impl<T, R> RangeSymmetricDifference<R, UnboundedRange> for &BoundedSet<T>
+where
+    R: Rangetools<Set = UnboundedRange>,
+    T: Element,
+{
+    type Output = UnboundedSet<T>;
+    fn symmetric_difference(self, other: R) -> Self::Output {
+        merge::symmetric_difference(self.into(), (&other.to_set()).into()).into()
+    }
+}
+
+impl<T, R> RangeSymmetricDifference<R, EmptyRange<T>> for &BoundedSet<T>
+where
+    R: Rangetools<Set = EmptyRange<T>>,
+    T: Element,
+{
+    type Output = BoundedSet<T>;
+    fn symmetric_difference(self, other: R) -> Self::Output {
+        merge::symmetric_difference(self.into(), (&other.to_set()).into()).into()
+    }
+}
+
+impl<T, R> RangeSymmetricDifference<R, AnySet<T>> for &BoundedSet<T>
+where
+    R: Rangetools<Set = AnySet<T>>,
+    T: Element,
+{
+    type Output = AnySet<T>;
+    fn symmetric_difference(self, other: R) -> Self::Output {
+        merge::symmetric_difference(self.into(), (&other.to_set()).into()).into()
+    }
+}
+
+impl<'b, T, R> RangeSymmetricDifference<R, &'b BoundedSet<T>> for &BoundedSet<T>
+where
+    R: Rangetools<Set = &'b BoundedSet<T>>,
+    T: Element,
+{
+    type Output = BoundedSet<T>;
+    fn symmetric_difference(self, other: R) -> Self::Output {
+        merge::symmetric_difference(self.into(), other.to_set().into()).into()
+    }
+}
+
+impl<'b, T, R> RangeSymmetricDifference<R, &'b LowerBoundedSet<T>> for &BoundedSet<T>
+where
+    R: Rangetools<Set = &'b LowerBoundedSet<T>>,
+    T: Element,
+{
+    type Output = LowerBoundedSet<T>;
+    fn symmetric_difference(self, other: R) -> Self::Output {
+        merge::symmetric_difference(self.into(), other.to_set().into()).into()
+    }
+}
+
+impl<'b, T, R> RangeSymmetricDifference<R, &'b UpperBoundedSet<T>> for &BoundedSet<T>
+where
+    R: Rangetools<Set = &'b UpperBoundedSet<T>>,
+    T: Element,
+{
+    type Output = UpperBoundedSet<T>;
+    fn symmetric_difference(self, other: R) -> Self::Output {
+        merge::symmetric_difference(self.into(), other.to_set().into()).into()
+    }
+}
+
+impl<'b, T, R> RangeSymmetricDifference<R, &'b UnboundedSet<T>> for &BoundedSet<T>
+where
+    R: Rangetools<Set = &'b UnboundedSet<T>>,
+    T: Element,
+{
+    type Output = UnboundedSet<T>;
+    fn symmetric_difference(self, other: R) -> Self::Output {
+        merge::symmetric_difference(self.into(), other.to_set().into()).into()
+    }
+}
@@ -0,0 +1,11 @@
+use crate::{EmptyRange, RangeSymmetricDifference, Rangetools};
+
+impl<T, Rhs, RhsSet> RangeSymmetricDifference<Rhs, RhsSet> for EmptyRange<T>
+where
+    Rhs: Rangetools<Set = RhsSet>,
+{
+    type Output = Rhs;
+    fn symmetric_difference(self, other: Rhs) -> Self::Output {
+        other
+    }
+}
@@ -0,0 +1,126 @@
+use crate::{
+    AnySet, BoundedSet, Element, EmptyRange, LowerBoundedRange, LowerBoundedSet, RangeComplement,
+    RangeSymmetricDifference, Rangetools, UnboundedRange, UnboundedSet, UpperBoundedRange,
+    UpperBoundedSet,
+};
+
+impl<T, R> RangeSymmetricDifference<R, BoundedSet<T>> for LowerBoundedRange<T>
+where
+    R: Rangetools<Set = BoundedSet<T>>,
+    T: Element,
+{
+    type Output = LowerBoundedSet<T>;
+    fn symmetric_difference(self, other: R) -> Self::Output {
+        RangeSymmetricDifference::symmetric_difference(self.to_set(), other)
+    }
+}
+
+impl<T, R> RangeSymmetricDifference<R, LowerBoundedSet<T>> for LowerBoundedRange<T>
+where
+    R: Rangetools<Set = LowerBoundedSet<T>>,
+    T: Element,
+{
+    type Output = BoundedSet<T>;
+    fn symmetric_difference(self, other: R) -> Self::Output {
+        RangeSymmetricDifference::symmetric_difference(self.to_set(), other)
+    }
+}
+
+impl<T, R> RangeSymmetricDifference<R, UpperBoundedSet<T>> for LowerBoundedRange<T>
+where
+    R: Rangetools<Set = UpperBoundedSet<T>>,
+    T: Element,
+{
+    type Output = UnboundedSet<T>;
+    fn symmetric_difference(self, other: R) -> Self::Output {
+        RangeSymmetricDifference::symmetric_difference(self.to_set(), other)
+    }
+}
+
+impl<T, R> RangeSymmetricDifference<R, UnboundedSet<T>> for LowerBoundedRange<T>
+where
+    R: Rangetools<Set = UnboundedSet<T>>,
+    T: Element,
+{
+    type Output = UpperBoundedSet<T>;
+    fn symmetric_difference(self, other: R) -> Self::Output {
+        RangeSymmetricDifference::symmetric_difference(self.to_set(), other)
+    }
+}
+
+impl<T, R> RangeSymmetricDifference<R, UnboundedRange> for LowerBoundedRange<T>
+where
+    R: Rangetools<Set = UnboundedRange>,
+    T: Element,
+{
+    type Output = UpperBoundedRange<T>;
+    fn symmetric_difference(self, _: R) -> Self::Output {
+        RangeComplement::complement(self)
+    }
+}
+
+impl<T, R> RangeSymmetricDifference<R, EmptyRange<T>> for LowerBoundedRange<T>
+where
+    R: Rangetools<Set = EmptyRange<T>>,
+    T: Element,
+{
+    type Output = LowerBoundedRange<T>;
+    fn symmetric_difference(self, other: R) -> Self::Output {
+        RangeSymmetricDifference::symmetric_difference(other.to_set(), self)
+    }
+}
+
+impl<T, R> RangeSymmetricDifference<R, AnySet<T>> for LowerBoundedRange<T>
+where
+    R: Rangetools<Set = AnySet<T>>,
+    T: Element,
+{
+    type Output = AnySet<T>;
+    fn symmetric_difference(self, other: R) -> Self::Output {
+        RangeSymmetricDifference::symmetric_difference(other.to_set(), self)
+    }
+}
+
+impl<'b, T, R> RangeSymmetricDifference<R, &'b BoundedSet<T>> for LowerBoundedRange<T>
+where
+    R: Rangetools<Set = &'b BoundedSet<T>>,
+    T: Element,
+{
+    type Output = LowerBoundedSet<T>;
+    fn symmetric_difference(self, other: R) -> Self::Output {
+        RangeSymmetricDifference::symmetric_difference(self.to_set(), other)
+    }
+}
+
+impl<'b, T, R> RangeSymmetricDifference<R, &'b LowerBoundedSet<T>> for LowerBoundedRange<T>
+where
+    R: Rangetools<Set = &'b LowerBoundedSet<T>>,
+    T: Element,
+{
+    type Output = BoundedSet<T>;
+    fn symmetric_difference(self, other: R) -> Self::Output {
+        RangeSymmetricDifference::symmetric_difference(self.to_set(), other)
+    }
+}
+
+impl<'b, T, R> RangeSymmetricDifference<R, &'b UpperBoundedSet<T>> for LowerBoundedRange<T>
+where
+    R: Rangetools<Set = &'b UpperBoundedSet<T>>,
+    T: Element,
+{
+    type Output = UnboundedSet<T>;
+    fn symmetric_difference(self, other: R) -> Self::Output {
+        RangeSymmetricDifference::symmetric_difference(self.to_set(), other)
+    }
+}
+
+impl<'b, T, R> RangeSymmetricDifference<R, &'b UnboundedSet<T>> for LowerBoundedRange<T>
+where
+    R: Rangetools<Set = &'b UnboundedSet<T>>,
+    T: Element,
+{
+    type Output = UpperBoundedSet<T>;
+    fn symmetric_difference(self, other: R) -> Self::Output {
+        RangeSymmetricDifference::symmetric_difference(self.to_set(), other)
+    }
+}
@@ -0,0 +1,42 @@
+use crate::{
+    AnySet, BoundedRange, BoundedSet, EmptyRange, LowerBoundedRange, LowerBoundedSet, RangeUnion,
+    Rangetools, UnboundedRange, UnboundedSet, UpperBoundedRange, UpperBoundedSet,
+};
+
+impl<T, R, RhsSet> RangeUnion<R, RhsSet> for AnySet<T>
+where
+    R: Rangetools<Set = RhsSet>,
+    EmptyRange<T>: RangeUnion<R, RhsSet>,
+    BoundedRange<T>: RangeUnion<R, RhsSet>,
+    BoundedSet<T>: RangeUnion<R, RhsSet>,
+    LowerBoundedRange<T>: RangeUnion<R, RhsSet>,
+    LowerBoundedSet<T>: RangeUnion<R, RhsSet>,
+    UpperBoundedRange<T>: RangeUnion<R, RhsSet>,
+    UpperBoundedSet<T>: RangeUnion<R, RhsSet>,
+    UnboundedRange: RangeUnion<R, RhsSet>,
+    UnboundedSet<T>: RangeUnion<R, RhsSet>,
+    AnySet<T>: From<<EmptyRange<T> as RangeUnion<R, RhsSet>>::Output>,
+    AnySet<T>: From<<BoundedRange<T> as RangeUnion<R, RhsSet>>::Output>,
+    AnySet<T>: From<<BoundedSet<T> as RangeUnion<R, RhsSet>>::Output>,
+    AnySet<T>: From<<LowerBoundedRange<T> as RangeUnion<R, RhsSet>>::Output>,
+    AnySet<T>: From<<LowerBoundedSet<T> as RangeUnion<R, RhsSet>>::Output>,
+    AnySet<T>: From<<UpperBoundedRange<T> as RangeUnion<R, RhsSet>>::Output>,
+    AnySet<T>: From<<UpperBoundedSet<T> as RangeUnion<R, RhsSet>>::Output>,
+    AnySet<T>: From<<UnboundedRange as RangeUnion<R, RhsSet>>::Output>,
+    AnySet<T>: From<<UnboundedSet<T> as RangeUnion<R, RhsSet>>::Output>,
+{
+    type Output = AnySet<T>;
+    fn union(self, other: R) -> Self::Output {
+        match self {
+            Self::EmptyRange(r) => RangeUnion::union(r, other).into(),
+            Self::BoundedRange(r) => RangeUnion::union(r, other).into(),
+            Self::BoundedSet(r) => RangeUnion::union(r, other).into(),
+            Self::LowerBoundedRange(r) => RangeUnion::union(r, other).into(),
+            Self::LowerBoundedSet(r) => RangeUnion::union(r, other).into(),
+            Self::UpperBoundedRange(r) => RangeUnion::union(r, other).into(),
+            Self::UpperBoundedSet(r) => RangeUnion::union(r, other).into(),
+            Self::UnboundedRange(r) => RangeUnion::union(r, other).into(),
+            Self::UnboundedSet(r) => RangeUnion::union(r, other).into(),
+        }
+    }
+}
@@ -0,0 +1,13 @@
+use crate::{AnySet, Element, RangeUnion, Rangetools};
+
+impl<T, R, RhsSet> RangeUnion<R, RhsSet> for (std::ops::Bound<T>, std::ops::Bound<T>)
+where
+    R: Rangetools<Set = RhsSet>,
+    T: Element,
+    AnySet<T>: RangeUnion<R, RhsSet>,
+{
+    type Output = <AnySet<T> as RangeUnion<R, RhsSet>>::Output;
+    fn union(self, other: R) -> Self::Output {
+        RangeUnion::union(self.to_set(), other)
+    }
+}
@@ -1,12 +1,12 @@
 use crate::{
-    BoundedRange, BoundedSet, EmptyRange, LowerBoundedSet, RangeUnion, Rangetools, UnboundedRange,
-    UnboundedSet, UpperBoundedSet,
+    AnySet, BoundedRange, BoundedSet, Element, EmptyRange, LowerBoundedSet, RangeUnion, Rangetools,
+    UnboundedRange, UnboundedSet, UpperBoundedSet,
 };
 
 impl<T, R> RangeUnion<R, BoundedSet<T>> for BoundedRange<T>
 where
     R: Rangetools<Set = BoundedSet<T>>,
-    T: Copy + Ord,
+    T: Element,
 {
     type Output = BoundedSet<T>;
     fn union(self, other: R) -> Self::Output {
@@ -17,7 +17,7 @@ where
 impl<T, R> RangeUnion<R, LowerBoundedSet<T>> for BoundedRange<T>
 where
     R: Rangetools<Set = LowerBoundedSet<T>>,
-    T: Copy + Ord,
+    T: Element,
 {
     type Output = LowerBoundedSet<T>;
     fn union(self, other: R) -> Self::Output {
@@ -28,7 +28,7 @@ where
 impl<T, R> RangeUnion<R, UpperBoundedSet<T>> for BoundedRange<T>
 where
     R: Rangetools<Set = UpperBoundedSet<T>>,
-    T: Copy + Ord,
+    T: Element,
 {
     type Output = UpperBoundedSet<T>;
     fn union(self, other: R) -> Self::Output {
@@ -39,7 +39,7 @@ where
 impl<T, R> RangeUnion<R, UnboundedSet<T>> for BoundedRange<T>
 where
     R: Rangetools<Set = UnboundedSet<T>>,
-    T: Copy + Ord,
+    T: Element,
 {
     type Output = UnboundedSet<T>;
     fn union(self, other: R) -> Self::Output {
@@ -50,7 +50,7 @@ where
 impl<T, R> RangeUnion<R, UnboundedRange> for BoundedRange<T>
 where
     R: Rangetools<Set = UnboundedRange>,
-    T: Copy + Ord,
+    T: Element,
 {
     type Output = UnboundedRange;
     fn union(self, other: R) -> Self::Output {
@@ -61,10 +61,65 @@ where
 impl<T, R> RangeUnion<R, EmptyRange<T>> for BoundedRange<T>
 where
     R: Rangetools<Set = EmptyRange<T>>,
-    T: Copy + Ord,
+    T: Element,
 {
     type Output = BoundedRange<T>;
     fn union(self, other: R) -> Self::Output {
         RangeUnion::union(other.to_set(), self)
     }
 }
+
+impl<T, R> RangeUnion<R, AnySet<T>> for BoundedRange<T>
+where
+    R: Rangetools<Set = AnySet<T>>,
+    T: Element,
+{
+    type Output = AnySet<T>;
+    fn union(self, other: R) -> Self::Output {
+        RangeUnion::union(other.to_set(), self)
+    }
+}
+
+impl<'b, T, R> RangeUnion<R, &'b BoundedSet<T>> for BoundedRange<T>
+where
+    R: Rangetools<Set = &'b BoundedSet<T>>,
+    T: Element,
+{
+    type Output = BoundedSet<T>;
+    fn union(self, other: R) -> Self::Output {
+        RangeUnion::union(self.to_set(), other)
+    }
+}
+
+impl<'b, T, R> RangeUnion<R, &'b LowerBoundedSet<T>> for BoundedRange<T>
+where
+    R: Rangetools<Set = &'b LowerBoundedSet<T>>,
+    T: Element,
+{
+    type Output = LowerBoundedSet<T>;
+    fn union(self, other: R) -> Self::Output {
+        RangeUnion::union(self.to_set(), other)
+    }
+}
+
+impl<'b, T, R> RangeUnion<R, &'b UpperBoundedSet<T>> for BoundedRange<T>
+where
+    R: Rangetools<Set = &'b UpperBoundedSet<T>>,
+    T: Element,
+{
+    type Output = UpperBoundedSet<T>;
+    fn union(self, other: R) -> Self::Output {
+        RangeUnion::union(self.to_set(), other)
+    }
+}
+
+impl<'b, T, R> RangeUnion<R, &'b UnboundedSet<T>> for BoundedRange<T>
+where
+    R: Rangetools<Set = &'b UnboundedSet<T>>,
+    T: Element,
+{
+    type Output = UnboundedSet<T>;
+    fn union(self, other: R) -> Self::Output {
+        RangeUnion::union(self.to_set(), other)
+    }
+}
@@ -1,63 +1,56 @@
 use crate::{
-    BoundedSet, EmptyRange, LowerBoundedSet, RangeUnion, Rangetools, UnboundedRange, UnboundedSet,
-    UpperBoundedSet,
+    merge, AnySet, BoundedSet, Element, EmptyRange, LowerBoundedSet, RangeUnion, Rangetools,
+    UnboundedRange, UnboundedSet, UpperBoundedSet,
 };
 
 impl<T, R> RangeUnion<R, BoundedSet<T>> for BoundedSet<T>
 where
     R: Rangetools<Set = BoundedSet<T>>,
-    T: Copy + Ord,
+    T: Element,
 {
     type Output = BoundedSet<T>;
-    fn union(mut self, other: R) -> Self::Output {
-        self.add_set(other.to_set());
-        self
+    fn union(self, other: R) -> Self::Output {
+        RangeUnion::union(&self, &other.to_set())
     }
 }
 
 impl<T, R> RangeUnion<R, LowerBoundedSet<T>> for BoundedSet<T>
 where
     R: Rangetools<Set = LowerBoundedSet<T>>,
-    T: Copy + Ord,
+    T: Element,
 {
     type Output = LowerBoundedSet<T>;
     fn union(self, other: R) -> Self::Output {
-        let mut set = other.to_set();
-        set.add_set(self);
-        set
+        RangeUnion::union(&self, &other.to_set())
     }
 }
 
 impl<T, R> RangeUnion<R, UpperBoundedSet<T>> for BoundedSet<T>
 where
     R: Rangetools<Set = UpperBoundedSet<T>>,
-    T: Copy + Ord,
+    T: Element,
 {
     type Output = UpperBoundedSet<T>;
     fn union(self, other: R) -> Self::Output {
-        let mut set = other.to_set();
-        set.add_set(self);
-        set
+        RangeUnion::union(&self, &other.to_set())
     }
 }
 
 impl<T, R> RangeUnion<R, UnboundedSet<T>> for BoundedSet<T>
 where
     R: Rangetools<Set = UnboundedSet<T>>,
-    T: Copy + Ord,
+    T: Element,
 {
     type Output = UnboundedSet<T>;
     fn union(self, other: R) -> Self::Output {
-        let mut set = other.to_set();
-        set.add_set(self);
-        set
+        RangeUnion::union(&self, &other.to_set())
     }
 }
 
 impl<T, R> RangeUnion<R, UnboundedRange> for BoundedSet<T>
 where
     R: Rangetools<Set = UnboundedRange>,
-    T: Copy + Ord,
+    T: Element,
 {
     type Output = UnboundedRange;
     fn union(self, other: R) -> Self::Output {
@@ -68,10 +61,186 @@ where
 impl<T, R> RangeUnion<R, EmptyRange<T>> for BoundedSet<T>
 where
     R: Rangetools<Set = EmptyRange<T>>,
-    T: Copy + Ord,
+    T: Element,
 {
     type Output = BoundedSet<T>;
     fn union(self, other: R) -> Self::Output {
         RangeUnion::union(other.to_set(), self)
     }
 }
+
+impl<T, R> RangeUnion<R, AnySet<T>> for BoundedSet<T>
+where
+    R: Rangetools<Set = AnySet<T>>,
+    T: Element,
+{
+    type Output = AnySet<T>;
+    fn union(self, other: R) -> Self::Output {
+        RangeUnion::union(other.to_set(), self)
+    }
+}
+
+impl<'b, T, R> RangeUnion<R, &'b BoundedSet<T>> for BoundedSet<T>
+where
+    R: Rangetools<Set = &'b BoundedSet<T>>,
+    T: Element,
+{
+    type Output = BoundedSet<T>;
+    fn union(self, other: R) -> Self::Output {
+        RangeUnion::union(&self, other)
+    }
+}
+
+impl<'b, T, R> RangeUnion<R, &'b LowerBoundedSet<T>> for BoundedSet<T>
+where
+    R: Rangetools<Set = &'b LowerBoundedSet<T>>,
+    T: Element,
+{
+    type Output = LowerBoundedSet<T>;
+    fn union(self, other: R) -> Self::Output {
+        RangeUnion::union(&self, other)
+    }
+}
+
+impl<'b, T, R> RangeUnion<R, &'b UpperBoundedSet<T>> for BoundedSet<T>
+where
+    R: Rangetools<Set = &'b UpperBoundedSet<T>>,
+    T: Element,
+{
+    type Output = UpperBoundedSet<T>;
+    fn union(self, other: R) -> Self::Output {
+        RangeUnion::union(&self, other)
+    }
+}
+
+impl<'b, T, R> RangeUnion<R, &'b UnboundedSet<T>> for BoundedSet<T>
+where
+    R: Rangetools<Set = &'b UnboundedSet<T>>,
+    T: Element,
+{
+    type Output = UnboundedSet<T>;
+    fn union(self, other: R) -> Self::Output {
+        RangeUnion::union(&self, other)
+    }
+}
+
+impl<T, R> RangeUnion<R, BoundedSet<T>> for &BoundedSet<T>
+where
+    R: Rangetools<Set = BoundedSet<T>>,
+    T: Element,
+{
+    type Output = BoundedSet<T>;
+    fn union(self, other: R) -> Self::Output {
+        merge::union(self.into(), (&other.to_set()).into()).into()
+    }
+}
+
+impl<T, R> RangeUnion<R, LowerBoundedSet<T>> for &BoundedSet<T>
+where
+    R: Rangetools<Set = LowerBoundedSet<T>>,
+    T: Element,
+{
+    type Output = LowerBoundedSet<T>;
+    fn union(self, other: R) -> Self::Output {
+        merge::union(self.into(), (&other.to_set()).into()).into()
+    }
+}
+
+impl<T, R> RangeUnion<R, UpperBoundedSet<T>> for &BoundedSet<T>
+where
+    R: Rangetools<Set = UpperBoundedSet<T>>,
+    T: Element,
+{
+    type Output = UpperBoundedSet<T>;
+    fn union(self, other: R) -> Self::Output {
+        merge::union(self.into(), (&other.to_set()).into()).into()
+    }
+}
+
+impl<T, R> RangeUnion<R, UnboundedSet<T>> for &BoundedSet<T>
+where
+    R: Rangetools<Set = UnboundedSet<T>>,
+    T: Element,
+{
+    type Output = UnboundedSet<T>;
+    fn union(self, other: R) -> Self::Output {
+        merge::union(self.into(), (&other.to_set()).into()).into()
+    }
+}
+
+impl<T, R> RangeUnion<R, UnboundedRange> for &BoundedSet<T>
+where
+    R: Rangetools<Set = UnboundedRange>,
+    T: Element,
+{
+    type Output = UnboundedRange;
+    fn union(self, other: R) -> Self::Output {
+        other.to_set()
+    }
+}
+
+impl<T, R> RangeUnion<R, EmptyRange<T>> for &BoundedSet<T>
+where
+    R: Rangetools<Set = EmptyRange<T>>,
+    T: Element,
+{
+    type Output = BoundedSet<T>;
+    fn union(self, other: R) -> Self::Output {
+        merge::union(self.into(), (&other.to_set()).into()).into()
+    }
+}
+
+impl<T, R> RangeUnion<R, AnySet<T>> for &BoundedSet<T>
+where
+    R: Rangetools<Set = AnySet<T>>,
+    T: Element,
+{
+    type Output = AnySet<T>;
+    fn union(self, other: R) -> Self::Output {
+        merge::union(self.into(), (&other.to_set()).into()).into()
+    }
+}
+
+impl<'b, T, R> RangeUnion<R, &'b BoundedSet<T>> for &BoundedSet<T>
+where
+    R: Rangetools<Set = &'b BoundedSet<T>>,
+    T: Element,
+{
+    type Output = BoundedSet<T>;
+    fn union(self, other: R) -> Self::Output {
+        merge::union(self.into(), other.to_set().into()).into()
+    }
+}
+
+impl<'b, T, R> RangeUnion<R, &'b LowerBoundedSet<T>> for &BoundedSet<T>
+where
+    R: Rangetools<Set = &'b LowerBoundedSet<T>>,
+    T: Element,
+{
+    type Output = LowerBoundedSet<T>;
+    fn union(self, other: R) -> Self::Output {
+        merge::union(self.into(), other.to_set().into()).into()
+    }
+}
+
+impl<'b, T, R> RangeUnion<R, &'b UpperBoundedSet<T>> for &BoundedSet<T>
+where
+    R: Rangetools<Set = &'b UpperBoundedSet<T>>,
+    T: Element,
+{
+    type Output = UpperBoundedSet<T>;
+    fn union(self, other: R) -> Self::Output {
+        merge::union(self.into(), other.to_set().into()).into()
+    }
+}
+
+impl<'b, T, R> RangeUnion<R, &'b UnboundedSet<T>> for &BoundedSet<T>
+where
+    R: Rangetools<Set = &'b UnboundedSet<T>>,
+    T: Element,
+{
+    type Output = UnboundedSet<T>;
+    fn union(self, other: R) -> Self::Output {
+        merge::union(self.into(), other.to_set().into()).into()
+    }
+}
@@ -1,62 +1,56 @@
 use crate::{
-    BoundedSet, EmptyRange, LowerBoundedSet, RangeUnion, Rangetools, UnboundedRange, UnboundedSet,
-    UpperBoundedSet,
+    merge, AnySet, BoundedSet, Element, EmptyRange, LowerBoundedSet, RangeUnion, Rangetools,
+    UnboundedRange, UnboundedSet, UpperBoundedSet,
 };
 
 impl<T, R> RangeUnion<R, BoundedSet<T>> for LowerBoundedSet<T>
 where
     R: Rangetools<Set = BoundedSet<T>>,
-    T: Copy + Ord,
+    T: Element,
 {
     type Output = LowerBoundedSet<T>;
     fn union(self, other: R) -> Self::Output {
-        RangeUnion::union(other.to_set(), self)
+        RangeUnion::union(&self, &other.to_set())
     }
 }
 
 impl<T, R> RangeUnion<R, LowerBoundedSet<T>> for LowerBoundedSet<T>
 where
     R: Rangetools<Set = LowerBoundedSet<T>>,
-    T: Copy + Ord,
+    T: Element,
 {
     type Output = LowerBoundedSet<T>;
-    fn union(mut self, other: R) -> Self::Output {
-        let other = other.to_set();
-        self.add_lower_bounded_range(other.lower_bounded_range);
-        RangeUnion::union(self, other.ranges)
+    fn union(self, other: R) -> Self::Output {
+        RangeUnion::union(&self, &other.to_set())
     }
 }
 
 impl<T, R> RangeUnion<R, UpperBoundedSet<T>> for LowerBoundedSet<T>
 where
     R: Rangetools<Set = UpperBoundedSet<T>>,
-    T: Copy + Ord,
+    T: Element,
 {
     type Output = UnboundedSet<T>;
     fn union(self, other: R) -> Self::Output {
-        let other = other.to_set();
-        let set = UnboundedSet::new(other.upper_bounded_range, self.lower_bounded_range);
-        RangeUnion::union(RangeUnion::union(set, self.ranges), other.ranges)
+        RangeUnion::union(&self, &other.to_set())
     }
 }
 
 impl<T, R> RangeUnion<R, UnboundedSet<T>> for LowerBoundedSet<T>
 where
     R: Rangetools<Set = UnboundedSet<T>>,
-    T: Copy + Ord,
+    T: Element,
 {
     type Output = UnboundedSet<T>;
     fn union(self, other: R) -> Self::Output {
-        let mut set = other.to_set();
-        set.add_lower_bounded_range(self.lower_bounded_range);
-        RangeUnion::union(set, self.ranges)
+        RangeUnion::union(&self, &other.to_set())
     }
 }
 
 impl<T, R> RangeUnion<R, UnboundedRange> for LowerBoundedSet<T>
 where
     R: Rangetools<Set = UnboundedRange>,
-    T: Copy + Ord,
+    T: Element,
 {
     type Output = UnboundedRange;
     fn union(self, other: R) -> Self::Output {
@@ -67,10 +61,186 @@ where
 impl<T, R> RangeUnion<R, EmptyRange<T>> for LowerBoundedSet<T>
 where
     R: Rangetools<Set = EmptyRange<T>>,
-    T: Copy + Ord,
+    T: Element,
 {
     type Output = LowerBoundedSet<T>;
     fn union(self, other: R) -> Self::Output {
         RangeUnion::union(other.to_set(), self)
     }
 }
+
+impl<T, R> RangeUnion<R, AnySet<T>> for LowerBoundedSet<T>
+where
+    R: Rangetools<Set = AnySet<T>>,
+    T: Element,
+{
+    type Output = AnySet<T>;
+    fn union(self, other: R) -> Self::Output {
+        RangeUnion::union(other.to_set(), self)
+    }
+}
+
+impl<'b, T, R> RangeUnion<R, &'b BoundedSet<T>> for LowerBoundedSet<T>
+where
+    R: Rangetools<Set = &'b BoundedSet<T>>,
+    T: Element,
+{
+    type Output = LowerBoundedSet<T>;
+    fn union(self, other: R) -> Self::Output {
+        RangeUnion::union(&self, other)
+    }
+}
+
+impl<'b, T, R> RangeUnion<R, &'b LowerBoundedSet<T>> for LowerBoundedSet<T>
+where
+    R: Rangetools<Set = &'b LowerBoundedSet<T>>,
+    T: Element,
+{
+    type Output = LowerBoundedSet<T>;
+    fn union(self, other: R) -> Self::Output {
+        RangeUnion::union(&self, other)
+    }
+}
+
+impl<'b, T, R> RangeUnion<R, &'b UpperBoundedSet<T>> for LowerBoundedSet<T>
+where
+    R: Rangetools<Set = &'b UpperBoundedSet<T>>,
+    T: Element,
+{
+    type Output = UnboundedSet<T>;
+    fn union(self, other: R) -> Self::Output {
+        RangeUnion::union(&self, other)
+    }
+}
+
+impl<'b, T, R> RangeUnion<R, &'b UnboundedSet<T>> for LowerBoundedSet<T>
+where
+    R: Rangetools<Set = &'b UnboundedSet<T>>,
+    T: Element,
+{
+    type Output = UnboundedSet<T>;
+    fn union(self, other: R) -> Self::Output {
+        RangeUnion::union(&self, other)
+    }
+}
+
+impl<T, R> RangeUnion<R, BoundedSet<T>> for &LowerBoundedSet<T>
+where
+    R: Rangetools<Set = BoundedSet<T>>,
+    T: Element,
+{
+    type Output = LowerBoundedSet<T>;
+    fn union(self, other: R) -> Self::Output {
+        merge::union(self.into(), (&other.to_set()).into()).into()
+    }
+}
+
+impl<T, R> RangeUnion<R, LowerBoundedSet<T>> for &LowerBoundedSet<T>
+where
+    R: Rangetools<Set = LowerBoundedSet<T>>,
+    T: Element,
+{
+    type Output = LowerBoundedSet<T>;
+    fn union(self, other: R) -> Self::Output {
+        merge::union(self.into(), (&other.to_set()).into()).into()
+    }
+}
+
+impl<T, R> RangeUnion<R, UpperBoundedSet<T>> for &LowerBoundedSet<T>
+where
+    R: Rangetools<Set = UpperBoundedSet<T>>,
+    T: Element,
+{
+    type Output = UnboundedSet<T>;
+    fn union(self, other: R) -> Self::Output {
+        merge::union(self.into(), (&other.to_set()).into()).into()
+    }
+}
+
+impl<T, R> RangeUnion<R, UnboundedSet<T>> for &LowerBoundedSet<T>
+where
+    R: Rangetools<Set = UnboundedSet<T>>,
+    T: Element,
+{
+    type Output = UnboundedSet<T>;
+    fn union(self, other: R) -> Self::Output {
+        merge::union(self.into(), (&other.to_set()).into()).into()
+    }
+}
+
+impl<T, R> RangeUnion<R, UnboundedRange> for &LowerBoundedSet<T>
+where
+    R: Rangetools<Set = UnboundedRange>,
+    T: Element,
+{
+    type Output = UnboundedRange;
+    fn union(self, other: R) -> Self::Output {
+        other.to_set()
+    }
+}
+
+impl<T, R> RangeUnion<R, EmptyRange<T>> for &LowerBoundedSet<T>
+where
+    R: Rangetools<Set = EmptyRange<T>>,
+    T: Element,
+{
+    type Output = LowerBoundedSet<T>;
+    fn union(self, other: R) -> Self::Output {
+        merge::union(self.into(), (&other.to_set()).into()).into()
+    }
+}
+
+impl<T, R> RangeUnion<R, AnySet<T>> for &LowerBoundedSet<T>
+where
+    R: Rangetools<Set = AnySet<T>>,
+    T: Element,
+{
+    type Output = AnySet<T>;
+    fn union(self, other: R) -> Self::Output {
+        merge::union(self.into(), (&other.to_set()).into()).into()
+    }
+}
+
+impl<'b, T, R> RangeUnion<R, &'b BoundedSet<T>> for &LowerBoundedSet<T>
+where
+    R: Rangetools<Set = &'b BoundedSet<T>>,
+    T: Element,
+{
+    type Output = LowerBoundedSet<T>;
+    fn union(self, other: R) -> Self::Output {
+        merge::union(self.into(), other.to_set().into()).into()
+    }
+}
+
+impl<'b, T, R> RangeUnion<R, &'b LowerBoundedSet<T>> for &LowerBoundedSet<T>
+where
+    R: Rangetools<Set = &'b LowerBoundedSet<T>>,
+    T: Element,
+{
+    type Output = LowerBoundedSet<T>;
+    fn union(self, other: R) -> Self::Output {
+        merge::union(self.into(), other.to_set().into()).into()
+    }
+}
+
+impl<'b, T, R> RangeUnion<R, &'b UpperBoundedSet<T>> for &LowerBoundedSet<T>
+where
+    R: Rangetools<Set = &'b UpperBoundedSet<T>>,
+    T: Element,
+{
+    type Output = UnboundedSet<T>;
+    fn union(self, other: R) -> Self::Output {
+        merge::union(self.into(), other.to_set().into()).into()
+    }
+}
+
+impl<'b, T, R> RangeUnion<R, &'b UnboundedSet<T>> for &LowerBoundedSet<T>
+where
+    R: Rangetools<Set = &'b UnboundedSet<T>>,
+    T: Element,
+{
+    type Output = UnboundedSet<T>;
+    fn union(self, other: R) -> Self::Output {
+        merge::union(self.into(), other.to_set().into()).into()
+    }
+}
@@ -1,4 +1,4 @@
-use crate::Rangetools;
+use crate::{merge, AnySet, Component, Element, RangeComplement, RangeQuery, Rangetools};
 
 /// Helper trait for performing range intersection.
 ///
@@ -12,3 +12,64 @@ pub trait RangeIntersection<Rhs, RhsInner> {
     where
         Rhs: Rangetools<Inner = RhsInner>;
 }
+
+/// Returns the intersection of all of the ranges or sets in `ranges`, or `None` if there are
+/// none.
+///
+/// The output is the tightest type able to hold the intersection of any number of items, ie
+/// the type of [`.to_inner()`](crate::Rangetools::to_inner) on an item. The items are combined
+/// in balanced pairs, so each component takes part in `O(log n)` merges. To combine items of
+/// different types, use [`intersection_all_any`].
+///
+/// # Example
+/// ```
+/// use rangetools::{intersection_all, BoundedRange};
+///
+/// let r = intersection_all(vec![0..10, 3..12, 2..8]).unwrap();
+/// assert_eq!(r, BoundedRange::from(3..8));
+///
+/// assert_eq!(intersection_all(Vec::<std::ops::Range<i32>>::new()), None);
+/// ```
+pub fn intersection_all<I, R>(ranges: I) -> Option<R::Inner>
+where
+    I: IntoIterator<Item = R>,
+    R: Rangetools,
+    R::Inner:
+        Rangetools<Inner = R::Inner> + RangeIntersection<R::Inner, R::Inner, Output = R::Inner>,
+{
+    merge::reduce_balanced(
+        ranges.into_iter().map(Rangetools::to_inner),
+        RangeIntersection::intersection,
+    )
+}
+
+/// Returns the intersection of all of the ranges or sets in `ranges`, which can be of different
+/// types by passing them as [`AnySet`]s.
+///
+/// Computed as the complement of the union of the complements, so like
+/// [`union_all_any`](crate::union_all_any) this takes `O(n log n)` time in the total number of
+/// components. The intersection of no items is the full set.
+///
+/// # Example
+/// ```
+/// use rangetools::{intersection_all_any, AnySet, Rangetools, UnboundedSet};
+///
+/// let s = intersection_all_any([AnySet::from(0..), (..5).union(8..).into(), (..10).into()]);
+/// assert_eq!(s, AnySet::from((0..5).union(8..10)));
+///
+/// assert_eq!(intersection_all_any(Vec::<AnySet<i32>>::new()), UnboundedSet::Full.into());
+/// ```
+pub fn intersection_all_any<T, I>(ranges: I) -> AnySet<T>
+where
+    T: Element,
+    I: IntoIterator,
+    I::Item: Into<AnySet<T>>,
+{
+    let mut pieces = Vec::new();
+    for r in ranges {
+        let s: AnySet<T> = r.into();
+        let complement = RangeComplement::complement(s);
+        pieces.extend(RangeQuery::components(&complement).map(Component::into_bounds));
+    }
+    RangeComplement::complement(AnySet::from(merge::union_all(pieces)))
+}
@@ -49,21 +49,65 @@
 //!                        // and thus cannot be iterated over.
 //! ```
 //!
+//! # Operators
+//!
+//! The range and set types introduced in this crate also overload the bitwise operators as
+//! shorthand for the [`Rangetools`] methods: `&` for
+//! [`intersection`](Rangetools::intersection), `|` for [`union`](Rangetools::union), `-` for
+//! [`difference`](Rangetools::difference), `^` for
+//! [`symmetric_difference`](Rangetools::symmetric_difference) and `!` for
+//! [`complement`](Rangetools::complement). The standard library range types can be used on the
+//! right-hand side of the binary operators.
+//!
+//! ```
+//! use rangetools::Rangetools;
+//!
+//! let r = (0..10).to_inner();
+//! let s = (r - (3..5)) | (20..23);
+//! assert_eq!(s.into_iter().collect::<Vec<_>>(), vec![0, 1, 2, 5, 6, 7, 8, 9, 20, 21, 22]);
+//! assert!((!r).contains(10));
+//! ```
+//!
+//! The `&`, `|` and `!` operators (and the corresponding [`Rangetools`] methods) also work on
+//! references to the set types, reading the operands in place rather than consuming them.
+//!
+//! ```
+//! use rangetools::Rangetools;
+//!
+//! let a = (0..5).union(10..20);
+//! let b = (3..12).union(15..);
+//! assert_eq!(&a & &b, (3..5).union(10..12).union(15..20));
+//! assert_eq!((&a).union(&b), (0..).to_set());
+//! assert_eq!(!&b, (..3).union(12..15));
+//! ```
+//!
 //! # Crate features
 //!
 //! **serde** - When enabled, derives [`serde`]'s [Serialize](serde::Serialize) and [Deserialize](serde::Deserialize) traits for
 //! all of the types introduced in this crate.
 
+mod any_set;
 mod bound;
 mod bounded_range;
 mod bounded_set;
 mod complement;
+mod component;
+mod difference;
+mod domain;
+mod element;
 mod empty_range;
+mod error;
+mod float;
 mod implementations;
 mod intersection;
 mod lower_bounded_range;
 mod lower_bounded_set;
+mod measure;
+mod merge;
+mod order_statistics;
+mod query;
 mod step;
+mod symmetric_difference;
 #[cfg(test)]
 mod test;
 mod unbounded_range;
@@ -73,9 +117,11 @@ mod upper_bounded_range;
 mod upper_bounded_set;
 
 pub use self::{
-    bound::*, bounded_range::*, bounded_set::*, complement::*, empty_range::*, intersection::*,
-    lower_bounded_range::*, lower_bounded_set::*, step::*, unbounded_range::*, unbounded_set::*,
-    union::*, upper_bounded_range::*, upper_bounded_set::*,
+    any_set::*, bound::*, bounded_range::*, bounded_set::*, complement::*, component::*,
+    difference::*, domain::*, element::*, empty_range::*, error::*, float::*, intersection::*,
+    lower_bounded_range::*, lower_bounded_set::*, measure::*, order_statistics::*, query::*,
+    step::*, symmetric_difference::*, unbounded_range::*, unbounded_set::*, union::*,
+    upper_bounded_range::*, upper_bounded_set::*,
 };
 
 /// Extends the standard library Range types with extra functionality.
@@ -85,12 +131,18 @@ pub use self::{
 pub trait Rangetools {
     /// Returns true if the range is empty.
     ///
+    /// A bounded range or set is empty when it doesn't contain any elements once its bounds are
+    /// in the canonical form given by [`Element`], so for `Step` types a range between two
+    /// adjacent values with both bounds excluded is empty. Ranges and sets with no upper or no
+    /// lower bound are never empty.
+    ///
     /// # Example
     /// ```
-    /// use rangetools::Rangetools;
+    /// use rangetools::{BoundedRange, LowerBound, Rangetools, UpperBound};
     ///
     /// assert!((3..2).is_empty());
     /// assert!(!(0..5).is_empty());
+    /// assert!(BoundedRange::new(LowerBound::excluded(3), UpperBound::excluded(4)).is_empty());
     /// ```
     fn is_empty(&self) -> bool;
 
@@ -184,6 +236,100 @@ pub trait Rangetools {
         !self.disjoint(other)
     }
 
+    /// Performs set difference on `self` and `other`.
+    ///
+    /// Returns a set/range containing all values contained in `self` but not in `other`.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::Rangetools;
+    ///
+    /// let d = (0..10).difference(3..5);
+    /// assert_eq!(d.into_iter().collect::<Vec<_>>(), vec![0, 1, 2, 5, 6, 7, 8, 9]);
+    ///
+    /// let d2 = (0..).difference(..5);
+    /// assert!(!d2.contains(4));
+    /// assert!(d2.contains(5));
+    /// ```
+    fn difference<R, Output>(self, other: R) -> Output
+    where
+        R: Rangetools,
+        Self: Sized + RangeDifference<R, R::Inner, Output = Output>,
+    {
+        RangeDifference::difference(self, other)
+    }
+
+    /// Returns true if every element of `self` is also contained in `other`.
+    ///
+    /// Like `==` on the set types, this compares elements according to [`Element`], so for
+    /// [`Step`] types a range with no elements outside `other` is a subset of it, whatever its
+    /// bounds.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::Rangetools;
+    ///
+    /// assert!((2..4).is_subset((0..).union(..-5)));
+    /// assert!((2..4).is_subset(2..4));
+    /// assert!(!(2..4).is_subset(3..));
+    /// assert!((0..3).is_subset(0..=2));
+    /// ```
+    #[allow(clippy::wrong_self_convention)]
+    fn is_subset<R, Output, T>(self, other: R) -> bool
+    where
+        R: Rangetools,
+        Output: RangeQuery<T>,
+        T: Element,
+        Self: Sized + RangeDifference<R, R::Inner, Output = Output>,
+    {
+        !Rangetools::difference(self, other)
+            .components()
+            .any(element::has_elements)
+    }
+
+    /// Returns true if every element of `other` is also contained in `self`.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::Rangetools;
+    ///
+    /// assert!((..).is_superset(3..5));
+    /// assert!((0..10).union(20..).is_superset(25..30));
+    /// assert!(!(0..10).is_superset(5..15));
+    /// ```
+    #[allow(clippy::wrong_self_convention)]
+    fn is_superset<R, Output, T>(self, other: R) -> bool
+    where
+        R: Rangetools + RangeDifference<Self, Self::Inner, Output = Output>,
+        Output: RangeQuery<T>,
+        T: Element,
+        Self: Sized,
+    {
+        Rangetools::is_subset(other, self)
+    }
+
+    /// Returns true if `self` is a subset of `other` and `other` contains at least one
+    /// element not in `self`.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::Rangetools;
+    ///
+    /// assert!((2..4).is_proper_subset(0..10));
+    /// assert!(!(2..4).is_proper_subset(2..4));
+    /// ```
+    #[allow(clippy::wrong_self_convention)]
+    fn is_proper_subset<R, Output, ReverseOutput, T>(self, other: R) -> bool
+    where
+        R: Rangetools + Clone + RangeDifference<Self, Self::Inner, Output = ReverseOutput>,
+        Output: RangeQuery<T>,
+        ReverseOutput: RangeQuery<T>,
+        T: Element,
+        Self: Sized + Clone + RangeDifference<R, R::Inner, Output = Output>,
+    {
+        Rangetools::is_subset(self.clone(), other.clone()) && !Rangetools::is_subset(other, self)
+    }
+
     /// The set type of the range, for talking about non-contiguous collections of
     /// elements.
     ///
@@ -220,4 +366,57 @@ pub trait Rangetools {
     {
         RangeUnion::union(self, other)
     }
+
+    /// Performs set symmetric difference on `self` and `other`.
+    ///
+    /// Returns a set/range containing all values contained in exactly one of the two sets/ranges.
+    /// The output is the union of the two differences (`self - other` and `other - self`), and
+    /// its type is determined by the same rules as [`.union()`](Rangetools::union) applied to
+    /// those differences.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::Rangetools;
+    ///
+    /// let s = (0..5).symmetric_difference(3..8);
+    /// assert_eq!(s.into_iter().collect::<Vec<_>>(), vec![0, 1, 2, 5, 6, 7]);
+    ///
+    /// let s2 = (..5).symmetric_difference(3..);
+    /// assert!(s2.contains(2));
+    /// assert!(!s2.contains(4));
+    /// assert!(s2.contains(5));
+    /// ```
+    fn symmetric_difference<R, Output>(self, other: R) -> Output
+    where
+        R: Rangetools,
+        Self: Sized + RangeSymmetricDifference<R, R::Set, Output = Output>,
+    {
+        RangeSymmetricDifference::symmetric_difference(self, other)
+    }
+
+    /// Returns true if `self` and `other` contain exactly the same elements.
+    ///
+    /// Like `==` on the set types, this compares elements according to [`Element`], but works
+    /// across any pair of types.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::Rangetools;
+    ///
+    /// assert!((0..5).union(3..10).set_eq(0..10));
+    /// assert!((..5).union(3..).set_eq(..));
+    /// assert!(!(0..5).set_eq(0..6));
+    /// assert!((0..3).set_eq(0..=2));
+    /// ```
+    fn set_eq<R, Output, T>(self, other: R) -> bool
+    where
+        R: Rangetools,
+        Output: RangeQuery<T>,
+        T: Element,
+        Self: Sized + RangeSymmetricDifference<R, R::Set, Output = Output>,
+    {
+        !Rangetools::symmetric_difference(self, other)
+            .components()
+            .any(element::has_elements)
+    }
 }
@@ -1,7 +1,10 @@
-use crate::{Bound, LowerBound, Step};
+use crate::{
+    domain::domain, Bound, BoundedSet, Domain, Element, LowerBound, RangeError, Rangetools, Step,
+    UpperBoundedRange,
+};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
-use std::iter::FusedIterator;
+use std::{borrow::Borrow, iter::FusedIterator};
 
 /// A range only bounded below (either inclusive or exclusive).
 ///
@@ -30,35 +33,68 @@ impl<T> From<std::ops::RangeFrom<T>> for LowerBoundedRange<T> {
     }
 }
 
-impl<T> From<LowerBoundedRange<T>> for std::ops::RangeFrom<T>
+impl<T> TryFrom<LowerBoundedRange<T>> for std::ops::RangeFrom<T>
 where
-    T: Copy + Step,
+    T: Clone + Step,
 {
-    fn from(r: LowerBoundedRange<T>) -> Self {
+    type Error = RangeError;
+    fn try_from(r: LowerBoundedRange<T>) -> Result<Self, Self::Error> {
         match r.start.to_bound() {
-            Bound::Excluded(t) => Step::forward(t, 1)..,
-            Bound::Included(t) => t..,
+            Bound::Excluded(t) => Ok(Step::forward_checked(t, 1).ok_or(RangeError::Overflow)?..),
+            Bound::Included(t) => Ok(t..),
         }
     }
 }
 
+/// Allows a `LowerBoundedRange` to be used wherever the standard library accepts a range,
+/// including with an excluded lower bound.
+///
+/// # Example
+/// ```
+/// use rangetools::{LowerBound, LowerBoundedRange};
+///
+/// let mut v = vec![1, 2, 3, 4, 5];
+/// let drained: Vec<_> = v.drain(LowerBoundedRange::new(LowerBound::excluded(2))).collect();
+/// assert_eq!(drained, vec![4, 5]);
+/// assert_eq!(v, vec![1, 2, 3]);
+/// ```
+impl<T> std::ops::RangeBounds<T> for LowerBoundedRange<T> {
+    fn start_bound(&self) -> std::ops::Bound<&T> {
+        self.start.0.as_ref().into()
+    }
+
+    fn end_bound(&self) -> std::ops::Bound<&T> {
+        std::ops::Bound::Unbounded
+    }
+}
+
 impl<T> IntoIterator for LowerBoundedRange<T>
 where
-    T: Copy + Step,
+    T: Clone + Step,
 {
     type IntoIter = LowerBoundedRangeIter<T>;
     type Item = T;
     fn into_iter(self) -> Self::IntoIter {
-        LowerBoundedRangeIter {
-            current: match self.start {
-                LowerBound(Bound::Excluded(t)) => Step::forward(t, 1),
-                LowerBound(Bound::Included(t)) => t,
+        match self.start {
+            LowerBound(Bound::Excluded(t)) => match Step::forward_checked(t.clone(), 1) {
+                Some(current) => LowerBoundedRangeIter {
+                    current,
+                    exhausted: false,
+                },
+                None => LowerBoundedRangeIter {
+                    current: t,
+                    exhausted: true,
+                },
+            },
+            LowerBound(Bound::Included(t)) => LowerBoundedRangeIter {
+                current: t,
+                exhausted: false,
             },
         }
     }
 }
 
-impl<T: Copy + Ord> LowerBoundedRange<T> {
+impl<T: Element> LowerBoundedRange<T> {
     /// Constructs a new `LowerBoundedRange` from a lower bound.
     ///
     /// # Example
@@ -72,7 +108,7 @@ impl<T: Copy + Ord> LowerBoundedRange<T> {
         Self { start }
     }
 
-    /// Returns true if the range contains `t`.
+    /// Returns true if the range contains `t`, which can be passed by value or by reference.
     ///
     /// # Example
     /// ```
@@ -80,14 +116,128 @@ impl<T: Copy + Ord> LowerBoundedRange<T> {
     ///
     /// let i = (5..).intersection(10..);
     /// assert!(i.contains(10));
-    /// assert!(!i.contains(5));
+    /// assert!(!i.contains(&5));
     /// ```
-    pub fn contains(&self, t: T) -> bool {
-        match self.start.0 {
+    pub fn contains<Q: Borrow<T>>(&self, t: Q) -> bool {
+        let t = t.borrow();
+        match &self.start.0 {
             Bound::Excluded(x) => t > x,
             Bound::Included(i) => t >= i,
         }
     }
+
+    /// Returns the smallest range containing every element of the range, ie the range itself.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{LowerBoundedRange, Rangetools};
+    ///
+    /// assert_eq!((5..).to_inner().span(), LowerBoundedRange::from(5..));
+    /// ```
+    #[doc(alias = "hull")]
+    pub fn span(&self) -> LowerBoundedRange<T> {
+        self.clone()
+    }
+
+    /// Returns the lower bound of the range.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{LowerBound, Rangetools};
+    ///
+    /// assert_eq!((5..).to_inner().lower_bound(), LowerBound::included(5));
+    /// ```
+    #[doc(alias = "infimum")]
+    pub fn lower_bound(&self) -> LowerBound<T> {
+        self.start.clone()
+    }
+}
+
+impl<T: Element + Step> LowerBoundedRange<T> {
+    /// Returns the smallest element of the range, or `None` if the range has no elements.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{LowerBound, LowerBoundedRange};
+    ///
+    /// assert_eq!(LowerBoundedRange::new(LowerBound::excluded(5)).first(), Some(6));
+    /// assert_eq!(LowerBoundedRange::new(LowerBound::excluded(u8::MAX)).first(), None);
+    /// ```
+    pub fn first(&self) -> Option<T> {
+        match &self.start.0 {
+            Bound::Excluded(t) => Step::forward_checked(t.clone(), 1),
+            Bound::Included(t) => Some(t.clone()),
+        }
+    }
+
+    /// Returns the range in canonical form, with an included lower bound.
+    ///
+    /// A range excluding the largest value of `T` has no elements but can't be represented as
+    /// empty, so is returned unchanged.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{LowerBound, LowerBoundedRange};
+    ///
+    /// let r = LowerBoundedRange::new(LowerBound::excluded(5));
+    /// assert_eq!(r.normalize(), LowerBoundedRange::from(6..));
+    /// ```
+    pub fn normalize(self) -> Self {
+        match self.first() {
+            Some(first) => Self::new(LowerBound::included(first)),
+            None => self,
+        }
+    }
+}
+
+impl<T: Element + Domain> LowerBoundedRange<T> {
+    /// Returns true if the range contains every value of `T`, ie if it starts at `T::MIN`.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::Rangetools;
+    ///
+    /// assert!((0u8..).to_inner().is_full());
+    /// assert!((i8::MIN..).to_inner().is_full());
+    /// assert!(!(1u8..).to_inner().is_full());
+    /// ```
+    pub fn is_full(&self) -> bool {
+        self.first() == Some(T::MIN)
+    }
+
+    /// Returns the values of `T` not contained in the range, as a normalized set.
+    ///
+    /// Unlike [`complement`](crate::Rangetools::complement), the result is bounded below by
+    /// `T::MIN`.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::Rangetools;
+    ///
+    /// assert!((0u8..).to_inner().complement_in_domain().is_empty());
+    /// assert_eq!((10u8..).to_inner().complement_in_domain(), (0..=9).to_set());
+    /// ```
+    pub fn complement_in_domain(self) -> BoundedSet<T> {
+        let complement: UpperBoundedRange<T> = self.complement();
+        domain().intersection(complement).to_set().normalize()
+    }
+
+    /// Returns the range as a normalized set over the values of `T`, treating it as though it
+    /// ended at `T::MAX`.
+    ///
+    /// Sets over the same domain compare equal exactly when they contain the same elements,
+    /// whatever the range or set type they were built from.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::Rangetools;
+    ///
+    /// let r = (250u8..).to_inner();
+    /// assert_eq!(r.normalize_in_domain(), (250..=255).to_set());
+    /// ```
+    pub fn normalize_in_domain(self) -> BoundedSet<T> {
+        domain().intersection(self).to_set().normalize()
+    }
 }
 
 /// An iterator over the values contained by a `LowerBoundedRange`.
@@ -104,27 +254,52 @@ impl<T: Copy + Ord> LowerBoundedRange<T> {
 #[derive(Clone, Debug)]
 pub struct LowerBoundedRangeIter<T> {
     current: T,
+    /// Set once the iterator has yielded the largest value of `T`.
+    exhausted: bool,
 }
 
 impl<T> Iterator for LowerBoundedRangeIter<T>
 where
-    T: Copy + Step,
+    T: Clone + Step,
 {
     type Item = T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let t = self.current;
-        self.current = Step::forward(self.current, 1);
+        if self.exhausted {
+            return None;
+        }
+        let t = self.current.clone();
+        match Step::forward_checked(t.clone(), 1) {
+            Some(next) => self.current = next,
+            None => self.exhausted = true,
+        }
         Some(t)
     }
 
+    /// The iterator only ends at the largest value of `T`, so while it hasn't ended there is
+    /// at least one element remaining.
     fn size_hint(&self) -> (usize, Option<usize>) {
-        (usize::MAX, None)
+        if self.exhausted {
+            (0, Some(0))
+        } else {
+            (1, None)
+        }
     }
 
     fn nth(&mut self, n: usize) -> Option<Self::Item> {
-        self.current = Step::forward(self.current, n);
-        self.next()
+        if self.exhausted {
+            return None;
+        }
+        match Step::forward_checked(self.current.clone(), n) {
+            Some(current) => {
+                self.current = current;
+                self.next()
+            }
+            None => {
+                self.exhausted = true;
+                None
+            }
+        }
     }
 
     fn min(mut self) -> Option<Self::Item>
@@ -135,4 +310,4 @@ where
     }
 }
 
-impl<T> FusedIterator for LowerBoundedRangeIter<T> where T: Copy + Step {}
+impl<T> FusedIterator for LowerBoundedRangeIter<T> where T: Clone + Step {}
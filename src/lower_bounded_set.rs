@@ -1,10 +1,16 @@
 use crate::{
-    BoundedRange, BoundedSet, BoundedSetIter, LowerBound, LowerBoundedRange, LowerBoundedRangeIter,
-    Rangetools, Step,
+    bounded_set::check_follows, domain::domain, merge, BoundedRange, BoundedSet, BoundedSetIter,
+    Component, Components, Domain, Element, Gaps, LowerBound, LowerBoundedRange,
+    LowerBoundedRangeIter, OrderStatistics, RangeDifference, RangeError, RangeQuery, RangeUnion,
+    Ranges, Rangetools, Step, UpperBound, UpperBoundedSet,
 };
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
-use std::iter::FusedIterator;
+use std::{
+    borrow::Borrow,
+    hash::{Hash, Hasher},
+    iter::FusedIterator,
+};
 
 /// A set of ranges with a finite lower bound but no upper bound.
 ///
@@ -15,14 +21,32 @@ use std::iter::FusedIterator;
 ///
 /// let s: LowerBoundedSet<_> = (0..3).union(5..);
 /// ```
-#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+#[derive(Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub struct LowerBoundedSet<T> {
-    /// Kept private to enforce the invariant that the ranges be non-empty and non-overlapping.
+    /// Kept private to enforce the invariant that the ranges be non-empty, non-overlapping and
+    /// non-touching.
     pub(crate) ranges: BoundedSet<T>,
     pub(crate) lower_bounded_range: LowerBoundedRange<T>,
 }
 
+// Compared and hashed in canonical form, so that sets containing the same elements are equal.
+impl<T: Element> PartialEq for LowerBoundedSet<T> {
+    fn eq(&self, other: &Self) -> bool {
+        merge::canonical(self).eq(merge::canonical(other))
+    }
+}
+
+impl<T: Element> Eq for LowerBoundedSet<T> {}
+
+impl<T: Element + Hash> Hash for LowerBoundedSet<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for piece in merge::canonical(self) {
+            piece.hash(state);
+        }
+    }
+}
+
 impl<T> From<LowerBoundedRange<T>> for LowerBoundedSet<T> {
     fn from(lower_bounded_range: LowerBoundedRange<T>) -> Self {
         Self {
@@ -32,9 +56,29 @@ impl<T> From<LowerBoundedRange<T>> for LowerBoundedSet<T> {
     }
 }
 
+/// Succeeds if the set has no bounded ranges before its lower-bounded range.
+///
+/// # Example
+/// ```
+/// use rangetools::{LowerBoundedRange, RangeError, Rangetools};
+///
+/// assert_eq!(LowerBoundedRange::try_from((0..5).union(3..)), Ok(LowerBoundedRange::from(0..)));
+/// assert_eq!(LowerBoundedRange::try_from((0..2).union(3..)), Err(RangeError::MultipleComponents));
+/// ```
+impl<T> TryFrom<LowerBoundedSet<T>> for LowerBoundedRange<T> {
+    type Error = RangeError;
+    fn try_from(s: LowerBoundedSet<T>) -> Result<Self, Self::Error> {
+        if s.ranges.ranges.is_empty() {
+            Ok(s.lower_bounded_range)
+        } else {
+            Err(RangeError::MultipleComponents)
+        }
+    }
+}
+
 impl<T> IntoIterator for LowerBoundedSet<T>
 where
-    T: Copy + Ord + Step,
+    T: Element + Step,
 {
     type IntoIter = LowerBoundedSetIter<T>;
     type Item = T;
@@ -46,41 +90,275 @@ where
     }
 }
 
-impl<T: Copy + Ord> LowerBoundedSet<T> {
+impl<T> LowerBoundedSet<T> {
+    /// Returns an iterator over the disjoint bounded ranges making up the set, in ascending
+    /// order.
+    ///
+    /// The set's unbounded component is not included, and can be accessed with
+    /// [`lower_bounded_range`](Self::lower_bounded_range). To iterate over every component,
+    /// including the unbounded one, use [`components`](Self::components).
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{BoundedRange, Rangetools};
+    ///
+    /// let s = (0..2).union(5..7).union(10..);
+    /// let ranges: Vec<_> = s.ranges().copied().collect();
+    /// assert_eq!(ranges, vec![BoundedRange::from(0..2), BoundedRange::from(5..7)]);
+    /// ```
+    pub fn ranges(&self) -> Ranges<'_, T> {
+        self.ranges.ranges()
+    }
+
+    /// Returns the trailing unbounded component of the set.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{LowerBoundedRange, Rangetools};
+    ///
+    /// let s = (0..2).union(10..);
+    /// assert_eq!(s.lower_bounded_range(), &LowerBoundedRange::from(10..));
+    /// ```
+    pub fn lower_bounded_range(&self) -> &LowerBoundedRange<T> {
+        &self.lower_bounded_range
+    }
+
+    /// Returns an iterator over all the disjoint components making up the set, in ascending
+    /// order, ending with its trailing unbounded component.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{BoundedRange, Component, LowerBoundedRange, Rangetools};
+    ///
+    /// let s = (0..2).union(10..);
+    /// let components: Vec<_> = s.components().collect();
+    /// assert_eq!(
+    ///     components,
+    ///     vec![
+    ///         Component::Bounded(BoundedRange::from(0..2)),
+    ///         Component::LowerBounded(LowerBoundedRange::from(10..)),
+    ///     ]
+    /// );
+    /// ```
+    pub fn components(&self) -> Components<'_, T>
+    where
+        T: Clone,
+    {
+        let tail = Component::LowerBounded(self.lower_bounded_range.clone());
+        Components::new(None, self.ranges(), Some(tail))
+    }
+
+    /// Retains only the bounded ranges making up the set for which the predicate returns true.
+    ///
+    /// The trailing lower-bounded range is always kept, since removing it would leave a
+    /// [`BoundedSet`].
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{BoundedRange, Rangetools};
+    ///
+    /// let mut s = (0..2).union(5..10).union(20..);
+    /// s.retain(|r| r.contains(7));
+    /// assert_eq!(s, (5..10).union(20..));
+    /// ```
+    pub fn retain<F>(&mut self, f: F)
+    where
+        F: FnMut(&BoundedRange<T>) -> bool,
+    {
+        self.ranges.retain(f);
+    }
+}
+
+impl<T: Element> LowerBoundedSet<T> {
     fn defragment(&mut self) {
-        while !self.ranges.is_empty() {
-            if self
-                .ranges
-                .ranges
-                .back()
-                .unwrap()
-                .intersection(self.lower_bounded_range)
-                .is_empty()
-            {
-                return;
-            } else {
-                let range = self.ranges.ranges.pop_back().unwrap();
-                self.lower_bounded_range.start = self.lower_bounded_range.start.min(range.start);
+        let start = &self.lower_bounded_range.start;
+        let index = self
+            .ranges
+            .ranges
+            .partition_point(|range| !merge::is_connected(&range.end, start));
+        if let Some(first) = self.ranges.ranges_mut().drain(index..).next() {
+            if first.start < self.lower_bounded_range.start {
+                self.lower_bounded_range.start = first.start;
             }
         }
     }
-
     pub(crate) fn add_range(&mut self, range: BoundedRange<T>) {
         self.ranges.add_range(range);
         self.defragment();
     }
-    pub(crate) fn add_lower_bounded_range(&mut self, range: LowerBoundedRange<T>) {
-        self.lower_bounded_range.start =
-            LowerBound::min(self.lower_bounded_range.start, range.start);
-        self.defragment();
+    pub(crate) fn remove_range(&mut self, range: BoundedRange<T>) {
+        self.remove_between(Some(range.start), range.end);
+    }
+
+    /// Removes the values between `start` and `end`, where a missing start is unbounded.
+    fn remove_between(&mut self, start: Option<LowerBound<T>>, end: UpperBound<T>) {
+        if merge::is_empty(start.as_ref(), Some(&end)) {
+            return;
+        }
+        self.ranges.remove_between(start.clone(), Some(end.clone()));
+        let tail = &mut self.lower_bounded_range;
+        if !merge::is_empty(Some(&tail.start), Some(&end)) {
+            if let Some(start) = start {
+                let before = BoundedRange::new(tail.start.clone(), UpperBound(start.0.flipped()));
+                if !before.is_empty_by_bounds() {
+                    self.ranges.ranges_mut().push_back(before);
+                }
+            }
+            let start = LowerBound(end.0.flipped());
+            if start > tail.start {
+                tail.start = start;
+            }
+        }
+    }
+
+    /// Constructs a set from bounded ranges and a final lower-bounded range which are already
+    /// sorted and disjoint, in linear time.
+    ///
+    /// Fails at the first component which is empty, starts no later than the component before
+    /// it, or overlaps or touches the component before it. The error holds the index of that
+    /// component, with the lower-bounded range coming last.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{LowerBoundedSet, RangeError, Rangetools};
+    ///
+    /// let s = LowerBoundedSet::from_sorted_ranges([0..2, 5..7], 10..);
+    /// assert_eq!(s, Ok((0..2).union(5..7).union(10..)));
+    ///
+    /// let s = LowerBoundedSet::from_sorted_ranges([0..2, 5..7], 6..);
+    /// assert_eq!(s, Err(RangeError::Overlapping { index: 2 }));
+    /// ```
+    pub fn from_sorted_ranges<I, R>(ranges: I, lower_bounded_range: R) -> Result<Self, RangeError>
+    where
+        I: IntoIterator,
+        I::Item: Rangetools<Inner = BoundedRange<T>>,
+        R: Rangetools<Inner = LowerBoundedRange<T>>,
+    {
+        let ranges = BoundedSet::from_sorted_ranges(ranges)?;
+        let lower_bounded_range = lower_bounded_range.to_inner();
+        if let Some(last) = ranges.ranges.back() {
+            check_follows(
+                Some(&last.start),
+                &last.end,
+                &lower_bounded_range.start,
+                ranges.ranges.len(),
+            )?;
+        }
+        Ok(Self {
+            ranges,
+            lower_bounded_range,
+        })
     }
-    pub(crate) fn add_set(&mut self, set: BoundedSet<T>) {
-        for range in set.ranges {
-            self.add_range(range);
+
+    /// Constructs a set from bounded ranges and a final lower-bounded range which are already
+    /// sorted and disjoint, without checking them.
+    ///
+    /// The components must meet the conditions checked by
+    /// [`from_sorted_ranges`](Self::from_sorted_ranges). Otherwise the results of operations
+    /// on the set are unspecified.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{LowerBoundedSet, Rangetools};
+    ///
+    /// let s = LowerBoundedSet::from_sorted_ranges_unchecked([0..2, 5..7], 10..);
+    /// assert_eq!(s, (0..2).union(5..7).union(10..));
+    /// ```
+    pub fn from_sorted_ranges_unchecked<I, R>(ranges: I, lower_bounded_range: R) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Rangetools<Inner = BoundedRange<T>>,
+        R: Rangetools<Inner = LowerBoundedRange<T>>,
+    {
+        Self {
+            ranges: BoundedSet::from_sorted_ranges_unchecked(ranges),
+            lower_bounded_range: lower_bounded_range.to_inner(),
+        }
+    }
+
+    /// Adds `range` to the set in place, merging it with any components it overlaps or touches.
+    ///
+    /// Only ranges whose union with a `LowerBoundedSet` is still a `LowerBoundedSet` can be
+    /// inserted, so inserting an upper-bounded range is a compile error.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::Rangetools;
+    ///
+    /// let mut s = (10..).to_set();
+    /// s.insert(0..2);
+    /// s.insert(5..);
+    /// assert_eq!(s, (0..2).union(5..));
+    /// ```
+    pub fn insert<R>(&mut self, range: R)
+    where
+        R: Rangetools,
+        R::Set: RangeQuery<T>,
+        Self: RangeUnion<R, R::Set, Output = Self>,
+    {
+        let set = range.to_set();
+        let components = RangeQuery::components(&set);
+        if components.len() > 1 {
+            let pieces: Vec<_> = components.map(Component::into_bounds).collect();
+            *self = merge::union_owned((&*self).into(), &pieces).into();
+        } else {
+            for component in components {
+                match component {
+                    Component::Bounded(r) => self.add_range(r),
+                    Component::LowerBounded(r) => {
+                        if r.start < self.lower_bounded_range.start {
+                            self.lower_bounded_range.start = r.start;
+                        }
+                        self.defragment();
+                    }
+                    _ => unreachable!(
+                        "only ranges bounded below can be inserted into a `LowerBoundedSet`"
+                    ),
+                }
+            }
+        }
+    }
+
+    /// Removes all elements of `range` from the set in place, splitting any components it
+    /// partially overlaps.
+    ///
+    /// Only ranges whose difference from a `LowerBoundedSet` is still a `LowerBoundedSet` can
+    /// be removed, so removing a lower-bounded range (which would leave a [`BoundedSet`]) is a
+    /// compile error.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::Rangetools;
+    ///
+    /// let mut s = (0..).to_set();
+    /// s.remove(3..5);
+    /// s.remove(..1);
+    /// assert_eq!(s, (1..3).union(5..));
+    /// ```
+    pub fn remove<R>(&mut self, range: R)
+    where
+        R: Rangetools,
+        R::Set: RangeQuery<T>,
+        Self: RangeDifference<R, R::Inner, Output = Self>,
+    {
+        let set = range.to_set();
+        let components = RangeQuery::components(&set);
+        if components.len() > 1 {
+            let pieces: Vec<_> = components.map(Component::into_bounds).collect();
+            *self = merge::difference_owned((&*self).into(), &pieces).into();
+        } else {
+            for component in components {
+                match component.into_bounds() {
+                    (start, Some(end)) => self.remove_between(start, end),
+                    _ => unreachable!(
+                        "only ranges bounded above can be removed from a `LowerBoundedSet`"
+                    ),
+                }
+            }
         }
     }
 
-    /// Returns true if the set contains `t`.
+    /// Returns true if the set contains `t`, which can be passed by value or by reference.
     ///
     /// # Example
     /// ```
@@ -88,11 +366,310 @@ impl<T: Copy + Ord> LowerBoundedSet<T> {
     ///
     /// let s = (1..5).union(10..);
     /// assert!(s.contains(42));
-    /// assert!(!s.contains(0));
+    /// assert!(!s.contains(&0));
     /// ```
-    pub fn contains(&self, t: T) -> bool {
+    pub fn contains<Q: Borrow<T>>(&self, t: Q) -> bool {
+        let t = t.borrow();
         self.lower_bounded_range.contains(t) || self.ranges.contains(t)
     }
+
+    /// Returns the component of the set which contains `t`, if any.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{BoundedRange, Component, LowerBoundedRange, Rangetools};
+    ///
+    /// let s = (1..5).union(10..);
+    /// assert_eq!(s.range_containing(3), Some(Component::Bounded(BoundedRange::from(1..5))));
+    /// assert_eq!(
+    ///     s.range_containing(42),
+    ///     Some(Component::LowerBounded(LowerBoundedRange::from(10..)))
+    /// );
+    /// assert_eq!(s.range_containing(7), None);
+    /// ```
+    pub fn range_containing<Q: Borrow<T>>(&self, t: Q) -> Option<Component<T>> {
+        let t = t.borrow();
+        if self.lower_bounded_range.contains(t) {
+            Some(Component::LowerBounded(self.lower_bounded_range.clone()))
+        } else {
+            self.ranges.range_containing(t).map(Component::Bounded)
+        }
+    }
+
+    /// Returns an iterator over the gaps between the components of the set, in ascending
+    /// order.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{BoundedRange, Rangetools};
+    ///
+    /// let s = (0..2).union(5..7).union(10..);
+    /// let gaps: Vec<_> = s.gaps().collect();
+    /// assert_eq!(gaps, vec![BoundedRange::from(2..5), BoundedRange::from(7..10)]);
+    /// ```
+    pub fn gaps(&self) -> Gaps<'_, T> {
+        Gaps::new(
+            None,
+            self.ranges(),
+            Some(self.lower_bounded_range.start.clone()),
+        )
+    }
+
+    /// Returns the smallest range containing every element of the set.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{LowerBoundedRange, Rangetools};
+    ///
+    /// let s = (0..2).union(5..);
+    /// assert_eq!(s.span(), LowerBoundedRange::from(0..));
+    /// ```
+    #[doc(alias = "hull")]
+    pub fn span(&self) -> LowerBoundedRange<T> {
+        LowerBoundedRange::new(self.lower_bound())
+    }
+
+    /// Returns the lower bound of the set.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{LowerBound, Rangetools};
+    ///
+    /// let s = (0..2).union(5..);
+    /// assert_eq!(s.lower_bound(), LowerBound::included(0));
+    /// ```
+    #[doc(alias = "infimum")]
+    pub fn lower_bound(&self) -> LowerBound<T> {
+        self.ranges
+            .ranges
+            .front()
+            .map_or(&self.lower_bounded_range.start, |r| &r.start)
+            .clone()
+    }
+}
+
+impl<T: Element + Step> LowerBoundedSet<T> {
+    /// Returns the smallest element of the set, or `None` if the set has no elements.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::Rangetools;
+    ///
+    /// let s = (3..5).union(10..);
+    /// assert_eq!(s.first(), Some(3));
+    /// ```
+    pub fn first(&self) -> Option<T> {
+        self.ranges
+            .first()
+            .or_else(|| self.lower_bounded_range.first())
+    }
+
+    /// Returns the smallest element of the set which is greater than or equal to `t`.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::Rangetools;
+    ///
+    /// let s = (1..5).union(10..);
+    /// assert_eq!(s.next_at_or_after(5), Some(10));
+    /// assert_eq!(s.next_at_or_after(42), Some(42));
+    /// ```
+    pub fn next_at_or_after(&self, t: T) -> Option<T> {
+        self.ranges.next_at_or_after(t.clone()).or_else(|| {
+            if self.lower_bounded_range.contains(&t) {
+                Some(t)
+            } else {
+                self.lower_bounded_range.first()
+            }
+        })
+    }
+
+    /// Returns the smallest element of the set which is strictly greater than `t`.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::Rangetools;
+    ///
+    /// let s = (1..5).union(10..);
+    /// assert_eq!(s.next_after(4), Some(10));
+    /// ```
+    pub fn next_after(&self, t: T) -> Option<T> {
+        Step::forward_checked(t, 1).and_then(|t| self.next_at_or_after(t))
+    }
+
+    /// Returns the largest element of the set which is less than or equal to `t`.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::Rangetools;
+    ///
+    /// let s = (1..5).union(10..);
+    /// assert_eq!(s.prev_at_or_before(7), Some(4));
+    /// assert_eq!(s.prev_at_or_before(42), Some(42));
+    /// assert_eq!(s.prev_at_or_before(0), None);
+    /// ```
+    pub fn prev_at_or_before(&self, t: T) -> Option<T> {
+        if self.lower_bounded_range.contains(&t) {
+            Some(t)
+        } else {
+            self.ranges.prev_at_or_before(t)
+        }
+    }
+
+    /// Returns the largest element of the set which is strictly less than `t`.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::Rangetools;
+    ///
+    /// let s = (1..5).union(10..);
+    /// assert_eq!(s.prev_before(10), Some(4));
+    /// ```
+    pub fn prev_before(&self, t: T) -> Option<T> {
+        Step::backward_checked(t, 1).and_then(|t| self.prev_at_or_before(t))
+    }
+
+    /// Returns the number of elements of the set which are less than `t`.
+    ///
+    /// The running totals of the bounded ranges are computed by the first rank or select query,
+    /// in linear time, and then kept until the set is next modified, so later queries take
+    /// logarithmic time.
+    ///
+    /// # Panics
+    /// Panics if the count overflows a `u128`, which can't happen for primitive integers. Use
+    /// [`order_statistics`](Self::order_statistics) for a checked version.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::Rangetools;
+    ///
+    /// let s = (0..10).union(100..);
+    /// assert_eq!(s.rank(50), 10);
+    /// assert_eq!(s.rank(1000), 910);
+    /// ```
+    pub fn rank(&self, t: T) -> u128 {
+        self.order_statistics()
+            .rank(t)
+            .expect("rank overflowed a `u128`")
+    }
+
+    /// Returns the `k`th smallest element of the set (counting from zero), or `None` if it
+    /// doesn't fit in `T`.
+    ///
+    /// Takes logarithmic time, except for the first rank or select query after the set is
+    /// modified, as with [`rank`](Self::rank).
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::Rangetools;
+    ///
+    /// let s = (0..10).union(100..);
+    /// assert_eq!(s.select(10), Some(100));
+    /// assert_eq!(s.select(1000), Some(1090));
+    /// assert_eq!((0u8..).to_set().select(256), None);
+    /// ```
+    pub fn select(&self, k: u128) -> Option<T> {
+        self.order_statistics().select(k)
+    }
+
+    /// Returns an index over the elements of the set answering [`rank`](OrderStatistics::rank)
+    /// and [`select`](OrderStatistics::select) queries in logarithmic time, returning `None`
+    /// rather than panicking when a count overflows.
+    ///
+    /// The index borrows the same cached running totals as [`rank`](Self::rank) and
+    /// [`select`](Self::select).
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::Rangetools;
+    ///
+    /// let s = (0..10).union(100..);
+    /// let stats = s.order_statistics();
+    /// assert_eq!(stats.select(1000), Some(1090));
+    /// assert_eq!(stats.rank(1090), Some(1000));
+    /// ```
+    pub fn order_statistics(&self) -> OrderStatistics<'_, T> {
+        OrderStatistics::new(&self.ranges, Some(self.lower_bounded_range.clone()))
+    }
+
+    /// Returns the set in canonical form: every range has its bounds included, ranges without
+    /// elements are dropped, and ranges with no elements between them are merged.
+    ///
+    /// Two normalized sets are equal exactly when they contain the same elements.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::Rangetools;
+    ///
+    /// let s = (0..=2).union(3..=5).union(6..);
+    /// assert_eq!(s.normalize(), (0..).to_set());
+    /// ```
+    pub fn normalize(self) -> Self {
+        let mut ranges = self.ranges.normalize();
+        let mut lower_bounded_range = self.lower_bounded_range.normalize();
+        while let Some(range) = ranges.ranges.back() {
+            if !BoundedRange::is_contiguous(&range.end, &lower_bounded_range.start) {
+                break;
+            }
+            let range = ranges.ranges_mut().pop_back().unwrap();
+            if range.start < lower_bounded_range.start {
+                lower_bounded_range.start = range.start;
+            }
+        }
+        Self {
+            ranges,
+            lower_bounded_range,
+        }
+    }
+}
+
+impl<T: Element + Domain> LowerBoundedSet<T> {
+    /// Returns true if the set contains every value of `T`.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::Rangetools;
+    ///
+    /// assert!((0u8..=10).union(11..).is_full());
+    /// assert!(!(1u8..=10).union(11..).is_full());
+    /// ```
+    pub fn is_full(&self) -> bool {
+        self.clone().complement_in_domain().ranges.is_empty()
+    }
+
+    /// Returns the values of `T` not contained in the set, as a normalized set.
+    ///
+    /// Unlike [`complement`](crate::Rangetools::complement), the result is bounded below by
+    /// `T::MIN`.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::Rangetools;
+    ///
+    /// let c = (0u8..10).union(20..).complement_in_domain();
+    /// assert_eq!(c, (10..=19).to_set());
+    /// ```
+    pub fn complement_in_domain(self) -> BoundedSet<T> {
+        let complement: UpperBoundedSet<T> = self.complement();
+        domain().intersection(complement).normalize()
+    }
+
+    /// Returns the set as a normalized set over the values of `T`, treating it as though it
+    /// ended at `T::MAX`.
+    ///
+    /// Sets over the same domain compare equal exactly when they contain the same elements,
+    /// whatever the range or set type they were built from.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::Rangetools;
+    ///
+    /// let s = (0u8..10).union(250..);
+    /// assert_eq!(s.normalize_in_domain(), (0..=9).union(250..=255));
+    /// ```
+    pub fn normalize_in_domain(self) -> BoundedSet<T> {
+        domain().intersection(self).normalize()
+    }
 }
 
 /// An iterator over the values contained by a `LowerBoundedSet`.
@@ -114,7 +691,7 @@ pub struct LowerBoundedSetIter<T> {
 
 impl<T> Iterator for LowerBoundedSetIter<T>
 where
-    T: Copy + Ord + Step,
+    T: Element + Step,
 {
     type Item = T;
 
@@ -123,11 +700,17 @@ where
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        (usize::MAX, None)
+        let (set_lower, set_upper) = self.set_iter.size_hint();
+        let (range_lower, range_upper) = self.range_iter.size_hint();
+        let upper = match (set_upper, range_upper) {
+            (Some(a), Some(b)) => a.checked_add(b),
+            _ => None,
+        };
+        (set_lower.saturating_add(range_lower), upper)
     }
 
     fn nth(&mut self, n: usize) -> Option<Self::Item> {
-        let bounded_size = self.set_iter.len();
+        let bounded_size = self.set_iter.size_hint().0;
         self.set_iter
             .nth(n)
             .or_else(|| self.range_iter.nth(n - bounded_size))
@@ -138,4 +721,4 @@ where
     }
 }
 
-impl<T> FusedIterator for LowerBoundedSetIter<T> where T: Copy + Ord + Step {}
+impl<T> FusedIterator for LowerBoundedSetIter<T> where T: Element + Step {}
@@ -0,0 +1,51 @@
+use std::time::{Duration, Instant};
+
+/// Types whose ranges have a continuous length, rather than a number of elements.
+///
+/// Types are required to implement this trait for the `measure` method on bounded ranges and
+/// sets of that type, which returns the sum of the widths of the components. Whether the
+/// bounds are inclusive or exclusive doesn't affect the measure.
+pub trait Measure {
+    /// The type of the length of a range.
+    type Length: Default + std::ops::Add<Output = Self::Length>;
+
+    /// Returns the length of the range from `start` to `end`.
+    ///
+    /// Is only called with `start < end`.
+    fn length_between(start: &Self, end: &Self) -> Self::Length;
+}
+
+macro_rules! measure_float_impls {
+    ($($t:ty)*) => {
+        $(
+            impl Measure for $t {
+                type Length = $t;
+
+                #[inline]
+                fn length_between(start: &Self, end: &Self) -> Self::Length {
+                    end - start
+                }
+            }
+        )*
+    };
+}
+
+measure_float_impls! { f32 f64 }
+
+impl Measure for Duration {
+    type Length = Duration;
+
+    #[inline]
+    fn length_between(start: &Self, end: &Self) -> Self::Length {
+        *end - *start
+    }
+}
+
+impl Measure for Instant {
+    type Length = Duration;
+
+    #[inline]
+    fn length_between(start: &Self, end: &Self) -> Self::Length {
+        end.duration_since(*start)
+    }
+}
@@ -0,0 +1,598 @@
+//! Set operations performed as a single sweep over the sorted components of borrowed sets.
+//!
+//! Each operation only reads its operands, cloning just the bounds which end up in the output.
+
+use crate::{
+    AnySet, Bound, BoundedRange, BoundedSet, Element, EmptyRange, LowerBound, LowerBoundedRange,
+    LowerBoundedSet, PiecewiseUnboundedSet, Ranges, Rangetools, UnboundedRange, UnboundedSet,
+    UpperBound, UpperBoundedRange, UpperBoundedSet,
+};
+use std::{collections::VecDeque, iter, iter::Peekable};
+
+/// A borrowed component of a set, where a missing bound stands for an unbounded side.
+#[derive(Debug)]
+pub(crate) struct Piece<'a, T> {
+    start: Option<&'a LowerBound<T>>,
+    end: Option<&'a UpperBound<T>>,
+}
+
+impl<T> Clone for Piece<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Piece<'_, T> {}
+
+impl<'a, T> From<&'a OwnedPiece<T>> for Piece<'a, T> {
+    fn from((start, end): &'a OwnedPiece<T>) -> Self {
+        Self {
+            start: start.as_ref(),
+            end: end.as_ref(),
+        }
+    }
+}
+
+/// An iterator over the components of a borrowed set, in ascending order.
+#[derive(Clone, Debug)]
+pub(crate) struct Pieces<'a, T> {
+    head: Option<Piece<'a, T>>,
+    ranges: Ranges<'a, T>,
+    tail: Option<Piece<'a, T>>,
+}
+
+impl<'a, T> Iterator for Pieces<'a, T> {
+    type Item = Piece<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.head
+            .take()
+            .or_else(|| {
+                self.ranges.next().map(|range| Piece {
+                    start: Some(&range.start),
+                    end: Some(&range.end),
+                })
+            })
+            .or_else(|| self.tail.take())
+    }
+}
+
+impl<'a, T> From<&'a BoundedSet<T>> for Pieces<'a, T> {
+    fn from(set: &'a BoundedSet<T>) -> Self {
+        Self {
+            head: None,
+            ranges: set.ranges(),
+            tail: None,
+        }
+    }
+}
+
+impl<'a, T> From<&'a LowerBoundedSet<T>> for Pieces<'a, T> {
+    fn from(set: &'a LowerBoundedSet<T>) -> Self {
+        Self {
+            head: None,
+            ranges: set.ranges.ranges(),
+            tail: Some(Piece {
+                start: Some(&set.lower_bounded_range.start),
+                end: None,
+            }),
+        }
+    }
+}
+
+impl<'a, T> From<&'a UpperBoundedSet<T>> for Pieces<'a, T> {
+    fn from(set: &'a UpperBoundedSet<T>) -> Self {
+        Self {
+            head: Some(Piece {
+                start: None,
+                end: Some(&set.upper_bounded_range.end),
+            }),
+            ranges: set.ranges.ranges(),
+            tail: None,
+        }
+    }
+}
+
+impl<'a, T> From<&'a PiecewiseUnboundedSet<T>> for Pieces<'a, T> {
+    fn from(set: &'a PiecewiseUnboundedSet<T>) -> Self {
+        Self {
+            head: Some(Piece {
+                start: None,
+                end: Some(&set.upper_bounded_range.end),
+            }),
+            ranges: set.ranges.ranges(),
+            tail: Some(Piece {
+                start: Some(&set.lower_bounded_range.start),
+                end: None,
+            }),
+        }
+    }
+}
+
+impl<'a, T> From<&'a UnboundedSet<T>> for Pieces<'a, T> {
+    fn from(set: &'a UnboundedSet<T>) -> Self {
+        match set {
+            UnboundedSet::Full => Self {
+                head: Some(Piece {
+                    start: None,
+                    end: None,
+                }),
+                ranges: Ranges::empty(),
+                tail: None,
+            },
+            UnboundedSet::Piecewise(set) => set.into(),
+        }
+    }
+}
+
+impl<'a, T> Pieces<'a, T> {
+    /// Returns the components of a range, which has at most one.
+    fn single(piece: Option<Piece<'a, T>>) -> Self {
+        Self {
+            head: piece,
+            ranges: Ranges::empty(),
+            tail: None,
+        }
+    }
+}
+
+impl<'a, T> From<&'a EmptyRange<T>> for Pieces<'a, T> {
+    fn from(_: &'a EmptyRange<T>) -> Self {
+        Self::single(None)
+    }
+}
+
+impl<'a, T: PartialOrd> From<&'a BoundedRange<T>> for Pieces<'a, T> {
+    fn from(range: &'a BoundedRange<T>) -> Self {
+        Self::single((!range.is_empty_by_bounds()).then_some(Piece {
+            start: Some(&range.start),
+            end: Some(&range.end),
+        }))
+    }
+}
+
+impl<'a, T> From<&'a LowerBoundedRange<T>> for Pieces<'a, T> {
+    fn from(range: &'a LowerBoundedRange<T>) -> Self {
+        Self::single(Some(Piece {
+            start: Some(&range.start),
+            end: None,
+        }))
+    }
+}
+
+impl<'a, T> From<&'a UpperBoundedRange<T>> for Pieces<'a, T> {
+    fn from(range: &'a UpperBoundedRange<T>) -> Self {
+        Self::single(Some(Piece {
+            start: None,
+            end: Some(&range.end),
+        }))
+    }
+}
+
+impl<'a, T> From<&'a UnboundedRange> for Pieces<'a, T> {
+    fn from(_: &'a UnboundedRange) -> Self {
+        Self::single(Some(Piece {
+            start: None,
+            end: None,
+        }))
+    }
+}
+
+impl<'a, T: PartialOrd> From<&'a AnySet<T>> for Pieces<'a, T> {
+    fn from(set: &'a AnySet<T>) -> Self {
+        match set {
+            AnySet::EmptyRange(r) => r.into(),
+            AnySet::BoundedRange(r) => r.into(),
+            AnySet::BoundedSet(s) => s.into(),
+            AnySet::LowerBoundedRange(r) => r.into(),
+            AnySet::LowerBoundedSet(s) => s.into(),
+            AnySet::UpperBoundedRange(r) => r.into(),
+            AnySet::UpperBoundedSet(s) => s.into(),
+            AnySet::UnboundedRange(r) => r.into(),
+            AnySet::UnboundedSet(s) => s.into(),
+        }
+    }
+}
+
+/// An owned component of a set, where a missing bound stands for an unbounded side.
+pub(crate) type OwnedPiece<T> = (Option<LowerBound<T>>, Option<UpperBound<T>>);
+
+/// An iterator over the components of a set in canonical form, in ascending order.
+///
+/// Sets containing the same elements give the same components, so sets are compared and hashed
+/// through this rather than by their stored bounds.
+#[derive(Clone, Debug)]
+pub(crate) struct Canonical<I, T> {
+    pieces: I,
+    pending: Option<OwnedPiece<T>>,
+}
+
+impl<I, T> Canonical<I, T> {
+    /// Wraps an iterator over the disjoint components of a set, in ascending order.
+    pub(crate) fn new(pieces: I) -> Self {
+        Self {
+            pieces,
+            pending: None,
+        }
+    }
+}
+
+/// Returns the components of a borrowed set in canonical form.
+pub(crate) fn canonical<'a, T, S>(
+    set: &'a S,
+) -> Canonical<impl Iterator<Item = OwnedPiece<T>> + 'a, T>
+where
+    T: Clone + 'a,
+    &'a S: Into<Pieces<'a, T>>,
+{
+    Canonical::new(
+        set.into()
+            .map(|piece| (piece.start.cloned(), piece.end.cloned())),
+    )
+}
+
+impl<I, T> Iterator for Canonical<I, T>
+where
+    I: Iterator<Item = OwnedPiece<T>>,
+    T: Element,
+{
+    type Item = OwnedPiece<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for (start, end) in self.pieces.by_ref() {
+            let start = start.map(T::canonical_lower);
+            let end = end.map(T::canonical_upper);
+            if is_empty(start.as_ref(), end.as_ref()) {
+                continue;
+            }
+            match self.pending.take() {
+                Some((pending_start, pending_end)) => {
+                    let connected = match (&pending_end, &start) {
+                        (Some(pending_end), Some(start)) => is_connected(pending_end, start),
+                        _ => true,
+                    };
+                    if connected {
+                        self.pending = Some((pending_start, end));
+                    } else {
+                        self.pending = Some((start, end));
+                        return Some((pending_start, pending_end));
+                    }
+                }
+                None => self.pending = Some((start, end)),
+            }
+        }
+        self.pending.take()
+    }
+}
+
+/// The owned components of the result of a set operation, in ascending order.
+///
+/// Converted into whichever set type the operation outputs. The operand types determine which
+/// unbounded components are present, so the conversions only check this in debug builds.
+#[derive(Debug)]
+pub(crate) struct Merged<T> {
+    full: bool,
+    head: Option<UpperBound<T>>,
+    ranges: VecDeque<BoundedRange<T>>,
+    tail: Option<LowerBound<T>>,
+}
+
+impl<T> Merged<T> {
+    fn new() -> Self {
+        Self {
+            full: false,
+            head: None,
+            ranges: VecDeque::new(),
+            tail: None,
+        }
+    }
+
+    /// Borrows the components, in ascending order.
+    fn pieces(&self) -> impl Iterator<Item = Piece<'_, T>> {
+        let full = self.full.then_some(Piece {
+            start: None,
+            end: None,
+        });
+        let head = self.head.as_ref().map(|end| Piece {
+            start: None,
+            end: Some(end),
+        });
+        let ranges = self.ranges.iter().map(|range| Piece {
+            start: Some(&range.start),
+            end: Some(&range.end),
+        });
+        let tail = self.tail.as_ref().map(|start| Piece {
+            start: Some(start),
+            end: None,
+        });
+        full.into_iter().chain(head).chain(ranges).chain(tail)
+    }
+}
+
+impl<T: Element> Merged<T> {
+    /// Appends a component, dropping it if it is bounded and doesn't contain any elements.
+    fn push(&mut self, start: Option<LowerBound<T>>, end: Option<UpperBound<T>>) {
+        match (start, end) {
+            (None, None) => self.full = true,
+            (None, Some(end)) => self.head = Some(end),
+            (Some(start), None) => self.tail = Some(start),
+            (Some(start), Some(end)) => {
+                let range = BoundedRange { start, end };
+                if !range.is_empty() {
+                    self.ranges.push_back(range);
+                }
+            }
+        }
+    }
+}
+
+impl<T> From<Merged<T>> for BoundedSet<T> {
+    fn from(merged: Merged<T>) -> Self {
+        debug_assert!(!merged.full && merged.head.is_none() && merged.tail.is_none());
+        Self::new(merged.ranges)
+    }
+}
+
+impl<T> From<Merged<T>> for LowerBoundedSet<T> {
+    fn from(merged: Merged<T>) -> Self {
+        debug_assert!(!merged.full && merged.head.is_none());
+        Self {
+            ranges: BoundedSet::new(merged.ranges),
+            lower_bounded_range: LowerBoundedRange {
+                start: merged
+                    .tail
+                    .expect("lower-bounded set has an unbounded tail"),
+            },
+        }
+    }
+}
+
+impl<T> From<Merged<T>> for UpperBoundedSet<T> {
+    fn from(merged: Merged<T>) -> Self {
+        debug_assert!(!merged.full && merged.tail.is_none());
+        Self {
+            upper_bounded_range: UpperBoundedRange {
+                end: merged
+                    .head
+                    .expect("upper-bounded set has an unbounded head"),
+            },
+            ranges: BoundedSet::new(merged.ranges),
+        }
+    }
+}
+
+/// Picks the set type from whichever unbounded components are present.
+impl<T> From<Merged<T>> for AnySet<T> {
+    fn from(merged: Merged<T>) -> Self {
+        match (merged.full, &merged.head, &merged.tail) {
+            (true, _, _) | (false, Some(_), Some(_)) => UnboundedSet::from(merged).into(),
+            (false, Some(_), None) => UpperBoundedSet::from(merged).into(),
+            (false, None, Some(_)) => LowerBoundedSet::from(merged).into(),
+            (false, None, None) => BoundedSet::from(merged).into(),
+        }
+    }
+}
+
+impl<T> From<Merged<T>> for UnboundedSet<T> {
+    fn from(merged: Merged<T>) -> Self {
+        if merged.full {
+            return Self::Full;
+        }
+        Self::Piecewise(PiecewiseUnboundedSet {
+            upper_bounded_range: UpperBoundedRange {
+                end: merged.head.expect("unbounded set has an unbounded head"),
+            },
+            ranges: BoundedSet::new(merged.ranges),
+            lower_bounded_range: LowerBoundedRange {
+                start: merged.tail.expect("unbounded set has an unbounded tail"),
+            },
+        })
+    }
+}
+
+/// Returns true if there are no values between `start` and `end`, both of which are bounded.
+pub(crate) fn is_empty<T: Ord>(start: Option<&LowerBound<T>>, end: Option<&UpperBound<T>>) -> bool {
+    match (start, end) {
+        (Some(start), Some(end)) => match (&start.0, &end.0) {
+            (Bound::Included(start), Bound::Included(end)) => start > end,
+            (Bound::Excluded(start), Bound::Included(end))
+            | (Bound::Included(start), Bound::Excluded(end))
+            | (Bound::Excluded(start), Bound::Excluded(end)) => start >= end,
+        },
+        _ => false,
+    }
+}
+
+/// Returns true if a component ending at `end` overlaps or touches one starting at `start`.
+pub(crate) fn is_connected<T: Ord>(end: &UpperBound<T>, start: &LowerBound<T>) -> bool {
+    match (&end.0, &start.0) {
+        (Bound::Excluded(end), Bound::Excluded(start)) => end > start,
+        (Bound::Included(end), Bound::Included(start))
+        | (Bound::Included(end), Bound::Excluded(start))
+        | (Bound::Excluded(end), Bound::Included(start)) => end >= start,
+    }
+}
+
+/// Returns true if `a` ends no later than `b`, where a missing end is unbounded.
+fn ends_first<T: Ord>(a: Option<&UpperBound<T>>, b: Option<&UpperBound<T>>) -> bool {
+    match (a, b) {
+        (Some(a), Some(b)) => a <= b,
+        (a, _) => a.is_some(),
+    }
+}
+
+fn min_end<'a, T: Ord>(
+    a: Option<&'a UpperBound<T>>,
+    b: Option<&'a UpperBound<T>>,
+) -> Option<&'a UpperBound<T>> {
+    if ends_first(a, b) {
+        a
+    } else {
+        b
+    }
+}
+
+fn max_end<'a, T: Ord>(
+    a: Option<&'a UpperBound<T>>,
+    b: Option<&'a UpperBound<T>>,
+) -> Option<&'a UpperBound<T>> {
+    if ends_first(a, b) {
+        b
+    } else {
+        a
+    }
+}
+
+/// Pops whichever of the next components of `a` and `b` starts first.
+fn next_by_start<'a, T, A, B>(a: &mut Peekable<A>, b: &mut Peekable<B>) -> Option<Piece<'a, T>>
+where
+    T: Ord,
+    A: Iterator<Item = Piece<'a, T>>,
+    B: Iterator<Item = Piece<'a, T>>,
+{
+    // A missing start is unbounded, which `Option`'s ordering already puts first.
+    match (a.peek(), b.peek()) {
+        (Some(p), Some(q)) if q.start < p.start => b.next(),
+        (Some(_), _) => a.next(),
+        (None, _) => b.next(),
+    }
+}
+
+/// Returns the intersection of two sets, in time linear in their number of components.
+pub(crate) fn intersection<T: Element>(a: Pieces<T>, b: Pieces<T>) -> Merged<T> {
+    intersection_sorted(a, b)
+}
+
+/// Returns the intersection of two sequences of disjoint components, each in ascending order.
+fn intersection_sorted<'a, T: Element + 'a>(
+    mut a: impl Iterator<Item = Piece<'a, T>>,
+    mut b: impl Iterator<Item = Piece<'a, T>>,
+) -> Merged<T> {
+    let mut merged = Merged::new();
+    let (mut p, mut q) = (a.next(), b.next());
+    while let (Some(x), Some(y)) = (p, q) {
+        let start = x.start.max(y.start);
+        let end = min_end(x.end, y.end);
+        if !is_empty(start, end) {
+            merged.push(start.cloned(), end.cloned());
+        }
+        if ends_first(x.end, y.end) {
+            p = a.next();
+        } else {
+            q = b.next();
+        }
+    }
+    merged
+}
+
+/// Returns the union of two sets, in time linear in their number of components.
+pub(crate) fn union<T: Element>(a: Pieces<T>, b: Pieces<T>) -> Merged<T> {
+    union_sorted(a, b)
+}
+
+/// Returns the union of two sequences of components, each in ascending order of their starts,
+/// though unlike the components of a set they may overlap.
+fn union_sorted<'a, T: Element + 'a>(
+    a: impl Iterator<Item = Piece<'a, T>>,
+    b: impl Iterator<Item = Piece<'a, T>>,
+) -> Merged<T> {
+    let mut merged = Merged::new();
+    let (mut a, mut b) = (a.peekable(), b.peekable());
+    let mut current: Option<Piece<T>> = None;
+    while let Some(piece) = next_by_start(&mut a, &mut b) {
+        current = match current {
+            Some(c) => match (c.end, piece.start) {
+                (Some(end), Some(start)) if !is_connected(end, start) => {
+                    merged.push(c.start.cloned(), Some(end.clone()));
+                    Some(piece)
+                }
+                _ => Some(Piece {
+                    start: c.start,
+                    end: max_end(c.end, piece.end),
+                }),
+            },
+            None => Some(piece),
+        };
+    }
+    if let Some(c) = current {
+        merged.push(c.start.cloned(), c.end.cloned());
+    }
+    merged
+}
+
+/// Combines any number of items in balanced pairs, so that with a linear `combine` each item
+/// takes part in a logarithmic number of combinations.
+pub(crate) fn reduce_balanced<S>(
+    items: impl IntoIterator<Item = S>,
+    mut combine: impl FnMut(S, S) -> S,
+) -> Option<S> {
+    let mut items: Vec<S> = items.into_iter().collect();
+    while items.len() > 1 {
+        let mut pairs = items.into_iter();
+        let mut combined = Vec::with_capacity(pairs.len() / 2 + 1);
+        while let Some(a) = pairs.next() {
+            combined.push(match pairs.next() {
+                Some(b) => combine(a, b),
+                None => a,
+            });
+        }
+        items = combined;
+    }
+    items.pop()
+}
+
+/// Returns the union of any number of possibly overlapping components, which are sorted once by
+/// their starts and then merged in a single sweep.
+pub(crate) fn union_all<T: Element>(mut pieces: Vec<OwnedPiece<T>>) -> Merged<T> {
+    pieces.sort_by(|a, b| a.0.cmp(&b.0));
+    union_sorted(pieces.iter().map(Piece::from), iter::empty())
+}
+
+/// Returns the components of `a` which aren't in `b`, in time linear in their number of
+/// components.
+pub(crate) fn difference<T: Element>(a: Pieces<T>, b: Pieces<T>) -> Merged<T> {
+    intersection_sorted(a, complement(b).pieces())
+}
+
+/// Returns the components in exactly one of `a` and `b`, in time linear in their number of
+/// components.
+pub(crate) fn symmetric_difference<T: Element>(a: Pieces<T>, b: Pieces<T>) -> Merged<T> {
+    let (left, right) = (difference(a.clone(), b.clone()), difference(b, a));
+    union_sorted(left.pieces(), right.pieces())
+}
+
+/// Returns the union of a set with some sorted and disjoint owned components, in time linear in
+/// their number.
+pub(crate) fn union_owned<T: Element>(a: Pieces<T>, b: &[OwnedPiece<T>]) -> Merged<T> {
+    union_sorted(a, b.iter().map(Piece::from))
+}
+
+/// Returns the components of a set which aren't in some sorted and disjoint owned components,
+/// in time linear in their number.
+pub(crate) fn difference_owned<T: Element>(a: Pieces<T>, b: &[OwnedPiece<T>]) -> Merged<T> {
+    intersection_sorted(a, complement_sorted(b.iter().map(Piece::from)).pieces())
+}
+
+/// Returns the complement of a set, in time linear in its number of components.
+pub(crate) fn complement<T: Element>(a: Pieces<T>) -> Merged<T> {
+    complement_sorted(a)
+}
+
+/// Returns the complement of a sequence of disjoint components in ascending order.
+fn complement_sorted<'a, T: Element + 'a>(a: impl Iterator<Item = Piece<'a, T>>) -> Merged<T> {
+    let mut merged = Merged::new();
+    // The start of the gap following the components seen so far, if there is one.
+    let mut gap_start = Some(None);
+    for piece in a {
+        if let (Some(start), Some(end)) = (gap_start, piece.start) {
+            merged.push(start, Some(UpperBound(end.0.clone().flipped())));
+        }
+        gap_start = piece
+            .end
+            .map(|end| Some(LowerBound(end.0.clone().flipped())));
+    }
+    if let Some(start) = gap_start {
+        merged.push(start, None);
+    }
+    merged
+}
@@ -0,0 +1,100 @@
+use crate::{BoundedRange, BoundedSet, Element, LowerBoundedRange, Step};
+use std::collections::VecDeque;
+
+/// An index over the elements of a set answering rank and select queries in logarithmic time.
+///
+/// Borrows the running total of the number of elements in each range making up the set, so
+/// that [`rank`](Self::rank) and [`select`](Self::select) only need to binary search the
+/// totals rather than iterate through the elements. Unlike the `rank` methods on the sets,
+/// these queries return `None` rather than panicking when a count overflows a `u128`.
+///
+/// Created by the `order_statistics` method on [`BoundedSet`] and
+/// [`LowerBoundedSet`](crate::LowerBoundedSet).
+///
+/// # Example
+/// ```
+/// use rangetools::Rangetools;
+///
+/// let s = (0..10).union(100..110).union(1000..1010);
+/// let stats = s.order_statistics();
+/// assert_eq!(stats.select(15), Some(105));
+/// assert_eq!(stats.rank(105), Some(15));
+/// ```
+#[derive(Clone, Debug)]
+pub struct OrderStatistics<'a, T> {
+    ranges: &'a VecDeque<BoundedRange<T>>,
+    tail: Option<LowerBoundedRange<T>>,
+    /// `totals[i]` is the number of elements in `ranges[..=i]`. Truncated at the first range
+    /// whose running total overflows a `u128`.
+    totals: &'a [u128],
+}
+
+impl<'a, T: Element + Step> OrderStatistics<'a, T> {
+    pub(crate) fn new(set: &'a BoundedSet<T>, tail: Option<LowerBoundedRange<T>>) -> Self {
+        Self {
+            ranges: &set.ranges,
+            tail,
+            totals: set.totals(),
+        }
+    }
+
+    /// Returns the number of elements of the set which are less than `t`, or `None` if the
+    /// count overflows.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::Rangetools;
+    ///
+    /// let s = (0..10).union(20..);
+    /// let stats = s.order_statistics();
+    /// assert_eq!(stats.rank(5), Some(5));
+    /// assert_eq!(stats.rank(15), Some(10));
+    /// assert_eq!(stats.rank(25), Some(15));
+    /// ```
+    pub fn rank(&self, t: T) -> Option<u128> {
+        let index = self.ranges.partition_point(|r| r.is_below(&t));
+        let before = match index {
+            0 => 0,
+            i => *self.totals.get(i - 1)?,
+        };
+        let first = match self.ranges.get(index) {
+            Some(range) => range.first(),
+            None => self.tail.as_ref().and_then(LowerBoundedRange::first),
+        };
+        match first {
+            Some(first) if first < t => {
+                let steps = Step::steps_between_u128(&first, &t)?;
+                before.checked_add(steps)
+            }
+            _ => Some(before),
+        }
+    }
+
+    /// Returns the `k`th smallest element of the set (counting from zero), or `None` if the
+    /// set has `k` or fewer elements.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::Rangetools;
+    ///
+    /// let s = (0..10).union(20..);
+    /// let stats = s.order_statistics();
+    /// assert_eq!(stats.select(0), Some(0));
+    /// assert_eq!(stats.select(10), Some(20));
+    /// assert_eq!(stats.select(100), Some(110));
+    /// ```
+    pub fn select(&self, k: u128) -> Option<T> {
+        let index = self.totals.partition_point(|&total| total <= k);
+        let before = match index {
+            0 => 0,
+            i => self.totals[i - 1],
+        };
+        // If the totals were truncated then the `k`th element, if it exists, lies in the range
+        // whose running total overflowed.
+        let first = match self.ranges.get(index) {
+            Some(range) => range.first()?,
+            None => self.tail.as_ref()?.first()?,
+        };
+        Step::forward_checked_u128(first, k - before)
+    }
+}
@@ -0,0 +1,74 @@
+use crate::{Components, LowerBound, Rangetools, UpperBound};
+
+/// Element-level queries on a range or set, for code which is generic over the range type.
+///
+/// The range and set types in this crate have inherent methods for these queries, but they
+/// can't be reached through a generic [`Rangetools`] bound. This trait is implemented for all
+/// of the std::ops range types and all of the range and set types in this crate.
+///
+/// # Example
+/// ```
+/// use rangetools::{RangeQuery, Rangetools};
+///
+/// fn count_hits<R: RangeQuery<i32>>(r: &R, xs: &[i32]) -> usize {
+///     xs.iter().filter(|x| r.contains(x)).count()
+/// }
+///
+/// let xs = [1, 5, 10, 15];
+/// assert_eq!(count_hits(&(0..10), &xs), 2);
+/// assert_eq!(count_hits(&(..5).union(10..), &xs), 3);
+/// ```
+pub trait RangeQuery<T>: Rangetools {
+    /// Returns true if `self` contains `t`.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{RangeQuery, Rangetools};
+    ///
+    /// let s = (0..5).union(10..);
+    /// assert!(RangeQuery::contains(&s, &3));
+    /// assert!(!RangeQuery::contains(&s, &7));
+    /// assert!(RangeQuery::contains(&(0..=5), &5));
+    /// ```
+    fn contains(&self, t: &T) -> bool;
+
+    /// Returns the lower bound of `self`, or `None` if `self` is empty or has no lower bound.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{LowerBound, RangeQuery, Rangetools};
+    ///
+    /// assert_eq!(RangeQuery::lower_bound(&(3..)), Some(LowerBound::included(3)));
+    /// assert_eq!(RangeQuery::lower_bound(&(..3).union(5..8)), None);
+    /// ```
+    fn lower_bound(&self) -> Option<LowerBound<T>>;
+
+    /// Returns the upper bound of `self`, or `None` if `self` is empty or has no upper bound.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{RangeQuery, Rangetools, UpperBound};
+    ///
+    /// assert_eq!(RangeQuery::upper_bound(&(0..=3)), Some(UpperBound::included(3)));
+    /// assert_eq!(RangeQuery::upper_bound(&(3..5).union(5..)), None);
+    /// ```
+    fn upper_bound(&self) -> Option<UpperBound<T>>;
+
+    /// Returns an iterator over the disjoint components of `self`, in ascending order.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{BoundedRange, Component, LowerBoundedRange, RangeQuery, Rangetools};
+    ///
+    /// let s = (0..5).union(10..);
+    /// assert_eq!(
+    ///     s.components().collect::<Vec<_>>(),
+    ///     vec![
+    ///         Component::Bounded(BoundedRange::from(0..5)),
+    ///         Component::LowerBounded(LowerBoundedRange::from(10..)),
+    ///     ]
+    /// );
+    /// assert_eq!((5..0).components().next(), None);
+    /// ```
+    fn components(&self) -> Components<'_, T>;
+}
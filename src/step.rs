@@ -14,12 +14,37 @@ pub trait Step: Clone + PartialOrd + Sized {
     /// or if `start` > `end`.
     fn steps_between(start: &Self, end: &Self) -> Option<usize>;
 
+    /// Returns the number of succesor steps required to get from `start` to `end`, counted in a
+    /// `u128` rather than a `usize`.
+    ///
+    /// Returns `None` if the number of steps would overflow `u128`, or is infinite
+    /// or if `start` > `end`.
+    ///
+    /// The default implementation defers to `steps_between`, so it only needs to be overridden by
+    /// types with more than `usize::MAX` values.
+    fn steps_between_u128(start: &Self, end: &Self) -> Option<u128> {
+        Step::steps_between(start, end).map(|steps| steps as u128)
+    }
+
     /// Returns the value that would be obtained by taking the successor of `start`
     /// `count` times.
     ///
     /// Returns `None` if this would overflow the range of values supported by `Self`.
     fn forward_checked(start: Self, count: usize) -> Option<Self>;
 
+    /// Returns the value that would be obtained by taking the successor of `start`
+    /// `count` times, where `count` is a `u128` rather than a `usize`.
+    ///
+    /// Returns `None` if this would overflow the range of values supported by `Self`.
+    ///
+    /// The default implementation defers to `forward_checked`, so it only needs to be overridden
+    /// by types with more than `usize::MAX` values.
+    fn forward_checked_u128(start: Self, count: u128) -> Option<Self> {
+        usize::try_from(count)
+            .ok()
+            .and_then(|count| Step::forward_checked(start, count))
+    }
+
     /// Returns the value that would be obtained by taking the successor of `start`
     /// `count` times.
     ///
@@ -192,11 +217,25 @@ macro_rules! step_integer_impls {
                     }
                 }
 
+                #[inline]
+                fn steps_between_u128(start: &Self, end: &Self) -> Option<u128> {
+                    if *start <= *end {
+                        Some((*end - *start) as u128)
+                    } else {
+                        None
+                    }
+                }
+
                 #[inline]
                 fn forward_checked(start: Self, n: usize) -> Option<Self> {
                     start.checked_add(n as Self)
                 }
 
+                #[inline]
+                fn forward_checked_u128(start: Self, n: u128) -> Option<Self> {
+                    Self::try_from(n).ok().and_then(|n| start.checked_add(n))
+                }
+
                 #[inline]
                 fn backward_checked(start: Self, n: usize) -> Option<Self> {
                     start.checked_sub(n as Self)
@@ -221,11 +260,39 @@ macro_rules! step_integer_impls {
                     }
                 }
 
+                #[inline]
+                fn steps_between_u128(start: &Self, end: &Self) -> Option<u128> {
+                    if *start <= *end {
+                        // The difference always fits in a u128, even when it doesn't fit in
+                        // an i128, so wrapping in i128 space and casting gives the right result.
+                        Some((*end as i128).wrapping_sub(*start as i128) as u128)
+                    } else {
+                        None
+                    }
+                }
+
                 #[inline]
                 fn forward_checked(start: Self, n: usize) -> Option<Self> {
                     start.checked_add(n as Self)
                 }
 
+                #[inline]
+                fn forward_checked_u128(start: Self, n: u128) -> Option<Self> {
+                    match $u_wider::try_from(n) {
+                        Ok(n) => {
+                            let wrapped = start.wrapping_add(n as Self);
+                            if wrapped >= start {
+                                Some(wrapped)
+                            } else {
+                                None // Addition overflowed
+                            }
+                        }
+                        // If n is out of range of e.g. u64, then it is bigger than the entire
+                        // range for i64 is wide so `any_i64 + n` necessarily overflows i64.
+                        Err(_) => None,
+                    }
+                }
+
                 #[inline]
                 fn backward_checked(start: Self, n: usize) -> Option<Self> {
                     start.checked_sub(n as Self)
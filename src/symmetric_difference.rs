@@ -0,0 +1,15 @@
+use crate::Rangetools;
+
+/// Helper trait for performing range symmetric difference.
+///
+/// In most cases, users should import the `Rangetools` trait instead of this one, as it performs
+/// the necessary forwarding.  See [`.symmetric_difference()`](crate::Rangetools::symmetric_difference)
+/// for more information.
+pub trait RangeSymmetricDifference<Rhs, RhsSet> {
+    /// The output type of the symmetric difference.
+    type Output;
+    /// Returns the set symmetric difference of `self` and `other`.
+    fn symmetric_difference(self, other: Rhs) -> Self::Output
+    where
+        Rhs: Rangetools<Set = RhsSet>;
+}
@@ -0,0 +1,231 @@
+use crate::{
+    AnySet, BoundedRange, BoundedSet, EmptyRange, LowerBoundedRange, LowerBoundedSet, RangeError,
+    Rangetools as _, UnboundedRange, UnboundedSet, UpperBoundedRange, UpperBoundedSet,
+};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+fn samples() -> Vec<AnySet<i8>> {
+    vec![
+        EmptyRange::new().into(),
+        (0..10).into(),
+        (-20..-10).union(5..15).into(),
+        (3..).into(),
+        (-5..0).union(20..).into(),
+        (..=7).into(),
+        (..-10).union(0..4).into(),
+        (..).into(),
+        (..-3).union(3..).into(),
+    ]
+}
+
+fn elements(s: &AnySet<i8>) -> Vec<i8> {
+    (i8::MIN..=i8::MAX).filter(|&x| s.contains(x)).collect()
+}
+
+#[test]
+fn operations_match_elementwise() {
+    for a in samples() {
+        assert_eq!(
+            elements(&a.clone().complement()),
+            (i8::MIN..=i8::MAX)
+                .filter(|&x| !a.contains(x))
+                .collect::<Vec<_>>()
+        );
+        for b in samples() {
+            let filter = |f: fn(bool, bool) -> bool| -> Vec<i8> {
+                (i8::MIN..=i8::MAX)
+                    .filter(|&x| f(a.contains(x), b.contains(x)))
+                    .collect()
+            };
+            assert_eq!(
+                elements(&a.clone().intersection(b.clone())),
+                filter(|x, y| x && y)
+            );
+            assert_eq!(elements(&a.clone().union(b.clone())), filter(|x, y| x || y));
+            assert_eq!(
+                elements(&a.clone().difference(b.clone())),
+                filter(|x, y| x && !y)
+            );
+            assert_eq!(
+                elements(&a.clone().symmetric_difference(b.clone())),
+                filter(|x, y| x != y)
+            );
+        }
+    }
+}
+
+#[test]
+fn matches_static_types() {
+    let a = AnySet::from((0..10).union(20..));
+    assert_eq!(
+        a.clone().intersection(5..25),
+        AnySet::from((0..10).union(20..).intersection(5..25))
+    );
+    assert_eq!(
+        a.clone().union(..0),
+        AnySet::from((0..10).union(20..).union(..0))
+    );
+    assert_eq!(
+        a.clone().complement(),
+        AnySet::from((0..10).union(20..).complement())
+    );
+    assert_eq!(
+        a.difference(15..),
+        AnySet::from((0..10).union(20..).difference(15..))
+    );
+}
+
+fn hash(s: &AnySet<i32>) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    s.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn equality_across_variants() {
+    let pairs: Vec<(AnySet<i32>, AnySet<i32>)> = vec![
+        (AnySet::from(0..5), AnySet::from((0..5).to_set())),
+        (
+            AnySet::from((0..5).union(10..20)).intersection(0..5),
+            AnySet::from(0..5),
+        ),
+        (
+            AnySet::from(EmptyRange::new()),
+            AnySet::from(BoundedSet::empty()),
+        ),
+        (AnySet::from(..), AnySet::from(UnboundedSet::Full)),
+        (AnySet::from(3..), AnySet::from((3..).to_set())),
+        (AnySet::from(..=3), AnySet::from((..=3).to_set())),
+        (AnySet::from(0..3), AnySet::from(0..=2)),
+        (
+            AnySet::from((0..=2).union(3..=5)),
+            AnySet::from(BoundedRange::from(0..6)),
+        ),
+    ];
+    for (a, b) in pairs {
+        assert_eq!(a, b);
+        assert_eq!(hash(&a), hash(&b));
+    }
+    assert_ne!(AnySet::from(0..5), AnySet::from(0..=5));
+    assert_ne!(AnySet::from(0..), AnySet::from((0..5).union(6..)));
+    assert_ne!(AnySet::from(..), AnySet::<i32>::from(EmptyRange::new()));
+}
+
+#[test]
+fn any_set_on_the_right() {
+    let a = AnySet::from(5..15);
+    assert_eq!((0..10).intersection(a.clone()), AnySet::from(5..10));
+    assert_eq!((0..=9).intersection(a.clone()), AnySet::from(5..=9));
+    assert_eq!((..10).union(a.clone()), AnySet::from((..10).union(5..15)));
+    assert_eq!(
+        (10..).difference(a.clone()),
+        AnySet::from((10..).difference(5..15))
+    );
+    assert_eq!(
+        (..).symmetric_difference(a.clone()),
+        AnySet::from((5..15).complement())
+    );
+    assert_eq!(
+        (..).difference(a.clone()),
+        AnySet::from((5..15).complement())
+    );
+    assert_eq!(
+        UnboundedRange.difference(a.clone()),
+        AnySet::from((5..15).complement())
+    );
+    assert_eq!(EmptyRange::<i32>::new().union(a.clone()), a);
+    assert_eq!(
+        BoundedSet::from(BoundedRange::from(0..3)).union(a.clone()),
+        AnySet::from((0..3).union(5..15))
+    );
+    assert_eq!(
+        a.clone() & (0..10) | (20..),
+        AnySet::from((5..10).union(20..))
+    );
+    assert_eq!(!a.clone() - (..0), AnySet::from((0..5).union(15..)));
+    assert!(a.clone().is_subset(0..20));
+    assert!((6..8).is_subset(a));
+}
+
+#[test]
+fn heterogeneous_collection() {
+    let filters: Vec<AnySet<i32>> = vec![
+        (0..100).into(),
+        (10..).union(-5..0).into(),
+        (..50).complement().complement().into(),
+    ];
+    let s = filters
+        .iter()
+        .cloned()
+        .fold(AnySet::from(..), |acc, f| acc.intersection(f));
+    assert_eq!(
+        BoundedRange::try_from(s.clone()),
+        Ok(BoundedRange::from(10..50))
+    );
+    let s = filters
+        .into_iter()
+        .fold(AnySet::from(EmptyRange::new()), |acc, f| acc.union(f));
+    assert_eq!(UnboundedSet::try_from(s), Ok(UnboundedSet::Full));
+}
+
+#[test]
+#[allow(clippy::reversed_empty_ranges)]
+fn narrowing_empty_bounded_range() {
+    for s in [AnySet::from(5..3), AnySet::from(3..3), AnySet::from(3..=2)] {
+        assert_eq!(s, AnySet::from(EmptyRange::new()));
+        assert_eq!(BoundedRange::try_from(s.clone()), Err(RangeError::Empty));
+        assert_eq!(
+            BoundedRange::try_from(s),
+            BoundedRange::try_from(AnySet::from(EmptyRange::new()))
+        );
+    }
+}
+
+#[test]
+fn narrowing() {
+    assert_eq!(
+        BoundedRange::try_from(AnySet::from(0..5)),
+        Ok(BoundedRange::from(0..5))
+    );
+    assert_eq!(
+        BoundedRange::try_from(AnySet::from((0..2).union(3..5))),
+        Err(RangeError::MultipleComponents)
+    );
+    assert_eq!(
+        BoundedRange::try_from(AnySet::<i32>::from(EmptyRange::new())),
+        Err(RangeError::Empty)
+    );
+    assert_eq!(
+        BoundedSet::try_from(AnySet::from(0..5)),
+        Ok(BoundedRange::from(0..5).into())
+    );
+    assert_eq!(
+        LowerBoundedSet::try_from(AnySet::from(0..)),
+        Ok(LowerBoundedRange::from(0..).into())
+    );
+    assert_eq!(
+        UpperBoundedSet::try_from(AnySet::from(..0)),
+        Ok(UpperBoundedRange::from(..0).into())
+    );
+    assert_eq!(
+        UnboundedSet::try_from(AnySet::<i32>::from(..)),
+        Ok(UnboundedSet::Full)
+    );
+    assert_eq!(
+        UnboundedRange::try_from(AnySet::from((..3).union(2..))),
+        Ok(UnboundedRange)
+    );
+    assert!(EmptyRange::try_from(AnySet::from((0..5).intersection(5..))).is_ok());
+    for s in samples().into_iter().skip(1) {
+        assert_eq!(EmptyRange::try_from(s), Err(RangeError::IncompatibleType));
+    }
+    assert_eq!(
+        LowerBoundedRange::try_from(AnySet::from(0..5)),
+        Err(RangeError::IncompatibleType)
+    );
+    assert_eq!(
+        UnboundedSet::try_from(AnySet::from(0..)),
+        Err(RangeError::IncompatibleType)
+    );
+}
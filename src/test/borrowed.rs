@@ -0,0 +1,428 @@
+use crate::{
+    AnySet, BoundedRange, BoundedSet, EmptyRange, LowerBoundedRange, LowerBoundedSet, Rangetools,
+    UnboundedRange, UnboundedSet, UpperBoundedRange, UpperBoundedSet,
+};
+use std::ops::Bound;
+
+fn bounded_sets() -> Vec<BoundedSet<i32>> {
+    vec![
+        BoundedSet::empty(),
+        (0..5).to_set(),
+        (0..5).union(10..=15),
+        (-10..-5).union(3..7).union(8..=12).union(20..30),
+        (5..=5).union(6..8).union(15..16),
+    ]
+}
+
+fn lower_bounded_sets() -> Vec<LowerBoundedSet<i32>> {
+    vec![
+        (0..).to_set(),
+        (0..5).union(10..),
+        (-10..-5).union(3..7).union(12..),
+        (5..=5).union(7..8).union(30..),
+    ]
+}
+
+fn upper_bounded_sets() -> Vec<UpperBoundedSet<i32>> {
+    vec![
+        (..0).to_set(),
+        (..=5).union(10..15),
+        (..-5).union(3..7).union(12..20),
+        (..-20).union(5..=5).union(7..8),
+    ]
+}
+
+fn unbounded_sets() -> Vec<UnboundedSet<i32>> {
+    vec![
+        UnboundedSet::Full,
+        (..0).union(5..),
+        (..=5).union(10..15).union(20..),
+        (..-5).union(3..7).union(12..=14).union(16..),
+    ]
+}
+
+fn check_all<A, B>(lhs: Vec<A>, rhs: Vec<B>)
+where
+    A: Clone
+        + std::fmt::Debug
+        + crate::RangeIntersection<B, B>
+        + crate::RangeUnion<B, B>
+        + crate::RangeDifference<B, B>
+        + crate::RangeSymmetricDifference<B, B>,
+    B: Clone + std::fmt::Debug + Rangetools<Inner = B, Set = B>,
+    for<'a, 'b> &'a A: crate::RangeIntersection<
+            &'b B,
+            &'b B,
+            Output = <A as crate::RangeIntersection<B, B>>::Output,
+        > + crate::RangeUnion<&'b B, &'b B, Output = <A as crate::RangeUnion<B, B>>::Output>
+        + crate::RangeDifference<&'b B, &'b B, Output = <A as crate::RangeDifference<B, B>>::Output>
+        + crate::RangeSymmetricDifference<
+            &'b B,
+            &'b B,
+            Output = <A as crate::RangeSymmetricDifference<B, B>>::Output,
+        >,
+    for<'b> &'b B: Rangetools<Inner = &'b B, Set = &'b B>,
+    <A as crate::RangeIntersection<B, B>>::Output: PartialEq + std::fmt::Debug,
+    <A as crate::RangeUnion<B, B>>::Output: PartialEq + std::fmt::Debug,
+    <A as crate::RangeDifference<B, B>>::Output: PartialEq + std::fmt::Debug,
+    <A as crate::RangeSymmetricDifference<B, B>>::Output: PartialEq + std::fmt::Debug,
+{
+    for a in lhs.iter() {
+        for b in rhs.iter() {
+            assert_eq!(
+                crate::RangeIntersection::intersection(a, b),
+                crate::RangeIntersection::intersection(a.clone(), b.clone()),
+                "{:?} & {:?}",
+                a,
+                b
+            );
+            assert_eq!(
+                crate::RangeUnion::union(a, b),
+                crate::RangeUnion::union(a.clone(), b.clone()),
+                "{:?} | {:?}",
+                a,
+                b
+            );
+            assert_eq!(
+                crate::RangeDifference::difference(a, b),
+                crate::RangeDifference::difference(a.clone(), b.clone()),
+                "{:?} - {:?}",
+                a,
+                b
+            );
+            assert_eq!(
+                crate::RangeSymmetricDifference::symmetric_difference(a, b),
+                crate::RangeSymmetricDifference::symmetric_difference(a.clone(), b.clone()),
+                "{:?} ^ {:?}",
+                a,
+                b
+            );
+        }
+    }
+}
+
+// Checks every operation of each borrowed set with each operand against the owned operation.
+macro_rules! check_operands {
+    ($sets:expr; $($operand:expr),+ $(,)?) => {
+        for a in $sets {
+            $(
+                assert_eq!(
+                    (&a).intersection($operand),
+                    a.clone().intersection($operand),
+                    "{:?} & {:?}",
+                    a,
+                    $operand
+                );
+                assert_eq!(
+                    (&a).union($operand),
+                    a.clone().union($operand),
+                    "{:?} | {:?}",
+                    a,
+                    $operand
+                );
+                assert_eq!(
+                    (&a).difference($operand),
+                    a.clone().difference($operand),
+                    "{:?} - {:?}",
+                    a,
+                    $operand
+                );
+                assert_eq!(
+                    (&a).symmetric_difference($operand),
+                    a.clone().symmetric_difference($operand),
+                    "{:?} ^ {:?}",
+                    a,
+                    $operand
+                );
+            )+
+        }
+    };
+}
+
+#[test]
+fn matches_owned_operations() {
+    check_all(bounded_sets(), bounded_sets());
+    check_all(bounded_sets(), lower_bounded_sets());
+    check_all(bounded_sets(), upper_bounded_sets());
+    check_all(bounded_sets(), unbounded_sets());
+    check_all(lower_bounded_sets(), bounded_sets());
+    check_all(lower_bounded_sets(), lower_bounded_sets());
+    check_all(lower_bounded_sets(), upper_bounded_sets());
+    check_all(lower_bounded_sets(), unbounded_sets());
+    check_all(upper_bounded_sets(), bounded_sets());
+    check_all(upper_bounded_sets(), lower_bounded_sets());
+    check_all(upper_bounded_sets(), upper_bounded_sets());
+    check_all(upper_bounded_sets(), unbounded_sets());
+    check_all(unbounded_sets(), bounded_sets());
+    check_all(unbounded_sets(), lower_bounded_sets());
+    check_all(unbounded_sets(), upper_bounded_sets());
+    check_all(unbounded_sets(), unbounded_sets());
+}
+
+#[test]
+#[allow(clippy::reversed_empty_ranges)]
+fn range_operands_match_owned() {
+    macro_rules! check_sets {
+        ($($sets:expr),+) => {
+            $(
+                check_operands!(
+                    $sets;
+                    3..12,
+                    3..=12,
+                    3..,
+                    ..12,
+                    ..=12,
+                    ..,
+                    12..3,
+                    BoundedRange::from(-7..4),
+                    UnboundedRange,
+                    EmptyRange::<i32>::new(),
+                    (3..12).union(15..20),
+                    (3..12).union(15..),
+                    (..3).union(12..15),
+                    (..3).union(12..),
+                    AnySet::from(3..12),
+                    AnySet::from((..3).union(12..)),
+                    AnySet::<i32>::from(EmptyRange::new()),
+                    AnySet::<i32>::from(..),
+                );
+            )+
+        };
+    }
+    check_sets!(
+        bounded_sets(),
+        lower_bounded_sets(),
+        upper_bounded_sets(),
+        unbounded_sets()
+    );
+}
+
+// Checks every operation of each owned operand with each borrowed set against the owned operation.
+macro_rules! check_borrowed_rhs {
+    ($($lhs:expr),+ $(,)?) => {
+        for b in bounded_sets() {
+            check_borrowed_rhs!(@ b; $($lhs),+);
+        }
+        for b in lower_bounded_sets() {
+            check_borrowed_rhs!(@ b; $($lhs),+);
+        }
+        for b in upper_bounded_sets() {
+            check_borrowed_rhs!(@ b; $($lhs),+);
+        }
+        for b in unbounded_sets() {
+            check_borrowed_rhs!(@ b; $($lhs),+);
+        }
+    };
+    (@ $b:ident; $($lhs:expr),+) => {
+        $(
+            assert_eq!(
+                ($lhs).intersection(&$b),
+                ($lhs).intersection($b.clone()),
+                "{:?} & {:?}",
+                $lhs,
+                $b
+            );
+            assert_eq!(
+                ($lhs).union(&$b),
+                ($lhs).union($b.clone()),
+                "{:?} | {:?}",
+                $lhs,
+                $b
+            );
+            assert_eq!(
+                ($lhs).difference(&$b),
+                ($lhs).difference($b.clone()),
+                "{:?} - {:?}",
+                $lhs,
+                $b
+            );
+            assert_eq!(
+                ($lhs).symmetric_difference(&$b),
+                ($lhs).symmetric_difference($b.clone()),
+                "{:?} ^ {:?}",
+                $lhs,
+                $b
+            );
+        )+
+    };
+}
+
+#[test]
+#[allow(clippy::reversed_empty_ranges)]
+fn owned_operands_with_borrowed_sets_match_owned() {
+    check_borrowed_rhs!(
+        3..12,
+        3..=12,
+        3..,
+        ..12,
+        ..=12,
+        ..,
+        12..3,
+        (Bound::Excluded(3), Bound::Included(12)),
+        (Bound::<i32>::Unbounded, Bound::Excluded(3)),
+        BoundedRange::from(-7..4),
+        LowerBoundedRange::from(3..),
+        UpperBoundedRange::from(..=12),
+        (3..12).union(15..20),
+        (3..12).union(15..),
+        (..3).union(12..15),
+        (..3).union(12..),
+        UnboundedSet::<i32>::Full,
+        AnySet::from(3..12),
+        AnySet::from((..3).union(12..)),
+        AnySet::<i32>::from(EmptyRange::new()),
+        AnySet::<i32>::from(..),
+    );
+
+    // The union with an empty range and the intersection with an unbounded range are the
+    // other operand itself, so they give back the reference.
+    macro_rules! check_passthrough {
+        ($($sets:expr),+) => {
+            $(
+                for b in $sets {
+                    let empty = EmptyRange::<i32>::new();
+                    assert_eq!(empty.union(&b), &b);
+                    assert_eq!(empty.symmetric_difference(&b), &b);
+                    assert_eq!(empty.intersection(&b), empty);
+                    assert_eq!(empty.difference(&b), empty);
+                    assert_eq!(UnboundedRange.intersection(&b), &b);
+                    assert_eq!(UnboundedRange.union(&b), UnboundedRange);
+                    assert_eq!(
+                        UnboundedRange.difference(&b),
+                        UnboundedRange.difference(b.clone())
+                    );
+                    assert_eq!(
+                        UnboundedRange.symmetric_difference(&b),
+                        UnboundedRange.symmetric_difference(b.clone())
+                    );
+                }
+            )+
+        };
+    }
+    check_passthrough!(
+        bounded_sets(),
+        lower_bounded_sets(),
+        upper_bounded_sets(),
+        unbounded_sets()
+    );
+}
+
+#[test]
+fn owned_operands_with_borrowed_sets() {
+    let a = (0..5).union(10..20);
+    let b = (3..12).union(15..);
+    assert_eq!(
+        a.clone().intersection(&b),
+        (3..5).union(10..12).union(15..20)
+    );
+    assert_eq!((0..5).intersection(&a), (0..5).to_set());
+    assert_eq!((4..12).union(&a), (0..20).to_set());
+    assert_eq!((..).difference(&a), (..0).union(5..10).union(20..));
+    assert_eq!((0..).symmetric_difference(&a), (5..10).union(20..));
+    assert_eq!(a.clone() & &b, (3..5).union(10..12).union(15..20));
+    assert_eq!(b.clone() - &a, (5..10).union(20..));
+    assert_eq!((0..5).to_inner() | &b, (0..12).union(15..));
+    assert_eq!(
+        AnySet::from(0..12) ^ &a,
+        AnySet::from((5..10).union(12..20))
+    );
+
+    // The borrowed operands are still usable afterwards.
+    assert_eq!(a, (0..5).union(10..20));
+    assert_eq!(b, (3..12).union(15..));
+}
+
+#[test]
+fn complement_matches_owned() {
+    for s in bounded_sets() {
+        assert_eq!((&s).complement(), s.clone().complement());
+    }
+    for s in lower_bounded_sets() {
+        assert_eq!((&s).complement(), s.clone().complement());
+    }
+    for s in upper_bounded_sets() {
+        assert_eq!((&s).complement(), s.clone().complement());
+    }
+    for s in unbounded_sets() {
+        let c: BoundedSet<_> = Rangetools::complement(&s);
+        assert_eq!(c, s.clone().complement());
+    }
+}
+
+#[test]
+fn borrowed_operations() {
+    let a = (0..5).union(10..20);
+    let b = (3..12).union(15..);
+    assert_eq!((&a).intersection(&b), (3..5).union(10..12).union(15..20));
+    assert_eq!((&a).union(&b), (0..).to_set());
+    assert_eq!((&b).complement(), (..3).union(12..15));
+    assert_eq!(&a & &b, (3..5).union(10..12).union(15..20));
+    assert_eq!(&a | &b, (0..).to_set());
+    assert_eq!(!&a, (..0).union(5..10).union(20..));
+
+    // The operands are still usable afterwards.
+    assert_eq!(a, (0..5).union(10..20));
+    assert_eq!(b, (3..12).union(15..));
+}
+
+#[test]
+fn borrowed_range_operands_and_differences() {
+    let a = (0..5).union(10..20);
+    let b = (3..12).union(15..);
+    assert_eq!((&a).intersection(3..12), (3..5).union(10..12));
+    assert_eq!(&a & (3..12), (3..5).union(10..12));
+    assert_eq!(&a | (3..12), (0..20).to_set());
+    assert_eq!(&a - (3..12), (0..3).union(12..20));
+    assert_eq!(&a ^ (3..12), (0..3).union(5..10).union(12..20));
+    assert_eq!(Rangetools::difference(&a, &b), (0..3).union(12..15));
+    assert_eq!(&a - &b, (0..3).union(12..15));
+    assert_eq!(
+        Rangetools::symmetric_difference(&a, &b),
+        (0..3).union(5..10).union(12..15).union(20..)
+    );
+    assert_eq!(&b ^ &a, (0..3).union(5..10).union(12..15).union(20..));
+    assert_eq!(&b - (..), EmptyRange::new());
+    assert_eq!(&a & AnySet::from(15..), AnySet::from(15..20));
+    assert!((&a).is_subset(0..20));
+    assert!(!(&a).is_subset(&b));
+    assert!((&a).set_eq((0..5).union(10..20)));
+
+    // The operands are still usable afterwards.
+    assert_eq!(a, (0..5).union(10..20));
+    assert_eq!(b, (3..12).union(15..));
+}
+
+#[test]
+fn borrowed_edge_cases() {
+    let touching = (0..3).to_set();
+    assert_eq!((&touching).union(&(3..5).to_set()), (0..5).to_set());
+    assert_eq!(
+        (&touching).intersection(&(3..5).to_set()),
+        BoundedSet::empty()
+    );
+    let single = (3..=3).to_set();
+    assert_eq!((&touching).union(&single), (0..=3).to_set());
+    assert_eq!((&single).intersection(&(0..=3).to_set()), single);
+
+    let u = (..0).union(0..);
+    assert_eq!(u, UnboundedSet::Full);
+    let l = (5..).to_set();
+    assert_eq!((&(..6).to_set()).union(&l), UnboundedSet::Full);
+    assert_eq!(
+        (&UnboundedSet::Full).complement(),
+        BoundedSet::<i32>::empty()
+    );
+    assert_eq!(
+        (&BoundedSet::<i32>::empty()).complement(),
+        UnboundedSet::Full
+    );
+}
+
+#[test]
+fn borrowed_non_copy() {
+    let s = |s: &str| String::from(s);
+    let a = (s("a")..s("f")).union(s("k")..s("p"));
+    let b = (s("c")..s("m")).to_set();
+    assert_eq!(&a & &b, (s("c")..s("f")).union(s("k")..s("m")));
+    assert_eq!(&a | &b, (s("a")..s("p")).to_set());
+}
@@ -0,0 +1,137 @@
+use crate::{
+    BoundedRange, BoundedSet, EmptyRange, LowerBound, LowerBoundedRange, Rangetools as _,
+    UnboundedRange, UnboundedSet, UpperBound, UpperBoundedRange,
+};
+
+#[test]
+#[allow(clippy::reversed_empty_ranges)]
+fn bounded_range() {
+    let r = BoundedRange::new(LowerBound::excluded(2), UpperBound::excluded(7));
+    assert_eq!(r.span(), Some(r));
+    assert_eq!(r.lower_bound(), Some(LowerBound::excluded(2)));
+    assert_eq!(r.upper_bound(), Some(UpperBound::excluded(7)));
+    assert_eq!(r.first(), Some(3));
+    assert_eq!(r.last(), Some(6));
+
+    let r = (5..=5).to_inner();
+    assert_eq!(r.first(), Some(5));
+    assert_eq!(r.last(), Some(5));
+
+    let r = (5..2).to_inner();
+    assert_eq!(r.span(), None);
+    assert_eq!(r.lower_bound(), None);
+    assert_eq!(r.upper_bound(), None);
+    assert_eq!(r.first(), None);
+    assert_eq!(r.last(), None);
+}
+
+#[test]
+fn bounded_range_without_elements() {
+    let r = BoundedRange::new(LowerBound::excluded(3), UpperBound::excluded(4));
+    assert_eq!(r.span(), None);
+    assert_eq!(r.first(), None);
+    assert_eq!(r.last(), None);
+}
+
+#[test]
+fn bounded_range_type_extremes() {
+    let r = BoundedRange::new(LowerBound::excluded(u8::MAX), UpperBound::included(u8::MAX));
+    assert_eq!(r.first(), None);
+    assert_eq!(r.last(), None);
+    let r = BoundedRange::new(LowerBound::included(i8::MIN), UpperBound::excluded(i8::MIN));
+    assert_eq!(r.first(), None);
+    assert_eq!(r.last(), None);
+    let r = (i64::MIN..=i64::MAX).to_inner();
+    assert_eq!(r.first(), Some(i64::MIN));
+    assert_eq!(r.last(), Some(i64::MAX));
+    let r = ('\u{D7FF}'..'\u{E001}').to_inner();
+    assert_eq!(r.last(), Some('\u{E000}'));
+    let r = BoundedRange::new(LowerBound::excluded('\u{D7FF}'), UpperBound::included('z'));
+    assert_eq!(r.first(), None);
+}
+
+#[test]
+fn bounded_set() {
+    let s = (10..12).union(0..2).union(5..=7);
+    assert_eq!(s.span(), Some(BoundedRange::from(0..12)));
+    assert_eq!(s.lower_bound(), Some(LowerBound::included(0)));
+    assert_eq!(s.upper_bound(), Some(UpperBound::excluded(12)));
+    assert_eq!(s.first(), Some(0));
+    assert_eq!(s.last(), Some(11));
+
+    let s = BoundedSet::<i32>::empty();
+    assert_eq!(s.span(), None);
+    assert_eq!(s.lower_bound(), None);
+    assert_eq!(s.upper_bound(), None);
+    assert_eq!(s.first(), None);
+    assert_eq!(s.last(), None);
+}
+
+#[test]
+fn bounded_set_drops_components_without_elements() {
+    let gap = BoundedRange::new(LowerBound::excluded(3), UpperBound::excluded(4));
+    let s = gap.union(10..12).union(BoundedRange::new(
+        LowerBound::excluded(20),
+        UpperBound::excluded(21),
+    ));
+    assert_eq!(s.first(), Some(10));
+    assert_eq!(s.last(), Some(11));
+    assert_eq!(s.lower_bound(), Some(LowerBound::included(10)));
+    assert_eq!(s.upper_bound(), Some(UpperBound::excluded(12)));
+    assert_eq!(s.ranges().len(), 1);
+}
+
+#[test]
+fn lower_bounded() {
+    let r = LowerBoundedRange::new(LowerBound::excluded(5));
+    assert_eq!(r.span(), r);
+    assert_eq!(r.lower_bound(), LowerBound::excluded(5));
+    assert_eq!(r.first(), Some(6));
+    assert_eq!(
+        LowerBoundedRange::new(LowerBound::excluded(u8::MAX)).first(),
+        None
+    );
+
+    let s = (0..2).union(5..);
+    assert_eq!(s.span(), LowerBoundedRange::from(0..));
+    assert_eq!(s.lower_bound(), LowerBound::included(0));
+    assert_eq!(s.first(), Some(0));
+
+    let s = (20..).to_set();
+    assert_eq!(s.span(), LowerBoundedRange::from(20..));
+    assert_eq!(s.first(), Some(20));
+
+    let s = BoundedRange::new(LowerBound::excluded(3), UpperBound::excluded(4)).union(10..);
+    assert_eq!(s.lower_bound(), LowerBound::included(10));
+    assert_eq!(s.first(), Some(10));
+}
+
+#[test]
+fn upper_bounded() {
+    let r = UpperBoundedRange::new(UpperBound::excluded(5));
+    assert_eq!(r.span(), r);
+    assert_eq!(r.upper_bound(), UpperBound::excluded(5));
+    assert_eq!(r.last(), Some(4));
+    assert_eq!((..i8::MIN).to_inner().last(), None);
+
+    let s = (..0).union(5..=7);
+    assert_eq!(s.span(), UpperBoundedRange::from(..=7));
+    assert_eq!(s.upper_bound(), UpperBound::included(7));
+    assert_eq!(s.last(), Some(7));
+
+    let s = (..=-3).to_set();
+    assert_eq!(s.span(), UpperBoundedRange::from(..=-3));
+    assert_eq!(s.last(), Some(-3));
+}
+
+#[test]
+fn unbounded_and_empty() {
+    assert_eq!(UnboundedRange.span(), UnboundedRange);
+    assert_eq!((..0).union(5..).span(), UnboundedRange);
+    assert_eq!(UnboundedSet::<i32>::Full.span(), UnboundedRange);
+
+    let e = EmptyRange::<i32>::new();
+    assert_eq!(e.span(), e);
+    assert_eq!(e.first(), None);
+    assert_eq!(e.last(), None);
+}
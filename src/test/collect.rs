@@ -0,0 +1,254 @@
+use crate::{
+    intersection_all, intersection_all_any, union_all, union_all_any, AnySet, BoundedRange,
+    BoundedSet, EmptyRange, LowerBound, LowerBoundedRange, Rangetools as _, UnboundedRange,
+    UnboundedSet, UpperBound, UpperBoundedRange,
+};
+
+#[test]
+fn from_iterator() {
+    let s: BoundedSet<i32> = vec![10..12, 0..3, 2..5, 7..7, 20..25, 11..15]
+        .into_iter()
+        .collect();
+    assert_eq!(s, (0..5).union(10..15).union(20..25));
+
+    let s: BoundedSet<i32> = (0..5).map(|i| i * 3..=i * 3 + 1).collect();
+    assert_eq!(
+        s.into_iter().collect::<Vec<_>>(),
+        vec![0, 1, 3, 4, 6, 7, 9, 10, 12, 13]
+    );
+
+    let s: BoundedSet<i32> = std::iter::empty::<BoundedRange<i32>>().collect();
+    assert!(s.is_empty());
+}
+
+#[test]
+fn from_iterator_matches_pairwise_union() {
+    let ranges: Vec<_> = (0..200)
+        .map(|i| (i * 37) % 101..(i * 37) % 101 + i % 4)
+        .collect();
+    let collected: BoundedSet<i32> = ranges.iter().cloned().collect();
+    let folded = ranges
+        .into_iter()
+        .fold(BoundedSet::empty(), |s, r| s.union(r));
+    assert_eq!(collected, folded);
+}
+
+#[test]
+fn touching_ranges_are_merged() {
+    let s: BoundedSet<i32> = vec![3..5, 0..3].into_iter().collect();
+    assert_eq!(s, (0..5).to_set());
+    let s: BoundedSet<i32> = vec![0..=2, 3..=5].into_iter().collect();
+    assert_eq!(s, (0..6).to_set());
+    assert_eq!(
+        s.normalize().ranges().copied().collect::<Vec<_>>(),
+        vec![BoundedRange::from(0..=5)]
+    );
+    let s: BoundedSet<i32> = vec![BoundedRange::from(0..=3), BoundedRange::from(3..5)]
+        .into_iter()
+        .collect();
+    assert_eq!(s, (0..5).to_set());
+}
+
+#[test]
+fn extend() {
+    let mut s = (0..2).union(10..12);
+    s.extend(vec![
+        (1..4).to_inner(),
+        (8..=9).to_inner(),
+        (30..31).to_inner(),
+    ]);
+    assert_eq!(s, (0..4).union(8..=9).union(10..12).union(30..31));
+    s.extend(vec![BoundedRange::new(
+        LowerBound::excluded(3),
+        UpperBound::excluded(10),
+    )]);
+    assert_eq!(s, (0..10).union(10..12).union(30..31));
+    s.extend(Vec::<std::ops::Range<i32>>::new());
+    assert_eq!(s, (0..10).union(10..12).union(30..31));
+}
+
+#[test]
+fn union_all_bounded() {
+    assert_eq!(union_all(vec![5..7, 0..2, 1..3]), Some((0..3).union(5..7)));
+    assert_eq!(
+        union_all(vec![(0..2).union(5..7), (6..9).to_set()]),
+        Some((0..2).union(5..9))
+    );
+    assert_eq!(union_all(Vec::<BoundedRange<i32>>::new()), None);
+}
+
+#[test]
+fn union_all_unbounded() {
+    assert_eq!(
+        union_all(vec![5.., 3.., 10..]),
+        Some(LowerBoundedRange::from(3..).to_set())
+    );
+    assert_eq!(
+        union_all(vec![(..5).to_inner(), (..=3).to_inner()]),
+        Some(UpperBoundedRange::from(..5).to_set())
+    );
+    assert_eq!(
+        union_all(vec![(..0).union(5..), (..-2).union(3..)]),
+        Some((..0).union(3..))
+    );
+    assert_eq!(
+        union_all(vec![(..0).union(5..), (..6).union(10..)]),
+        Some(UnboundedSet::Full)
+    );
+    assert_eq!(
+        union_all(vec![UnboundedRange, UnboundedRange]),
+        Some(UnboundedRange)
+    );
+    assert_eq!(
+        union_all(vec![EmptyRange::<i32>::new()]),
+        Some(EmptyRange::new())
+    );
+}
+
+#[test]
+fn union_all_many() {
+    // Reversed and overlapping, so components from distant items have to be merged.
+    let s = union_all((0..1000).rev().map(|i| i * 10..i * 10 + 5));
+    assert_eq!(s, Some((0..1000).map(|i| i * 10..i * 10 + 5).collect()));
+    let s = union_all((0..1000).rev().map(|i| i * 10..i * 10 + 15));
+    assert_eq!(s, Some((0..10005).to_set()));
+}
+
+#[test]
+fn intersection_all_ranges() {
+    assert_eq!(
+        intersection_all(vec![
+            (0..10).to_inner(),
+            (2..12).to_inner(),
+            (1..=5).to_inner()
+        ]),
+        Some(BoundedRange::from(2..=5))
+    );
+    assert!(intersection_all(vec![0..3, 5..7]).unwrap().is_empty());
+    assert_eq!(
+        intersection_all(vec![0.., 5.., 3..]),
+        Some(LowerBoundedRange::from(5..))
+    );
+    assert_eq!(
+        intersection_all(vec![(..0).to_inner(), (..=-3).to_inner()]),
+        Some(UpperBoundedRange::from(..=-3))
+    );
+    assert_eq!(
+        intersection_all(Vec::<std::ops::RangeFrom<i32>>::new()),
+        None
+    );
+}
+
+#[test]
+fn intersection_all_sets() {
+    assert_eq!(
+        intersection_all(vec![
+            (0..5).union(10..15),
+            (3..12).to_set(),
+            (4..11).union(14..20)
+        ]),
+        Some((4..5).union(10..11))
+    );
+    assert_eq!(
+        intersection_all(vec![(..0).union(5..), (..-5).union(10..)]),
+        Some((..-5).union(10..))
+    );
+}
+
+#[test]
+fn union_all_any_bounded() {
+    assert_eq!(
+        union_all_any(vec![5..7, 0..2, 1..3]),
+        AnySet::from((0..3).union(5..7))
+    );
+    assert_eq!(
+        union_all_any([AnySet::from(0..=2), (3..5).into()]),
+        AnySet::from((0..=2).union(3..5))
+    );
+    assert_eq!(
+        union_all_any(Vec::<BoundedRange<i32>>::new()),
+        AnySet::BoundedSet(BoundedSet::empty())
+    );
+}
+
+#[test]
+fn union_all_any_mixed_types() {
+    let s = union_all_any([
+        AnySet::from(20..30),
+        (..-10).into(),
+        (0..5).union(8..12).into(),
+        (25..).into(),
+        EmptyRange::new().into(),
+        (4..9).into(),
+    ]);
+    assert_eq!(s, AnySet::from((..-10).union(0..12).union(20..)));
+    assert!(matches!(s, AnySet::UnboundedSet(_)));
+    assert!(matches!(
+        union_all_any([AnySet::from(0..5), (3..).into()]),
+        AnySet::LowerBoundedSet(_)
+    ));
+    assert!(matches!(
+        union_all_any([AnySet::from(..0), (3..5).into()]),
+        AnySet::UpperBoundedSet(_)
+    ));
+    assert_eq!(
+        union_all_any([AnySet::from(..0), (..6).union(10..).into(), (5..11).into()]),
+        AnySet::UnboundedSet(UnboundedSet::Full)
+    );
+}
+
+#[test]
+fn intersection_all_any_mixed_types() {
+    let s = intersection_all_any([
+        AnySet::from(..20),
+        (-10..).into(),
+        (..0).union(5..).into(),
+        (..).into(),
+    ]);
+    assert_eq!(s, AnySet::from((-10..0).union(5..20)));
+    assert!(matches!(s, AnySet::BoundedSet(_)));
+    assert_eq!(
+        intersection_all_any([AnySet::from(0..10), EmptyRange::new().into()]),
+        AnySet::from(EmptyRange::new())
+    );
+    assert_eq!(
+        intersection_all_any([AnySet::from((..0).union(5..)), (..3).union(8..).into()]),
+        AnySet::from((..0).union(8..))
+    );
+    assert_eq!(
+        intersection_all_any(Vec::<AnySet<i32>>::new()),
+        AnySet::UnboundedSet(UnboundedSet::Full)
+    );
+}
+
+#[test]
+fn all_match_pairwise_folds() {
+    let sets: Vec<AnySet<i32>> = vec![
+        (0..10).union(20..30).into(),
+        (5..25).into(),
+        (..8).union(22..).into(),
+        (3..=27).into(),
+    ];
+    assert_eq!(
+        union_all_any(sets.clone()),
+        sets.iter()
+            .cloned()
+            .fold(AnySet::from(EmptyRange::new()), |a, b| a.union(b))
+    );
+    assert_eq!(
+        intersection_all_any(sets.clone()),
+        sets.iter()
+            .cloned()
+            .fold(AnySet::from(..), |a, b| a.intersection(b))
+    );
+
+    let sets: Vec<_> = (0..7).map(|i| (i..i + 20).union(i * 10..)).collect();
+    assert_eq!(
+        union_all(sets.clone()),
+        sets.iter().cloned().reduce(|a, b| a.union(b))
+    );
+    assert_eq!(
+        intersection_all(sets.clone()),
+        sets.iter().cloned().reduce(|a, b| a.intersection(b))
+    );
+}
@@ -116,3 +116,15 @@ fn unbounded_set() {
     let c = (1..3).union(5..10);
     assert_eq!(s.complement(), c);
 }
+
+#[test]
+fn large_set() {
+    let s: BoundedSet<i64> = (0..100_000).map(|i| i * 10..i * 10 + 4).collect();
+    let expected = (0..99_999)
+        .map(|i| i * 10 + 4..i * 10 + 10)
+        .collect::<BoundedSet<_>>()
+        .union(..0)
+        .union(999_994..);
+    assert_eq!(s.clone().complement(), expected);
+    assert_eq!(expected.complement(), s);
+}
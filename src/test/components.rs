@@ -0,0 +1,219 @@
+use crate::{
+    BoundedRange, BoundedSet, Component, LowerBound, LowerBoundedRange, Rangetools as _,
+    UnboundedRange, UnboundedSet, UpperBound, UpperBoundedRange,
+};
+
+#[test]
+fn bounded_set_ranges() {
+    let s = (10..12).union(0..2).union(5..=7);
+    let ranges: Vec<_> = s.ranges().copied().collect();
+    assert_eq!(
+        ranges,
+        vec![
+            BoundedRange::from(0..2),
+            BoundedRange::from(5..=7),
+            BoundedRange::from(10..12)
+        ]
+    );
+    let reversed: Vec<_> = s.ranges().rev().copied().collect();
+    assert_eq!(
+        reversed,
+        vec![
+            BoundedRange::from(10..12),
+            BoundedRange::from(5..=7),
+            BoundedRange::from(0..2)
+        ]
+    );
+    assert_eq!(s.ranges().len(), 3);
+    assert_eq!(BoundedSet::<i32>::empty().ranges().next(), None);
+}
+
+#[test]
+fn bounded_set_gaps() {
+    let s = (10..12).union(0..2).union(5..=7);
+    let gaps: Vec<_> = s.gaps().collect();
+    assert_eq!(
+        gaps,
+        vec![
+            BoundedRange::from(2..5),
+            BoundedRange::new(LowerBound::excluded(7), UpperBound::excluded(10))
+        ]
+    );
+    let reversed: Vec<_> = s.gaps().rev().collect();
+    assert_eq!(reversed, gaps.into_iter().rev().collect::<Vec<_>>());
+
+    let mut gaps = s.gaps();
+    assert_eq!(
+        gaps.next_back(),
+        Some(BoundedRange::new(
+            LowerBound::excluded(7),
+            UpperBound::excluded(10)
+        ))
+    );
+    assert_eq!(gaps.next(), Some(BoundedRange::from(2..5)));
+    assert_eq!(gaps.next(), None);
+    assert_eq!(gaps.next_back(), None);
+
+    assert_eq!((0..5).to_set().gaps().next(), None);
+    assert_eq!(BoundedSet::<i32>::empty().gaps().next(), None);
+}
+
+#[test]
+fn touching_ranges_have_no_gap() {
+    let s = (0..3).union(3..5).union(7..9);
+    assert_eq!(s.gaps().collect::<Vec<_>>(), vec![BoundedRange::from(5..7)]);
+    assert_eq!(
+        s.gaps().rev().collect::<Vec<_>>(),
+        vec![BoundedRange::from(5..7)]
+    );
+}
+
+#[test]
+fn lower_bounded_set() {
+    let s = (0..2).union(5..7).union(10..);
+    assert_eq!(
+        s.ranges().copied().collect::<Vec<_>>(),
+        vec![BoundedRange::from(0..2), BoundedRange::from(5..7)]
+    );
+    assert_eq!(s.lower_bounded_range(), &LowerBoundedRange::from(10..));
+    assert_eq!(
+        s.gaps().collect::<Vec<_>>(),
+        vec![BoundedRange::from(2..5), BoundedRange::from(7..10)]
+    );
+    assert_eq!(
+        s.gaps().rev().collect::<Vec<_>>(),
+        vec![BoundedRange::from(7..10), BoundedRange::from(2..5)]
+    );
+
+    let s2 = (3..).to_set();
+    assert_eq!(s2.ranges().next(), None);
+    assert_eq!(s2.gaps().next(), None);
+}
+
+#[test]
+fn upper_bounded_set() {
+    let s = (..0).union(5..7).union(10..12);
+    assert_eq!(s.upper_bounded_range(), &UpperBoundedRange::from(..0));
+    assert_eq!(
+        s.ranges().copied().collect::<Vec<_>>(),
+        vec![BoundedRange::from(5..7), BoundedRange::from(10..12)]
+    );
+    assert_eq!(
+        s.gaps().collect::<Vec<_>>(),
+        vec![BoundedRange::from(0..5), BoundedRange::from(7..10)]
+    );
+    assert_eq!(
+        s.gaps().rev().collect::<Vec<_>>(),
+        vec![BoundedRange::from(7..10), BoundedRange::from(0..5)]
+    );
+
+    let s2 = (..=3).to_set();
+    assert_eq!(s2.ranges().next(), None);
+    assert_eq!(s2.gaps().next(), None);
+}
+
+#[test]
+fn unbounded_set() {
+    let s = (..0).union(5..7).union(10..);
+    assert_eq!(s.upper_bounded_range(), Some(&UpperBoundedRange::from(..0)));
+    assert_eq!(
+        s.ranges().copied().collect::<Vec<_>>(),
+        vec![BoundedRange::from(5..7)]
+    );
+    assert_eq!(
+        s.lower_bounded_range(),
+        Some(&LowerBoundedRange::from(10..))
+    );
+    assert_eq!(
+        s.gaps().collect::<Vec<_>>(),
+        vec![BoundedRange::from(0..5), BoundedRange::from(7..10)]
+    );
+    assert_eq!(
+        s.gaps().rev().collect::<Vec<_>>(),
+        vec![BoundedRange::from(7..10), BoundedRange::from(0..5)]
+    );
+
+    let s2 = (..0).union(10..);
+    assert_eq!(s2.ranges().next(), None);
+    assert_eq!(
+        s2.gaps().collect::<Vec<_>>(),
+        vec![BoundedRange::from(0..10)]
+    );
+
+    let full = UnboundedSet::<i32>::Full;
+    assert_eq!(full.upper_bounded_range(), None);
+    assert_eq!(full.ranges().next(), None);
+    assert_eq!(full.lower_bounded_range(), None);
+    assert_eq!(full.gaps().next(), None);
+}
+
+#[test]
+fn non_step_elements() {
+    let s = ((0, 0)..(0, 5)).union((1, 0)..(1, 5));
+    assert_eq!(
+        s.ranges().copied().collect::<Vec<_>>(),
+        vec![
+            BoundedRange::from((0, 0)..(0, 5)),
+            BoundedRange::from((1, 0)..(1, 5))
+        ]
+    );
+    assert_eq!(
+        s.gaps().collect::<Vec<_>>(),
+        vec![BoundedRange::from((0, 5)..(1, 0))]
+    );
+}
+
+#[test]
+fn components_include_unbounded_ranges() {
+    let s = (5..7).union(0..2);
+    assert_eq!(
+        s.components().collect::<Vec<_>>(),
+        vec![
+            Component::Bounded(BoundedRange::from(0..2)),
+            Component::Bounded(BoundedRange::from(5..7))
+        ]
+    );
+    assert_eq!(BoundedSet::<i32>::empty().components().next(), None);
+
+    let s = (0..2).union(10..);
+    assert_eq!(
+        s.components().collect::<Vec<_>>(),
+        vec![
+            Component::Bounded(BoundedRange::from(0..2)),
+            Component::LowerBounded(LowerBoundedRange::from(10..))
+        ]
+    );
+
+    let s = (..0).union(5..7);
+    assert_eq!(
+        s.components().rev().collect::<Vec<_>>(),
+        vec![
+            Component::Bounded(BoundedRange::from(5..7)),
+            Component::UpperBounded(UpperBoundedRange::from(..0))
+        ]
+    );
+
+    let s = (..0).union(5..7).union(10..);
+    let mut components = s.components();
+    assert_eq!(components.len(), 3);
+    assert_eq!(
+        components.next(),
+        Some(Component::UpperBounded(UpperBoundedRange::from(..0)))
+    );
+    assert_eq!(
+        components.next_back(),
+        Some(Component::LowerBounded(LowerBoundedRange::from(10..)))
+    );
+    assert_eq!(components.len(), 1);
+    assert_eq!(
+        components.next(),
+        Some(Component::Bounded(BoundedRange::from(5..7)))
+    );
+    assert_eq!(components.next(), None);
+    assert_eq!(components.next_back(), None);
+
+    assert_eq!(
+        UnboundedSet::<i32>::Full.components().collect::<Vec<_>>(),
+        vec![Component::Unbounded(UnboundedRange)]
+    );
+}
@@ -1,15 +1,16 @@
 use crate::{
-    BoundedRange, EmptyRange, LowerBound, LowerBoundedRange, UnboundedRange, UpperBoundedRange,
+    BoundedRange, BoundedSet, EmptyRange, LowerBound, LowerBoundedRange, RangeError,
+    Rangetools as _, UnboundedRange, UnboundedSet, UpperBound, UpperBoundedRange,
 };
 
 #[test]
 fn range() {
     let a: BoundedRange<_> = (0..10).into();
-    let b: std::ops::Range<_> = a.into();
+    let b: std::ops::Range<_> = a.try_into().unwrap();
     assert_eq!(b, 0..10);
 
     let c: BoundedRange<_> = (0..=10).into();
-    let d: std::ops::Range<_> = c.into();
+    let d: std::ops::Range<_> = c.try_into().unwrap();
     assert_eq!(d, 0..11);
 
     let e: EmptyRange<i32> = EmptyRange::new();
@@ -35,33 +36,33 @@ fn range_inclusive() {
 #[test]
 fn range_to() {
     let a: UpperBoundedRange<_> = (..10).into();
-    let b: std::ops::RangeTo<_> = a.into();
+    let b: std::ops::RangeTo<_> = a.try_into().unwrap();
     assert_eq!(b, ..10);
 
     let c: UpperBoundedRange<_> = (..=10).into();
-    let d: std::ops::RangeTo<_> = c.into();
+    let d: std::ops::RangeTo<_> = c.try_into().unwrap();
     assert_eq!(d, ..11);
 }
 
 #[test]
 fn range_to_inclusive() {
     let a: UpperBoundedRange<_> = (..10).into();
-    let b: std::ops::RangeToInclusive<_> = a.into();
+    let b: std::ops::RangeToInclusive<_> = a.try_into().unwrap();
     assert_eq!(b, ..=9);
 
     let c: UpperBoundedRange<_> = (..=10).into();
-    let d: std::ops::RangeToInclusive<_> = c.into();
+    let d: std::ops::RangeToInclusive<_> = c.try_into().unwrap();
     assert_eq!(d, ..=10);
 }
 
 #[test]
 fn range_from() {
     let a: LowerBoundedRange<_> = (10..).into();
-    let b: std::ops::RangeFrom<_> = a.into();
+    let b: std::ops::RangeFrom<_> = a.try_into().unwrap();
     assert_eq!(b, 10..);
 
     let c = LowerBoundedRange::new(LowerBound::excluded(10));
-    let d: std::ops::RangeFrom<_> = c.into();
+    let d: std::ops::RangeFrom<_> = c.try_into().unwrap();
     assert_eq!(d, 11..);
 }
 
@@ -71,3 +72,108 @@ fn range_full() {
     let b: std::ops::RangeFull = a.into();
     assert_eq!(b, ..);
 }
+
+#[test]
+fn overflowing_conversions() {
+    let r = BoundedRange::from(0..=u8::MAX);
+    assert_eq!(std::ops::Range::try_from(r), Err(RangeError::Overflow));
+    assert_eq!(std::ops::RangeInclusive::from(r), 0..=u8::MAX);
+
+    let r = BoundedRange::new(LowerBound::excluded(i8::MAX), UpperBound::included(i8::MAX));
+    assert_eq!(std::ops::Range::try_from(r), Err(RangeError::Overflow));
+
+    let r = UpperBoundedRange::from(..=u32::MAX);
+    assert_eq!(std::ops::RangeTo::try_from(r), Err(RangeError::Overflow));
+    assert_eq!(std::ops::RangeToInclusive::try_from(r), Ok(..=u32::MAX));
+    let r = UpperBoundedRange::from(..0u32);
+    assert_eq!(
+        std::ops::RangeToInclusive::try_from(r),
+        Err(RangeError::Overflow)
+    );
+
+    let r = LowerBoundedRange::new(LowerBound::excluded(u8::MAX));
+    assert_eq!(std::ops::RangeFrom::try_from(r), Err(RangeError::Overflow));
+
+    let r = BoundedRange::from('\u{D7FF}'..='\u{D7FF}');
+    assert_eq!(std::ops::Range::try_from(r), Ok('\u{D7FF}'..'\u{E000}'));
+}
+
+#[test]
+fn empty_range_inclusive() {
+    let r: std::ops::RangeInclusive<u32> = EmptyRange::new().into();
+    assert!(r.is_empty());
+    let r: std::ops::RangeInclusive<i8> = EmptyRange::new().into();
+    assert!(r.is_empty());
+}
+
+#[test]
+#[allow(clippy::reversed_empty_ranges)]
+fn empty_bounded_range_inclusive() {
+    // Stepping these bounds inward would overflow, but any empty `RangeInclusive` will do.
+    let r = BoundedRange::new(LowerBound::included(i8::MIN), UpperBound::excluded(i8::MIN));
+    assert!(std::ops::RangeInclusive::from(r).is_empty());
+    let r = BoundedRange::new(LowerBound::excluded(u8::MAX), UpperBound::included(u8::MAX));
+    assert!(std::ops::RangeInclusive::from(r).is_empty());
+    let r = BoundedRange::new(LowerBound::excluded(u8::MAX), UpperBound::excluded(u8::MAX));
+    assert!(std::ops::RangeInclusive::from(r).is_empty());
+    let r = BoundedRange::from(5u8..0);
+    assert!(std::ops::RangeInclusive::from(r).is_empty());
+    let r = BoundedRange::new(LowerBound::excluded(3), UpperBound::excluded(4));
+    assert!(std::ops::RangeInclusive::from(r).is_empty());
+    let r = BoundedRange::new(LowerBound::excluded(i8::MIN), UpperBound::excluded(i8::MAX));
+    assert_eq!(std::ops::RangeInclusive::from(r), i8::MIN + 1..=i8::MAX - 1);
+}
+
+#[test]
+fn set_to_range() {
+    let s = (0..5).union(3..8);
+    assert_eq!(BoundedRange::try_from(s), Ok(BoundedRange::from(0..8)));
+    let s = (0..5).union(6..8);
+    assert_eq!(
+        BoundedRange::try_from(s),
+        Err(RangeError::MultipleComponents)
+    );
+    assert_eq!(
+        BoundedRange::try_from(BoundedSet::<i32>::empty()),
+        Err(RangeError::Empty)
+    );
+
+    let s = (0..5).union(3..);
+    assert_eq!(
+        LowerBoundedRange::try_from(s),
+        Ok(LowerBoundedRange::from(0..))
+    );
+    let s = (0..2).union(3..);
+    assert_eq!(
+        LowerBoundedRange::try_from(s),
+        Err(RangeError::MultipleComponents)
+    );
+
+    let s = (..5).union(3..8);
+    assert_eq!(
+        UpperBoundedRange::try_from(s),
+        Ok(UpperBoundedRange::from(..8))
+    );
+    let s = (..2).union(3..8);
+    assert_eq!(
+        UpperBoundedRange::try_from(s),
+        Err(RangeError::MultipleComponents)
+    );
+
+    assert_eq!(
+        UnboundedRange::try_from(UnboundedSet::<i32>::Full),
+        Ok(UnboundedRange)
+    );
+    let s = (..2).union(3..);
+    assert_eq!(
+        UnboundedRange::try_from(s),
+        Err(RangeError::MultipleComponents)
+    );
+}
+
+#[test]
+fn error_display() {
+    assert!(!RangeError::Overflow.to_string().is_empty());
+    let e: Box<dyn std::error::Error> = Box::new(RangeError::Empty);
+    assert!(!e.to_string().is_empty());
+}
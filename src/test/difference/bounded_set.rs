@@ -0,0 +1,275 @@
+use crate::{BoundedSet, EmptyRange, Rangetools as _};
+
+#[test]
+fn range() {
+    let d = (1..4).union(5..8).difference(2..6);
+    assert_eq!(d.clone().into_iter().collect::<Vec<i32>>(), vec![1, 6, 7]);
+    assert!(!d.contains(0));
+    assert!(d.contains(1));
+    assert!(!d.contains(2));
+    assert!(!d.contains(3));
+    assert!(!d.contains(4));
+    assert!(!d.contains(5));
+    assert!(d.contains(6));
+    assert!(d.contains(7));
+    assert!(!d.contains(8));
+    assert!(!d.contains(9));
+    assert!(!d.is_empty());
+}
+
+#[test]
+fn range_from() {
+    let d = (1..4).union(5..8).difference(4..);
+    assert_eq!(d.clone().into_iter().collect::<Vec<i32>>(), vec![1, 2, 3]);
+    assert!(!d.contains(0));
+    assert!(d.contains(1));
+    assert!(d.contains(2));
+    assert!(d.contains(3));
+    assert!(!d.contains(4));
+    assert!(!d.contains(5));
+    assert!(!d.contains(6));
+    assert!(!d.contains(7));
+    assert!(!d.contains(8));
+    assert!(!d.contains(9));
+    assert!(!d.is_empty());
+}
+
+#[test]
+fn range_full() {
+    let d = (1..4).union(5..8).difference(..);
+    assert_eq!(d.into_iter().collect::<Vec<i32>>(), vec![]);
+    assert!(!d.contains(0));
+    assert!(!d.contains(1));
+    assert!(!d.contains(2));
+    assert!(!d.contains(3));
+    assert!(!d.contains(4));
+    assert!(!d.contains(5));
+    assert!(!d.contains(6));
+    assert!(!d.contains(7));
+    assert!(!d.contains(8));
+    assert!(!d.contains(9));
+    assert!(d.is_empty());
+}
+
+#[test]
+fn range_inclusive() {
+    let d = (1..4).union(5..8).difference(2..=5);
+    assert_eq!(d.clone().into_iter().collect::<Vec<i32>>(), vec![1, 6, 7]);
+    assert!(!d.contains(0));
+    assert!(d.contains(1));
+    assert!(!d.contains(2));
+    assert!(!d.contains(3));
+    assert!(!d.contains(4));
+    assert!(!d.contains(5));
+    assert!(d.contains(6));
+    assert!(d.contains(7));
+    assert!(!d.contains(8));
+    assert!(!d.contains(9));
+    assert!(!d.is_empty());
+}
+
+#[test]
+fn range_to() {
+    let d = (1..4).union(5..8).difference(..4);
+    assert_eq!(d.clone().into_iter().collect::<Vec<i32>>(), vec![5, 6, 7]);
+    assert!(!d.contains(0));
+    assert!(!d.contains(1));
+    assert!(!d.contains(2));
+    assert!(!d.contains(3));
+    assert!(!d.contains(4));
+    assert!(d.contains(5));
+    assert!(d.contains(6));
+    assert!(d.contains(7));
+    assert!(!d.contains(8));
+    assert!(!d.contains(9));
+    assert!(!d.is_empty());
+}
+
+#[test]
+fn range_to_inclusive() {
+    let d = (1..4).union(5..8).difference(..=3);
+    assert_eq!(d.clone().into_iter().collect::<Vec<i32>>(), vec![5, 6, 7]);
+    assert!(!d.contains(0));
+    assert!(!d.contains(1));
+    assert!(!d.contains(2));
+    assert!(!d.contains(3));
+    assert!(!d.contains(4));
+    assert!(d.contains(5));
+    assert!(d.contains(6));
+    assert!(d.contains(7));
+    assert!(!d.contains(8));
+    assert!(!d.contains(9));
+    assert!(!d.is_empty());
+}
+
+#[test]
+fn bounded_range() {
+    let d = (1..4).union(5..8).difference((1..8).intersection(2..6));
+    assert_eq!(d.clone().into_iter().collect::<Vec<i32>>(), vec![1, 6, 7]);
+    assert!(!d.contains(0));
+    assert!(d.contains(1));
+    assert!(!d.contains(2));
+    assert!(!d.contains(3));
+    assert!(!d.contains(4));
+    assert!(!d.contains(5));
+    assert!(d.contains(6));
+    assert!(d.contains(7));
+    assert!(!d.contains(8));
+    assert!(!d.contains(9));
+    assert!(!d.is_empty());
+}
+
+#[test]
+fn lower_bounded_range() {
+    let d = (1..4).union(5..8).difference((4..).intersection(3..));
+    assert_eq!(d.clone().into_iter().collect::<Vec<i32>>(), vec![1, 2, 3]);
+    assert!(!d.contains(0));
+    assert!(d.contains(1));
+    assert!(d.contains(2));
+    assert!(d.contains(3));
+    assert!(!d.contains(4));
+    assert!(!d.contains(5));
+    assert!(!d.contains(6));
+    assert!(!d.contains(7));
+    assert!(!d.contains(8));
+    assert!(!d.contains(9));
+    assert!(!d.is_empty());
+}
+
+#[test]
+fn upper_bounded_range() {
+    let d = (1..4).union(5..8).difference((..4).intersection(..6));
+    assert_eq!(d.clone().into_iter().collect::<Vec<i32>>(), vec![5, 6, 7]);
+    assert!(!d.contains(0));
+    assert!(!d.contains(1));
+    assert!(!d.contains(2));
+    assert!(!d.contains(3));
+    assert!(!d.contains(4));
+    assert!(d.contains(5));
+    assert!(d.contains(6));
+    assert!(d.contains(7));
+    assert!(!d.contains(8));
+    assert!(!d.contains(9));
+    assert!(!d.is_empty());
+}
+
+#[test]
+fn unbounded_range() {
+    let d = (1..4).union(5..8).difference((..).intersection(..));
+    assert_eq!(d.into_iter().collect::<Vec<i32>>(), vec![]);
+    assert!(!d.contains(0));
+    assert!(!d.contains(1));
+    assert!(!d.contains(2));
+    assert!(!d.contains(3));
+    assert!(!d.contains(4));
+    assert!(!d.contains(5));
+    assert!(!d.contains(6));
+    assert!(!d.contains(7));
+    assert!(!d.contains(8));
+    assert!(!d.contains(9));
+    assert!(d.is_empty());
+}
+
+#[test]
+fn empty_range() {
+    let d = (1..4).union(5..8).difference(EmptyRange::new());
+    assert_eq!(
+        d.clone().into_iter().collect::<Vec<i32>>(),
+        vec![1, 2, 3, 5, 6, 7]
+    );
+    assert!(!d.contains(0));
+    assert!(d.contains(1));
+    assert!(d.contains(2));
+    assert!(d.contains(3));
+    assert!(!d.contains(4));
+    assert!(d.contains(5));
+    assert!(d.contains(6));
+    assert!(d.contains(7));
+    assert!(!d.contains(8));
+    assert!(!d.contains(9));
+    assert!(!d.is_empty());
+}
+
+#[test]
+fn bounded_set() {
+    let d = (1..4).union(5..8).difference((1..3).union(4..6));
+    assert_eq!(d.clone().into_iter().collect::<Vec<i32>>(), vec![3, 6, 7]);
+    assert!(!d.contains(0));
+    assert!(!d.contains(1));
+    assert!(!d.contains(2));
+    assert!(d.contains(3));
+    assert!(!d.contains(4));
+    assert!(!d.contains(5));
+    assert!(d.contains(6));
+    assert!(d.contains(7));
+    assert!(!d.contains(8));
+    assert!(!d.contains(9));
+    assert!(!d.is_empty());
+}
+
+#[test]
+fn lower_bounded_set() {
+    let d = (1..4).union(5..8).difference((2..3).union(6..));
+    assert_eq!(d.clone().into_iter().collect::<Vec<i32>>(), vec![1, 3, 5]);
+    assert!(!d.contains(0));
+    assert!(d.contains(1));
+    assert!(!d.contains(2));
+    assert!(d.contains(3));
+    assert!(!d.contains(4));
+    assert!(d.contains(5));
+    assert!(!d.contains(6));
+    assert!(!d.contains(7));
+    assert!(!d.contains(8));
+    assert!(!d.contains(9));
+    assert!(!d.is_empty());
+}
+
+#[test]
+fn upper_bounded_set() {
+    let d = (1..4).union(5..8).difference((..2).union(4..6));
+    assert_eq!(
+        d.clone().into_iter().collect::<Vec<i32>>(),
+        vec![2, 3, 6, 7]
+    );
+    assert!(!d.contains(0));
+    assert!(!d.contains(1));
+    assert!(d.contains(2));
+    assert!(d.contains(3));
+    assert!(!d.contains(4));
+    assert!(!d.contains(5));
+    assert!(d.contains(6));
+    assert!(d.contains(7));
+    assert!(!d.contains(8));
+    assert!(!d.contains(9));
+    assert!(!d.is_empty());
+}
+
+#[test]
+fn unbounded_set() {
+    let d = (1..4).union(5..8).difference((..2).union(4..6).union(7..));
+    assert_eq!(d.clone().into_iter().collect::<Vec<i32>>(), vec![2, 3, 6]);
+    assert!(!d.contains(0));
+    assert!(!d.contains(1));
+    assert!(d.contains(2));
+    assert!(d.contains(3));
+    assert!(!d.contains(4));
+    assert!(!d.contains(5));
+    assert!(d.contains(6));
+    assert!(!d.contains(7));
+    assert!(!d.contains(8));
+    assert!(!d.contains(9));
+    assert!(!d.is_empty());
+}
+
+#[test]
+fn many_components() {
+    let a: BoundedSet<i32> = (0..10_000).map(|i| i * 10..i * 10 + 6).collect();
+    let b: BoundedSet<i32> = (0..10_000).map(|i| i * 10 + 4..i * 10 + 8).collect();
+    let expected: BoundedSet<i32> = (0..10_000).map(|i| i * 10..i * 10 + 4).collect();
+    assert_eq!(a.clone().difference(b.clone()), expected);
+    assert_eq!(
+        a.clone().union(100_000..).difference(b.clone().union(..0)),
+        expected.union(100_000..)
+    );
+    assert_eq!(a.clone().difference(a), BoundedSet::empty());
+}
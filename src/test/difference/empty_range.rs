@@ -0,0 +1,256 @@
+use crate::{EmptyRange, Rangetools as _};
+
+#[test]
+fn range() {
+    let d = EmptyRange::<i32>::new().difference(2..6);
+    assert_eq!(d.into_iter().collect::<Vec<i32>>(), vec![]);
+    assert!(!d.contains(0));
+    assert!(!d.contains(1));
+    assert!(!d.contains(2));
+    assert!(!d.contains(3));
+    assert!(!d.contains(4));
+    assert!(!d.contains(5));
+    assert!(!d.contains(6));
+    assert!(!d.contains(7));
+    assert!(!d.contains(8));
+    assert!(!d.contains(9));
+    assert!(d.is_empty());
+}
+
+#[test]
+fn range_from() {
+    let d = EmptyRange::<i32>::new().difference(4..);
+    assert_eq!(d.into_iter().collect::<Vec<i32>>(), vec![]);
+    assert!(!d.contains(0));
+    assert!(!d.contains(1));
+    assert!(!d.contains(2));
+    assert!(!d.contains(3));
+    assert!(!d.contains(4));
+    assert!(!d.contains(5));
+    assert!(!d.contains(6));
+    assert!(!d.contains(7));
+    assert!(!d.contains(8));
+    assert!(!d.contains(9));
+    assert!(d.is_empty());
+}
+
+#[test]
+fn range_full() {
+    let d = EmptyRange::<i32>::new().difference(..);
+    assert_eq!(d.into_iter().collect::<Vec<i32>>(), vec![]);
+    assert!(!d.contains(0));
+    assert!(!d.contains(1));
+    assert!(!d.contains(2));
+    assert!(!d.contains(3));
+    assert!(!d.contains(4));
+    assert!(!d.contains(5));
+    assert!(!d.contains(6));
+    assert!(!d.contains(7));
+    assert!(!d.contains(8));
+    assert!(!d.contains(9));
+    assert!(d.is_empty());
+}
+
+#[test]
+fn range_inclusive() {
+    let d = EmptyRange::<i32>::new().difference(2..=5);
+    assert_eq!(d.into_iter().collect::<Vec<i32>>(), vec![]);
+    assert!(!d.contains(0));
+    assert!(!d.contains(1));
+    assert!(!d.contains(2));
+    assert!(!d.contains(3));
+    assert!(!d.contains(4));
+    assert!(!d.contains(5));
+    assert!(!d.contains(6));
+    assert!(!d.contains(7));
+    assert!(!d.contains(8));
+    assert!(!d.contains(9));
+    assert!(d.is_empty());
+}
+
+#[test]
+fn range_to() {
+    let d = EmptyRange::<i32>::new().difference(..4);
+    assert_eq!(d.into_iter().collect::<Vec<i32>>(), vec![]);
+    assert!(!d.contains(0));
+    assert!(!d.contains(1));
+    assert!(!d.contains(2));
+    assert!(!d.contains(3));
+    assert!(!d.contains(4));
+    assert!(!d.contains(5));
+    assert!(!d.contains(6));
+    assert!(!d.contains(7));
+    assert!(!d.contains(8));
+    assert!(!d.contains(9));
+    assert!(d.is_empty());
+}
+
+#[test]
+fn range_to_inclusive() {
+    let d = EmptyRange::<i32>::new().difference(..=3);
+    assert_eq!(d.into_iter().collect::<Vec<i32>>(), vec![]);
+    assert!(!d.contains(0));
+    assert!(!d.contains(1));
+    assert!(!d.contains(2));
+    assert!(!d.contains(3));
+    assert!(!d.contains(4));
+    assert!(!d.contains(5));
+    assert!(!d.contains(6));
+    assert!(!d.contains(7));
+    assert!(!d.contains(8));
+    assert!(!d.contains(9));
+    assert!(d.is_empty());
+}
+
+#[test]
+fn bounded_range() {
+    let d = EmptyRange::<i32>::new().difference((1..8).intersection(2..6));
+    assert_eq!(d.into_iter().collect::<Vec<i32>>(), vec![]);
+    assert!(!d.contains(0));
+    assert!(!d.contains(1));
+    assert!(!d.contains(2));
+    assert!(!d.contains(3));
+    assert!(!d.contains(4));
+    assert!(!d.contains(5));
+    assert!(!d.contains(6));
+    assert!(!d.contains(7));
+    assert!(!d.contains(8));
+    assert!(!d.contains(9));
+    assert!(d.is_empty());
+}
+
+#[test]
+fn lower_bounded_range() {
+    let d = EmptyRange::<i32>::new().difference((4..).intersection(3..));
+    assert_eq!(d.into_iter().collect::<Vec<i32>>(), vec![]);
+    assert!(!d.contains(0));
+    assert!(!d.contains(1));
+    assert!(!d.contains(2));
+    assert!(!d.contains(3));
+    assert!(!d.contains(4));
+    assert!(!d.contains(5));
+    assert!(!d.contains(6));
+    assert!(!d.contains(7));
+    assert!(!d.contains(8));
+    assert!(!d.contains(9));
+    assert!(d.is_empty());
+}
+
+#[test]
+fn upper_bounded_range() {
+    let d = EmptyRange::<i32>::new().difference((..4).intersection(..6));
+    assert_eq!(d.into_iter().collect::<Vec<i32>>(), vec![]);
+    assert!(!d.contains(0));
+    assert!(!d.contains(1));
+    assert!(!d.contains(2));
+    assert!(!d.contains(3));
+    assert!(!d.contains(4));
+    assert!(!d.contains(5));
+    assert!(!d.contains(6));
+    assert!(!d.contains(7));
+    assert!(!d.contains(8));
+    assert!(!d.contains(9));
+    assert!(d.is_empty());
+}
+
+#[test]
+fn unbounded_range() {
+    let d = EmptyRange::<i32>::new().difference((..).intersection(..));
+    assert_eq!(d.into_iter().collect::<Vec<i32>>(), vec![]);
+    assert!(!d.contains(0));
+    assert!(!d.contains(1));
+    assert!(!d.contains(2));
+    assert!(!d.contains(3));
+    assert!(!d.contains(4));
+    assert!(!d.contains(5));
+    assert!(!d.contains(6));
+    assert!(!d.contains(7));
+    assert!(!d.contains(8));
+    assert!(!d.contains(9));
+    assert!(d.is_empty());
+}
+
+#[test]
+fn empty_range() {
+    let d = EmptyRange::<i32>::new().difference(EmptyRange::<i32>::new());
+    assert_eq!(d.into_iter().collect::<Vec<i32>>(), vec![]);
+    assert!(!d.contains(0));
+    assert!(!d.contains(1));
+    assert!(!d.contains(2));
+    assert!(!d.contains(3));
+    assert!(!d.contains(4));
+    assert!(!d.contains(5));
+    assert!(!d.contains(6));
+    assert!(!d.contains(7));
+    assert!(!d.contains(8));
+    assert!(!d.contains(9));
+    assert!(d.is_empty());
+}
+
+#[test]
+fn bounded_set() {
+    let d = EmptyRange::<i32>::new().difference((1..3).union(4..6));
+    assert_eq!(d.into_iter().collect::<Vec<i32>>(), vec![]);
+    assert!(!d.contains(0));
+    assert!(!d.contains(1));
+    assert!(!d.contains(2));
+    assert!(!d.contains(3));
+    assert!(!d.contains(4));
+    assert!(!d.contains(5));
+    assert!(!d.contains(6));
+    assert!(!d.contains(7));
+    assert!(!d.contains(8));
+    assert!(!d.contains(9));
+    assert!(d.is_empty());
+}
+
+#[test]
+fn lower_bounded_set() {
+    let d = EmptyRange::<i32>::new().difference((2..3).union(6..));
+    assert_eq!(d.into_iter().collect::<Vec<i32>>(), vec![]);
+    assert!(!d.contains(0));
+    assert!(!d.contains(1));
+    assert!(!d.contains(2));
+    assert!(!d.contains(3));
+    assert!(!d.contains(4));
+    assert!(!d.contains(5));
+    assert!(!d.contains(6));
+    assert!(!d.contains(7));
+    assert!(!d.contains(8));
+    assert!(!d.contains(9));
+    assert!(d.is_empty());
+}
+
+#[test]
+fn upper_bounded_set() {
+    let d = EmptyRange::<i32>::new().difference((..2).union(4..6));
+    assert_eq!(d.into_iter().collect::<Vec<i32>>(), vec![]);
+    assert!(!d.contains(0));
+    assert!(!d.contains(1));
+    assert!(!d.contains(2));
+    assert!(!d.contains(3));
+    assert!(!d.contains(4));
+    assert!(!d.contains(5));
+    assert!(!d.contains(6));
+    assert!(!d.contains(7));
+    assert!(!d.contains(8));
+    assert!(!d.contains(9));
+    assert!(d.is_empty());
+}
+
+#[test]
+fn unbounded_set() {
+    let d = EmptyRange::<i32>::new().difference((..2).union(4..6).union(7..));
+    assert_eq!(d.into_iter().collect::<Vec<i32>>(), vec![]);
+    assert!(!d.contains(0));
+    assert!(!d.contains(1));
+    assert!(!d.contains(2));
+    assert!(!d.contains(3));
+    assert!(!d.contains(4));
+    assert!(!d.contains(5));
+    assert!(!d.contains(6));
+    assert!(!d.contains(7));
+    assert!(!d.contains(8));
+    assert!(!d.contains(9));
+    assert!(d.is_empty());
+}
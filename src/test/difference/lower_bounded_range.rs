@@ -0,0 +1,285 @@
+use crate::{EmptyRange, Rangetools as _};
+
+#[test]
+fn range() {
+    let d = (2..).intersection(..).difference(2..6);
+    assert_eq!(
+        d.clone().into_iter().take(5).collect::<Vec<_>>(),
+        vec![6, 7, 8, 9, 10]
+    );
+    assert!(!d.contains(0));
+    assert!(!d.contains(1));
+    assert!(!d.contains(2));
+    assert!(!d.contains(3));
+    assert!(!d.contains(4));
+    assert!(!d.contains(5));
+    assert!(d.contains(6));
+    assert!(d.contains(7));
+    assert!(d.contains(8));
+    assert!(d.contains(9));
+    assert!(!d.is_empty());
+}
+
+#[test]
+fn range_from() {
+    let d = (2..).intersection(..).difference(4..);
+    assert_eq!(d.into_iter().collect::<Vec<i32>>(), vec![2, 3]);
+    assert!(!d.contains(0));
+    assert!(!d.contains(1));
+    assert!(d.contains(2));
+    assert!(d.contains(3));
+    assert!(!d.contains(4));
+    assert!(!d.contains(5));
+    assert!(!d.contains(6));
+    assert!(!d.contains(7));
+    assert!(!d.contains(8));
+    assert!(!d.contains(9));
+    assert!(!d.is_empty());
+}
+
+#[test]
+fn range_full() {
+    let d = (2..).intersection(..).difference(..);
+    assert_eq!(d.into_iter().collect::<Vec<i32>>(), vec![]);
+    assert!(!d.contains(0));
+    assert!(!d.contains(1));
+    assert!(!d.contains(2));
+    assert!(!d.contains(3));
+    assert!(!d.contains(4));
+    assert!(!d.contains(5));
+    assert!(!d.contains(6));
+    assert!(!d.contains(7));
+    assert!(!d.contains(8));
+    assert!(!d.contains(9));
+    assert!(d.is_empty());
+}
+
+#[test]
+fn range_inclusive() {
+    let d = (2..).intersection(..).difference(2..=5);
+    assert_eq!(
+        d.clone().into_iter().take(5).collect::<Vec<_>>(),
+        vec![6, 7, 8, 9, 10]
+    );
+    assert!(!d.contains(0));
+    assert!(!d.contains(1));
+    assert!(!d.contains(2));
+    assert!(!d.contains(3));
+    assert!(!d.contains(4));
+    assert!(!d.contains(5));
+    assert!(d.contains(6));
+    assert!(d.contains(7));
+    assert!(d.contains(8));
+    assert!(d.contains(9));
+    assert!(!d.is_empty());
+}
+
+#[test]
+fn range_to() {
+    let d = (2..).intersection(..).difference(..4);
+    assert_eq!(
+        d.into_iter().take(5).collect::<Vec<_>>(),
+        vec![4, 5, 6, 7, 8]
+    );
+    assert!(!d.contains(0));
+    assert!(!d.contains(1));
+    assert!(!d.contains(2));
+    assert!(!d.contains(3));
+    assert!(d.contains(4));
+    assert!(d.contains(5));
+    assert!(d.contains(6));
+    assert!(d.contains(7));
+    assert!(d.contains(8));
+    assert!(d.contains(9));
+    assert!(!d.is_empty());
+}
+
+#[test]
+fn range_to_inclusive() {
+    let d = (2..).intersection(..).difference(..=3);
+    assert_eq!(
+        d.into_iter().take(5).collect::<Vec<_>>(),
+        vec![4, 5, 6, 7, 8]
+    );
+    assert!(!d.contains(0));
+    assert!(!d.contains(1));
+    assert!(!d.contains(2));
+    assert!(!d.contains(3));
+    assert!(d.contains(4));
+    assert!(d.contains(5));
+    assert!(d.contains(6));
+    assert!(d.contains(7));
+    assert!(d.contains(8));
+    assert!(d.contains(9));
+    assert!(!d.is_empty());
+}
+
+#[test]
+fn bounded_range() {
+    let d = (2..).intersection(..).difference((1..8).intersection(2..6));
+    assert_eq!(
+        d.clone().into_iter().take(5).collect::<Vec<_>>(),
+        vec![6, 7, 8, 9, 10]
+    );
+    assert!(!d.contains(0));
+    assert!(!d.contains(1));
+    assert!(!d.contains(2));
+    assert!(!d.contains(3));
+    assert!(!d.contains(4));
+    assert!(!d.contains(5));
+    assert!(d.contains(6));
+    assert!(d.contains(7));
+    assert!(d.contains(8));
+    assert!(d.contains(9));
+    assert!(!d.is_empty());
+}
+
+#[test]
+fn lower_bounded_range() {
+    let d = (2..).intersection(..).difference((4..).intersection(3..));
+    assert_eq!(d.into_iter().collect::<Vec<i32>>(), vec![2, 3]);
+    assert!(!d.contains(0));
+    assert!(!d.contains(1));
+    assert!(d.contains(2));
+    assert!(d.contains(3));
+    assert!(!d.contains(4));
+    assert!(!d.contains(5));
+    assert!(!d.contains(6));
+    assert!(!d.contains(7));
+    assert!(!d.contains(8));
+    assert!(!d.contains(9));
+    assert!(!d.is_empty());
+}
+
+#[test]
+fn upper_bounded_range() {
+    let d = (2..).intersection(..).difference((..4).intersection(..6));
+    assert_eq!(
+        d.into_iter().take(5).collect::<Vec<_>>(),
+        vec![4, 5, 6, 7, 8]
+    );
+    assert!(!d.contains(0));
+    assert!(!d.contains(1));
+    assert!(!d.contains(2));
+    assert!(!d.contains(3));
+    assert!(d.contains(4));
+    assert!(d.contains(5));
+    assert!(d.contains(6));
+    assert!(d.contains(7));
+    assert!(d.contains(8));
+    assert!(d.contains(9));
+    assert!(!d.is_empty());
+}
+
+#[test]
+fn unbounded_range() {
+    let d = (2..).intersection(..).difference((..).intersection(..));
+    assert_eq!(d.into_iter().collect::<Vec<i32>>(), vec![]);
+    assert!(!d.contains(0));
+    assert!(!d.contains(1));
+    assert!(!d.contains(2));
+    assert!(!d.contains(3));
+    assert!(!d.contains(4));
+    assert!(!d.contains(5));
+    assert!(!d.contains(6));
+    assert!(!d.contains(7));
+    assert!(!d.contains(8));
+    assert!(!d.contains(9));
+    assert!(d.is_empty());
+}
+
+#[test]
+fn empty_range() {
+    let d = (2..).intersection(..).difference(EmptyRange::new());
+    assert_eq!(
+        d.into_iter().take(5).collect::<Vec<_>>(),
+        vec![2, 3, 4, 5, 6]
+    );
+    assert!(!d.contains(0));
+    assert!(!d.contains(1));
+    assert!(d.contains(2));
+    assert!(d.contains(3));
+    assert!(d.contains(4));
+    assert!(d.contains(5));
+    assert!(d.contains(6));
+    assert!(d.contains(7));
+    assert!(d.contains(8));
+    assert!(d.contains(9));
+    assert!(!d.is_empty());
+}
+
+#[test]
+fn bounded_set() {
+    let d = (2..).intersection(..).difference((1..3).union(4..6));
+    assert_eq!(
+        d.clone().into_iter().take(5).collect::<Vec<_>>(),
+        vec![3, 6, 7, 8, 9]
+    );
+    assert!(!d.contains(0));
+    assert!(!d.contains(1));
+    assert!(!d.contains(2));
+    assert!(d.contains(3));
+    assert!(!d.contains(4));
+    assert!(!d.contains(5));
+    assert!(d.contains(6));
+    assert!(d.contains(7));
+    assert!(d.contains(8));
+    assert!(d.contains(9));
+    assert!(!d.is_empty());
+}
+
+#[test]
+fn lower_bounded_set() {
+    let d = (2..).intersection(..).difference((2..3).union(6..));
+    assert_eq!(d.clone().into_iter().collect::<Vec<i32>>(), vec![3, 4, 5]);
+    assert!(!d.contains(0));
+    assert!(!d.contains(1));
+    assert!(!d.contains(2));
+    assert!(d.contains(3));
+    assert!(d.contains(4));
+    assert!(d.contains(5));
+    assert!(!d.contains(6));
+    assert!(!d.contains(7));
+    assert!(!d.contains(8));
+    assert!(!d.contains(9));
+    assert!(!d.is_empty());
+}
+
+#[test]
+fn upper_bounded_set() {
+    let d = (2..).intersection(..).difference((..2).union(4..6));
+    assert_eq!(
+        d.clone().into_iter().take(5).collect::<Vec<_>>(),
+        vec![2, 3, 6, 7, 8]
+    );
+    assert!(!d.contains(0));
+    assert!(!d.contains(1));
+    assert!(d.contains(2));
+    assert!(d.contains(3));
+    assert!(!d.contains(4));
+    assert!(!d.contains(5));
+    assert!(d.contains(6));
+    assert!(d.contains(7));
+    assert!(d.contains(8));
+    assert!(d.contains(9));
+    assert!(!d.is_empty());
+}
+
+#[test]
+fn unbounded_set() {
+    let d = (2..)
+        .intersection(..)
+        .difference((..2).union(4..6).union(7..));
+    assert_eq!(d.clone().into_iter().collect::<Vec<i32>>(), vec![2, 3, 6]);
+    assert!(!d.contains(0));
+    assert!(!d.contains(1));
+    assert!(d.contains(2));
+    assert!(d.contains(3));
+    assert!(!d.contains(4));
+    assert!(!d.contains(5));
+    assert!(d.contains(6));
+    assert!(!d.contains(7));
+    assert!(!d.contains(8));
+    assert!(!d.contains(9));
+    assert!(!d.is_empty());
+}
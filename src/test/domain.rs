@@ -0,0 +1,155 @@
+use crate::{
+    AnySet, BoundedRange, BoundedSet, Domain, EmptyRange, LowerBound, LowerBoundedRange,
+    Rangetools as _, UnboundedRange, UnboundedSet, UpperBound, UpperBoundedRange,
+};
+
+macro_rules! full_ranges {
+    ($($t:ident)*) => {
+        $(
+            assert!(($t::MIN..=$t::MAX).to_inner().is_full(), stringify!($t));
+            assert!(($t::MIN..).to_inner().is_full(), stringify!($t));
+            assert!((..=$t::MAX).to_inner().is_full(), stringify!($t));
+            assert!(!($t::MIN..$t::MAX).to_inner().is_full(), stringify!($t));
+            assert!(($t::MIN..).to_inner().complement_in_domain().is_empty(), stringify!($t));
+            assert!((..=$t::MAX).to_inner().complement_in_domain().is_empty(), stringify!($t));
+            assert_eq!(<$t as Domain>::MIN, $t::MIN);
+            assert_eq!(<$t as Domain>::MAX, $t::MAX);
+        )*
+    };
+}
+
+#[test]
+fn full_width_ranges() {
+    full_ranges!(u8 i8 u16 i16 u32 i32 u64 i64 u128 i128 usize isize);
+    assert!((char::MIN..).to_inner().is_full());
+    assert!((..=char::MAX).to_inner().is_full());
+    assert!(!(..char::MAX).to_inner().is_full());
+}
+
+#[test]
+fn excluded_domain_bounds() {
+    let r = LowerBoundedRange::new(LowerBound::excluded(u8::MAX));
+    assert!(!r.is_full());
+    assert!(r.normalize_in_domain().is_empty());
+    assert_eq!(r.complement_in_domain(), (0..=255).to_set());
+
+    let r = UpperBoundedRange::new(UpperBound::excluded(i8::MIN));
+    assert!(r.normalize_in_domain().is_empty());
+    assert!(r.complement_in_domain().is_full());
+
+    let r = BoundedRange::new(LowerBound::excluded(u8::MIN), UpperBound::included(u8::MAX));
+    assert_eq!(r.complement_in_domain(), (0..=0).to_set());
+}
+
+#[test]
+fn unbounded_set_in_domain() {
+    assert_eq!((..=10u8).union(11..).normalize(), UnboundedSet::Full);
+    assert!((..=10u8).union(11..).is_full());
+    assert!(UnboundedSet::<u8>::Full.is_full());
+    assert!(UnboundedSet::<u8>::Full.complement_in_domain().is_empty());
+
+    let s = (..=255u8).union(LowerBoundedRange::new(LowerBound::excluded(255)));
+    assert!(s.is_full());
+    assert_eq!(s.normalize_in_domain(), (0..=255).to_set());
+
+    let s = (..0u8)
+        .union(10..=20)
+        .union(LowerBoundedRange::new(LowerBound::excluded(255)));
+    assert!(!s.is_full());
+    assert_eq!(s.clone().normalize_in_domain(), (10..=20).to_set());
+    assert_eq!(s.complement_in_domain(), (0..=9).union(21..=255));
+}
+
+#[test]
+fn empty_and_unbounded_ranges_in_domain() {
+    let r = EmptyRange::<i8>::new();
+    assert!(!r.is_full());
+    assert_eq!(r.complement_in_domain(), (-128..=127).to_set());
+    assert!(r.normalize_in_domain().is_empty());
+
+    assert!(UnboundedRange.is_full());
+    assert!(UnboundedRange.complement_in_domain::<i8>().is_empty());
+    assert_eq!(
+        UnboundedRange.normalize_in_domain(),
+        (-128i8..=127).to_set()
+    );
+    assert_eq!(
+        UnboundedRange.normalize_in_domain::<char>(),
+        (char::MIN..=char::MAX).to_set()
+    );
+}
+
+#[test]
+fn any_set_in_domain() {
+    let sets: Vec<AnySet<u8>> = vec![
+        EmptyRange::new().into(),
+        (10..20).into(),
+        (0..=255).into(),
+        (0..5).union(10..20).into(),
+        (250..).into(),
+        (0..).into(),
+        (..5).union(250..).into(),
+        (..=10).into(),
+        (..=10).union(20..30).into(),
+        UnboundedRange.into(),
+        (..10).union(20..).into(),
+        (..=10).union(11..).into(),
+    ];
+    for s in sets {
+        let full = (0..=255).all(|x| s.contains(x));
+        assert_eq!(s.is_full(), full, "{:?}", s);
+        let n = s.clone().normalize_in_domain();
+        let c = s.clone().complement_in_domain();
+        for x in 0..=255 {
+            assert_eq!(n.contains(x), s.contains(x), "{:?}", s);
+            assert_ne!(c.contains(x), s.contains(x), "{:?}", s);
+        }
+        assert_eq!(c.clone().complement_in_domain(), n);
+    }
+}
+
+#[test]
+fn half_bounded_sets_in_domain() {
+    let s = (..=10u8).union(11..=255);
+    assert!(s.is_full());
+    assert_eq!(s.normalize_in_domain(), (0..=255).to_set());
+
+    let s = (0u8..=10).union(11..);
+    assert!(s.is_full());
+    assert!(s.complement_in_domain().is_empty());
+
+    let s = (..=10i8).union(20..=30);
+    assert!(!s.is_full());
+    assert_eq!(s.complement_in_domain(), (11..=19).union(31..=127));
+}
+
+#[test]
+fn normalize_in_domain_agrees_across_types() {
+    let a = (200u8..).to_inner().normalize_in_domain();
+    let b = (200u8..=255).to_inner().normalize_in_domain();
+    let c = (..0u8).union(200..).normalize_in_domain();
+    let d = (199u8..=255)
+        .to_inner()
+        .difference(..=199)
+        .normalize_in_domain();
+    assert_eq!(a, b);
+    assert_eq!(a, c);
+    assert_eq!(a, d);
+}
+
+#[test]
+fn complement_in_domain_is_involution() {
+    let sets: Vec<BoundedSet<i8>> = vec![
+        BoundedSet::empty(),
+        (-128..=127).to_set(),
+        (-5..5).union(10..=20),
+        (-128..0).union(100..=127),
+    ];
+    for s in sets {
+        let c = s.clone().complement_in_domain();
+        assert_eq!(c.clone().complement_in_domain(), s.clone().normalize());
+        for x in i8::MIN..=i8::MAX {
+            assert_ne!(s.contains(x), c.contains(x));
+        }
+    }
+}
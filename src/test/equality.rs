@@ -0,0 +1,98 @@
+use crate::{
+    BoundedRange, BoundedSet, Component, EmptyRange, LowerBound, Rangetools as _, UpperBound,
+};
+use std::collections::{HashMap, HashSet};
+
+#[test]
+#[allow(clippy::reversed_empty_ranges)]
+fn empty_bounded_ranges_are_equal() {
+    let a = (5..3).intersection(0..1);
+    let b = (0..3).intersection(10..20);
+    let c = BoundedRange::new(LowerBound::excluded(7), UpperBound::excluded(7));
+    assert!(a.is_empty() && b.is_empty() && c.is_empty());
+    assert_eq!(a, b);
+    assert_eq!(b, c);
+    assert_eq!(BoundedRange::from(4..4), BoundedRange::from(9..=8));
+}
+
+#[test]
+fn bounded_ranges_compare_by_elements() {
+    assert_ne!(BoundedRange::from(0..3), BoundedRange::from(0..=3));
+    assert_ne!(BoundedRange::from(0..3), BoundedRange::from(3..3));
+    assert_eq!(BoundedRange::from(0..3), (0..3).to_inner());
+    assert_eq!((0..3).to_inner(), (0..=2).to_inner());
+    assert_eq!(
+        BoundedRange::new(LowerBound::excluded(-1), UpperBound::included(2)),
+        (0..3).to_inner()
+    );
+    let vacuous = BoundedRange::new(LowerBound::excluded(3), UpperBound::excluded(4));
+    assert!(vacuous.is_empty());
+    assert_eq!(vacuous, BoundedRange::from(3..3));
+    assert_eq!(vacuous, EmptyRange::new());
+    assert_eq!(EmptyRange::new(), vacuous);
+    let at_limit = BoundedRange::new(LowerBound::excluded(u8::MAX), UpperBound::included(u8::MAX));
+    assert_eq!(at_limit, EmptyRange::new());
+}
+
+#[test]
+#[allow(clippy::reversed_empty_ranges)]
+fn bounded_range_and_empty_range() {
+    let empty = EmptyRange::<i32>::new();
+    assert_eq!((5..3).intersection(0..1), empty);
+    assert_eq!(empty, (5..3).intersection(0..1));
+    assert_ne!((0..3).to_inner(), empty);
+    assert_ne!(empty, (0..3).to_inner());
+}
+
+#[test]
+#[allow(clippy::reversed_empty_ranges)]
+fn empty_bounded_ranges_hash_equal() {
+    let mut set = HashSet::new();
+    set.insert((5..3).intersection(0..1));
+    set.insert(BoundedRange::from(4..4));
+    set.insert(BoundedRange::from(i32::MAX..=i32::MIN));
+    assert_eq!(set.len(), 1);
+    set.insert(BoundedRange::new(
+        LowerBound::excluded(3),
+        UpperBound::excluded(4),
+    ));
+    assert_eq!(set.len(), 1);
+    set.insert(BoundedRange::from(0..1));
+    assert_eq!(set.len(), 2);
+    set.insert(BoundedRange::from(0..=0));
+    assert_eq!(set.len(), 2);
+
+    let mut cache = HashMap::new();
+    cache.insert((0..5).intersection(10..20), "empty");
+    assert_eq!(cache.get(&(30..40).intersection(0..1)), Some(&"empty"));
+}
+
+#[test]
+#[allow(clippy::reversed_empty_ranges)]
+fn components_with_empty_ranges() {
+    assert_eq!(
+        Component::Bounded(BoundedRange::from(3..3)),
+        Component::Bounded(BoundedRange::from(5..2))
+    );
+    assert_ne!(
+        Component::Bounded(BoundedRange::from(0..3)),
+        Component::Bounded(BoundedRange::from(0..=3))
+    );
+}
+
+#[test]
+fn equal_sets_agree_on_emptiness() {
+    let vacuous = BoundedRange::new(LowerBound::excluded(3), UpperBound::excluded(4));
+    let a = vacuous.to_set();
+    let b = BoundedSet::empty();
+    assert_eq!(a, b);
+    assert_eq!(a.is_empty(), b.is_empty());
+
+    let a = (..=3).complement().intersection(..4).to_set();
+    assert_eq!(a, b);
+    assert_eq!(a.is_empty(), b.is_empty());
+
+    let a = (0..=3).union(4..6).complement().intersection(0..6);
+    assert_eq!(a, b);
+    assert!(a.is_empty());
+}
@@ -0,0 +1,95 @@
+use crate::{
+    BoundedRange, LowerBound, LowerBoundedRange, RangeError, Rangetools as _, TotalF32, TotalF64,
+    UpperBound, UpperBoundedRange,
+};
+use std::collections::HashSet;
+
+fn f(t: f64) -> TotalF64 {
+    TotalF64::new(t).unwrap()
+}
+
+#[test]
+fn nan_is_rejected() {
+    assert_eq!(TotalF64::new(f64::NAN), None);
+    assert_eq!(TotalF64::new(-f64::NAN), None);
+    assert_eq!(TotalF32::new(f32::NAN), None);
+    assert_eq!(TotalF64::try_from(f64::NAN), Err(RangeError::NotANumber));
+    assert_eq!(TotalF32::try_from(1.0f32).map(f32::from), Ok(1.0));
+
+    let r = (f(f64::NEG_INFINITY)..=f(f64::INFINITY)).to_inner();
+    assert!(!TotalF64::new(f64::NAN).is_some_and(|t| r.contains(t)));
+}
+
+#[test]
+fn signed_zeros() {
+    assert_eq!(f(-0.0), f(0.0));
+    assert!(f(-0.0).get().is_sign_positive());
+    let mut set = HashSet::new();
+    set.insert(f(0.0));
+    assert!(set.contains(&f(-0.0)));
+
+    let r = (f(0.0)..f(1.0)).to_inner();
+    assert!(r.contains(f(-0.0)));
+    let r = (f(-1.0)..f(-0.0)).to_inner();
+    assert!(!r.contains(f(0.0)));
+    let r = BoundedRange::new(LowerBound::excluded(f(-0.0)), UpperBound::included(f(1.0)));
+    assert!(!r.contains(f(0.0)));
+    assert!(r.contains(f(f64::MIN_POSITIVE)));
+}
+
+#[test]
+fn infinite_bounds() {
+    let r = (f(f64::NEG_INFINITY)..=f(f64::INFINITY)).to_inner();
+    assert!(r.contains(f(f64::MAX)));
+    assert!(r.contains(f(f64::INFINITY)));
+    assert!(r.contains(f(f64::NEG_INFINITY)));
+
+    let r = (f(0.0)..f(f64::INFINITY)).to_inner();
+    assert!(r.contains(f(f64::MAX)));
+    assert!(!r.contains(f(f64::INFINITY)));
+
+    let r = LowerBoundedRange::new(LowerBound::excluded(f(f64::INFINITY)));
+    assert!(!r.contains(f(f64::INFINITY)));
+    let r = UpperBoundedRange::from(..=f(f64::NEG_INFINITY));
+    assert!(r.contains(f(f64::NEG_INFINITY)));
+    assert!(!r.contains(f(f64::MIN)));
+
+    let c = (f(f64::NEG_INFINITY)..=f(f64::INFINITY)).complement();
+    assert!(!c.contains(f(f64::INFINITY)));
+    assert!(!c.contains(f(0.0)));
+}
+
+#[test]
+fn set_operations() {
+    let a = (f(0.0)..f(10.0)).to_inner();
+    let b = (f(5.0)..=f(15.0)).to_inner();
+    assert_eq!(a.intersection(b), (f(5.0)..f(10.0)).to_inner());
+    let u = a.union(f(20.0)..);
+    assert!(u.contains(f(9.99)));
+    assert!(!u.contains(f(10.0)));
+    assert!(u.contains(f(1e300)));
+
+    let d = a.difference(f(2.5)..=f(7.5));
+    assert!(d.contains(f(2.0)));
+    assert!(!d.contains(f(7.5)));
+    assert!(d.contains(f(7.6)));
+
+    let s = (f(0.0)..f(1.5)).union(f(2.0)..=f(3.0));
+    assert_eq!(s.measure(), 2.5);
+    let s = (TotalF32::new(0.5).unwrap()..TotalF32::new(2.0).unwrap()).to_inner();
+    assert_eq!(s.measure(), 1.5);
+}
+
+#[test]
+fn ordering() {
+    let mut values: Vec<_> = [3.0, f64::INFINITY, -0.0, -2.0, f64::NEG_INFINITY, 1e-300]
+        .into_iter()
+        .map(f)
+        .collect();
+    values.sort();
+    let values: Vec<f64> = values.into_iter().map(f64::from).collect();
+    assert_eq!(
+        values,
+        vec![f64::NEG_INFINITY, -2.0, 0.0, 1e-300, 3.0, f64::INFINITY]
+    );
+}
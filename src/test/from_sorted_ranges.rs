@@ -0,0 +1,167 @@
+use crate::{
+    BoundedRange, BoundedSet, LowerBound, LowerBoundedSet, RangeError, Rangetools as _,
+    UnboundedSet, UpperBound, UpperBoundedSet,
+};
+
+#[test]
+#[allow(clippy::reversed_empty_ranges)]
+fn bounded_set() {
+    assert_eq!(
+        BoundedSet::from_sorted_ranges(Vec::<std::ops::Range<i32>>::new()),
+        Ok(BoundedSet::empty())
+    );
+    assert_eq!(
+        BoundedSet::from_sorted_ranges([0..2, 3..6, 7..8]),
+        Ok((0..2).union(3..6).union(7..8))
+    );
+    assert_eq!(
+        BoundedSet::from_sorted_ranges([0..2, 5..3]),
+        Err(RangeError::EmptyComponent { index: 1 })
+    );
+    assert_eq!(
+        BoundedSet::from_sorted_ranges([0..2, 5..7, 4..5]),
+        Err(RangeError::Unsorted { index: 2 })
+    );
+    assert_eq!(
+        BoundedSet::from_sorted_ranges([0..2, 0..1]),
+        Err(RangeError::Unsorted { index: 1 })
+    );
+    assert_eq!(
+        BoundedSet::from_sorted_ranges([0..5, 3..7]),
+        Err(RangeError::Overlapping { index: 1 })
+    );
+    assert_eq!(
+        BoundedSet::from_sorted_ranges([0..=2, 2..=7]),
+        Err(RangeError::Overlapping { index: 1 })
+    );
+}
+
+#[test]
+fn bounded_set_touching_bounds() {
+    // Excluded bounds at the same value leave a gap between the ranges.
+    let ranges = [
+        BoundedRange::new(LowerBound::included(0), UpperBound::excluded(2)),
+        BoundedRange::new(LowerBound::excluded(2), UpperBound::included(4)),
+    ];
+    let s = BoundedSet::from_sorted_ranges(ranges).unwrap();
+    assert_eq!(s.ranges().count(), 2);
+    assert_eq!(s, ranges[0].union(ranges[1]));
+
+    // Whereas an included and an excluded bound at the same value touch.
+    assert_eq!(
+        BoundedSet::from_sorted_ranges([0..2, 2..4]),
+        Err(RangeError::Overlapping { index: 1 })
+    );
+}
+
+#[test]
+#[allow(clippy::reversed_empty_ranges)]
+fn lower_bounded_set() {
+    assert_eq!(
+        LowerBoundedSet::from_sorted_ranges(Vec::<std::ops::Range<i32>>::new(), 3..),
+        Ok((3..).to_set())
+    );
+    assert_eq!(
+        LowerBoundedSet::from_sorted_ranges([0..2, 5..7], 8..),
+        Ok((0..2).union(5..7).union(8..))
+    );
+    assert_eq!(
+        LowerBoundedSet::from_sorted_ranges([0..2, 5..7], 7..),
+        Err(RangeError::Overlapping { index: 2 })
+    );
+    assert_eq!(
+        LowerBoundedSet::from_sorted_ranges([0..2, 5..7], 1..),
+        Err(RangeError::Unsorted { index: 2 })
+    );
+    assert_eq!(
+        LowerBoundedSet::from_sorted_ranges([BoundedRange::from(2..0)], 1..),
+        Err(RangeError::EmptyComponent { index: 0 })
+    );
+}
+
+#[test]
+fn upper_bounded_set() {
+    assert_eq!(
+        UpperBoundedSet::from_sorted_ranges(..=3, Vec::<std::ops::Range<i32>>::new()),
+        Ok((..=3).to_set())
+    );
+    assert_eq!(
+        UpperBoundedSet::from_sorted_ranges(..0, [1..2, 5..7]),
+        Ok((..0).union(1..2).union(5..7))
+    );
+    assert_eq!(
+        UpperBoundedSet::from_sorted_ranges(..0, [BoundedRange::from(-1..2)]),
+        Err(RangeError::Overlapping { index: 1 })
+    );
+    assert_eq!(
+        UpperBoundedSet::from_sorted_ranges(..0, [1..2, 3..4, 3..6]),
+        Err(RangeError::Unsorted { index: 3 })
+    );
+}
+
+#[test]
+fn unbounded_set() {
+    assert_eq!(
+        UnboundedSet::from_sorted_ranges(..0, Vec::<std::ops::Range<i32>>::new(), 1..),
+        Ok((..0).union(1..))
+    );
+    assert_eq!(
+        UnboundedSet::from_sorted_ranges(..0, [1..2, 5..7], 10..),
+        Ok((..0).union(1..2).union(5..7).union(10..))
+    );
+    assert_eq!(
+        UnboundedSet::from_sorted_ranges(..0, [BoundedRange::from(0..2)], 10..),
+        Err(RangeError::Overlapping { index: 1 })
+    );
+    assert_eq!(
+        UnboundedSet::from_sorted_ranges(..0, [1..2, 5..7], 6..),
+        Err(RangeError::Overlapping { index: 3 })
+    );
+    assert_eq!(
+        UnboundedSet::from_sorted_ranges(..0, [BoundedRange::from(1..2)], -5..),
+        Err(RangeError::Unsorted { index: 2 })
+    );
+    assert_eq!(
+        UnboundedSet::from_sorted_ranges(..5, [BoundedRange::from(1..2)], 10..),
+        Err(RangeError::Overlapping { index: 1 })
+    );
+}
+
+#[test]
+fn unchecked() {
+    let ranges: Vec<_> = (0..1000).map(|i| i * 10..i * 10 + 4).collect();
+    let checked = BoundedSet::from_sorted_ranges(ranges.clone()).unwrap();
+    assert_eq!(
+        BoundedSet::from_sorted_ranges_unchecked(ranges.clone()),
+        checked
+    );
+    assert_eq!(checked, ranges.iter().cloned().collect());
+    assert_eq!(
+        LowerBoundedSet::from_sorted_ranges_unchecked(ranges.clone(), 10_000..),
+        LowerBoundedSet::from_sorted_ranges(ranges.clone(), 10_000..).unwrap()
+    );
+    assert_eq!(
+        UpperBoundedSet::from_sorted_ranges_unchecked(..-1, ranges.clone()),
+        UpperBoundedSet::from_sorted_ranges(..-1, ranges.clone()).unwrap()
+    );
+    assert_eq!(
+        UnboundedSet::from_sorted_ranges_unchecked(..-1, ranges.clone(), 10_000..),
+        UnboundedSet::from_sorted_ranges(..-1, ranges, 10_000..).unwrap()
+    );
+}
+
+#[test]
+fn error_messages() {
+    assert_eq!(
+        RangeError::EmptyComponent { index: 3 }.to_string(),
+        "component 3 is empty"
+    );
+    assert_eq!(
+        RangeError::Unsorted { index: 1 }.to_string(),
+        "component 1 starts no later than the previous component"
+    );
+    assert_eq!(
+        RangeError::Overlapping { index: 2 }.to_string(),
+        "component 2 overlaps or touches the previous component"
+    );
+}
@@ -1,4 +1,4 @@
-use crate::{EmptyRange, Rangetools as _};
+use crate::{BoundedSet, EmptyRange, Rangetools as _};
 
 #[test]
 fn range() {
@@ -247,3 +247,11 @@ fn unbounded_set() {
     let r = (..1).union(3..4).union(6..);
     assert!(s.intersection(r).is_empty());
 }
+
+#[test]
+fn large_sets() {
+    let a: BoundedSet<i64> = (0..100_000).map(|i| i * 10..i * 10 + 4).collect();
+    let b: BoundedSet<i64> = (0..100_000).map(|i| i * 10 + 2..i * 10 + 7).collect();
+    let expected: BoundedSet<i64> = (0..100_000).map(|i| i * 10 + 2..i * 10 + 4).collect();
+    assert_eq!(a.intersection(b), expected);
+}
@@ -174,6 +174,7 @@ fn empty_range() {
 }
 
 #[test]
+#[allow(clippy::reversed_empty_ranges)]
 fn bounded_set() {
     let s1 = (1..2).union(3..);
     let s2 = (0..3).union(3..5);
@@ -208,6 +209,21 @@ fn lower_bounded_set() {
     assert!(!i.is_empty());
 }
 
+#[test]
+fn lower_bounded_set_overlapping_bounded_ranges() {
+    // The lower-bounded range of the first set overlaps the bounded ranges of the second.
+    let s1 = (2..).to_set();
+    let s2 = (2..4).union(6..);
+    let i = s1.intersection(s2);
+    assert_eq!(
+        i.clone().into_iter().take(4).collect::<Vec<_>>(),
+        vec![2, 3, 6, 7]
+    );
+    assert!(!i.contains(1));
+    assert!(i.contains(3));
+    assert!(!i.contains(5));
+}
+
 #[test]
 fn upper_bounded_set() {
     let s1 = (1..2).union(3..);
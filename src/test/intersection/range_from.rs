@@ -41,6 +41,7 @@ fn range_full() {
 }
 
 #[test]
+#[allow(clippy::reversed_empty_ranges)]
 fn range_inclusive() {
     let i = (3..).intersection(1..=5);
     assert_eq!(i.into_iter().collect::<Vec<_>>(), vec![3, 4, 5]);
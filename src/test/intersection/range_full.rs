@@ -34,6 +34,7 @@ fn range_full() {
 }
 
 #[test]
+#[allow(clippy::reversed_empty_ranges)]
 fn range_inclusive() {
     let i = (..).intersection(1..=3);
     assert_eq!(i.into_iter().collect::<Vec<_>>(), vec![1, 2, 3]);
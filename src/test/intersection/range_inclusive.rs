@@ -31,6 +31,7 @@ fn range_from() {
 }
 
 #[test]
+#[allow(clippy::reversed_empty_ranges)]
 fn range_full() {
     let i = (0..=3).intersection(..);
     assert_eq!(i.into_iter().collect::<Vec<_>>(), vec![0, 1, 2, 3]);
@@ -132,6 +133,7 @@ fn upper_bounded_range() {
 }
 
 #[test]
+#[allow(clippy::reversed_empty_ranges)]
 fn unbounded_range() {
     let r = (..).intersection(..);
     let i = (0..=3).intersection(r);
@@ -37,6 +37,7 @@ fn range_full() {
 }
 
 #[test]
+#[allow(clippy::reversed_empty_ranges)]
 fn range_inclusive() {
     let r = (..).intersection(..);
     let i = r.intersection(1..=3);
@@ -92,6 +92,7 @@ fn last() {
 }
 
 #[test]
+#[allow(clippy::iter_nth_zero)]
 fn nth() {
     let r1 = BoundedRange::new(LowerBound::excluded(5), UpperBound::excluded(10));
     assert_eq!(r1.into_iter().nth(1), Some(7));
@@ -19,6 +19,7 @@ fn count() {
 }
 
 #[test]
+#[allow(clippy::double_ended_iterator_last)]
 fn last() {
     let r = EmptyRange::<i32>::new();
     assert_eq!(r.into_iter().last(), None);
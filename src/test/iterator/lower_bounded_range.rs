@@ -17,7 +17,7 @@ fn next() {
 
 #[test]
 fn size_hint() {
-    let hint = (usize::MAX, None);
+    let hint = (1, None);
     let r1 = LowerBoundedRange::new(LowerBound::excluded(2));
     assert_eq!(r1.into_iter().size_hint(), hint);
 
@@ -15,12 +15,11 @@ fn next() {
 
 #[test]
 fn size_hint() {
-    let hint = (usize::MAX, None);
     let s1: LowerBoundedSet<i32> = (4..).union(BoundedSet::empty());
-    assert_eq!(s1.into_iter().size_hint(), hint);
+    assert_eq!(s1.into_iter().size_hint(), (1, None));
 
     let s2: LowerBoundedSet<i32> = (1..2).union(4..);
-    assert_eq!(s2.into_iter().size_hint(), hint);
+    assert_eq!(s2.into_iter().size_hint(), (2, None));
 }
 
 #[test]
@@ -3,3 +3,4 @@ mod bounded_set;
 mod empty_range;
 mod lower_bounded_range;
 mod lower_bounded_set;
+mod type_limits;
@@ -0,0 +1,201 @@
+use crate::{BoundedRange, LowerBound, LowerBoundedRange, Rangetools as _, UpperBound};
+
+macro_rules! iterate_to_limits {
+    ($($t:ident)*) => {
+        $(
+            let r = ($t::MAX - 3..=$t::MAX).to_inner();
+            assert_eq!(
+                r.into_iter().collect::<Vec<_>>(),
+                vec![$t::MAX - 3, $t::MAX - 2, $t::MAX - 1, $t::MAX],
+                stringify!($t)
+            );
+            let mut i = r.into_iter();
+            assert_eq!(i.nth(3), Some($t::MAX), stringify!($t));
+            assert_eq!(i.next(), None, stringify!($t));
+            assert_eq!(i.next_back(), None, stringify!($t));
+            assert_eq!(r.into_iter().nth(4), None, stringify!($t));
+            assert_eq!(r.into_iter().nth(usize::MAX), None, stringify!($t));
+            assert_eq!(r.into_iter().last(), Some($t::MAX), stringify!($t));
+
+            let r = ($t::MIN..=$t::MIN + 3).to_inner();
+            assert_eq!(
+                r.into_iter().rev().collect::<Vec<_>>(),
+                vec![$t::MIN + 3, $t::MIN + 2, $t::MIN + 1, $t::MIN],
+                stringify!($t)
+            );
+            let mut i = r.into_iter();
+            assert_eq!(i.nth_back(3), Some($t::MIN), stringify!($t));
+            assert_eq!(i.next_back(), None, stringify!($t));
+            assert_eq!(i.next(), None, stringify!($t));
+            assert_eq!(r.into_iter().nth_back(4), None, stringify!($t));
+
+            let r = ($t::MAX..=$t::MAX).to_inner();
+            assert_eq!(r.into_iter().collect::<Vec<_>>(), vec![$t::MAX], stringify!($t));
+            let r = BoundedRange::new(LowerBound::excluded($t::MAX), UpperBound::included($t::MAX));
+            assert_eq!(r.into_iter().next(), None, stringify!($t));
+            let r = BoundedRange::new(LowerBound::included($t::MIN), UpperBound::excluded($t::MIN));
+            assert_eq!(r.into_iter().next_back(), None, stringify!($t));
+
+            let r = ($t::MAX - 2..).to_inner();
+            assert_eq!(
+                r.into_iter().collect::<Vec<_>>(),
+                vec![$t::MAX - 2, $t::MAX - 1, $t::MAX],
+                stringify!($t)
+            );
+            let mut i = r.into_iter();
+            assert_eq!(i.nth(2), Some($t::MAX), stringify!($t));
+            assert_eq!(i.next(), None, stringify!($t));
+            assert_eq!(i.size_hint(), (0, Some(0)), stringify!($t));
+            assert_eq!(r.into_iter().nth(3), None, stringify!($t));
+            let r = LowerBoundedRange::new(LowerBound::excluded($t::MAX));
+            assert_eq!(r.into_iter().next(), None, stringify!($t));
+
+            let s = ($t::MIN..=$t::MIN + 1).union($t::MAX - 1..=$t::MAX);
+            assert_eq!(
+                s.clone().into_iter().collect::<Vec<_>>(),
+                vec![$t::MIN, $t::MIN + 1, $t::MAX - 1, $t::MAX],
+                stringify!($t)
+            );
+            assert_eq!(
+                s.clone().into_iter().rev().collect::<Vec<_>>(),
+                vec![$t::MAX, $t::MAX - 1, $t::MIN + 1, $t::MIN],
+                stringify!($t)
+            );
+            assert_eq!(s.clone().into_iter().nth(3), Some($t::MAX), stringify!($t));
+            assert_eq!(s.into_iter().nth(4), None, stringify!($t));
+
+            let s = ($t::MIN..$t::MIN + 2).union($t::MAX - 1..);
+            assert_eq!(
+                s.clone().into_iter().collect::<Vec<_>>(),
+                vec![$t::MIN, $t::MIN + 1, $t::MAX - 1, $t::MAX],
+                stringify!($t)
+            );
+            assert_eq!(s.into_iter().nth(4), None, stringify!($t));
+        )*
+    };
+}
+
+#[test]
+fn integers() {
+    iterate_to_limits!(u8 i8 u16 i16 u32 i32 u64 i64 u128 i128 usize isize);
+}
+
+#[test]
+fn full_width() {
+    assert_eq!((u8::MIN..=u8::MAX).to_inner().into_iter().count(), 256);
+    assert_eq!((i8::MIN..).to_inner().into_iter().count(), 256);
+    assert_eq!((u16::MIN..=u16::MAX).to_set().into_iter().count(), 1 << 16);
+    assert_eq!((0u16..).to_set().into_iter().count(), 1 << 16);
+}
+
+#[test]
+fn overflowing_size_hint() {
+    let i = (u128::MIN..=u128::MAX).to_inner().into_iter();
+    assert_eq!(i.size_hint(), (usize::MAX, None));
+    let i = (0u128..=usize::MAX as u128 - 1).to_inner().into_iter();
+    assert_eq!(i.size_hint(), (usize::MAX, Some(usize::MAX)));
+    assert_eq!(i.len(), usize::MAX);
+    let i = (0u128..=usize::MAX as u128).to_inner().into_iter();
+    assert_eq!(i.size_hint(), (usize::MAX, None));
+    let mut i = (u128::MIN..=u128::MAX).to_set().into_iter();
+    assert_eq!(i.size_hint(), (usize::MAX, None));
+    assert_eq!(i.nth(5), Some(5));
+    assert_eq!(i.next_back(), Some(u128::MAX));
+    let i = (0u128..5).union(10..=usize::MAX as u128 + 4).into_iter();
+    assert_eq!(i.size_hint(), (usize::MAX, Some(usize::MAX)));
+    let i = (0u128..5).union(10..=usize::MAX as u128 + 5).into_iter();
+    assert_eq!(i.size_hint(), (usize::MAX, None));
+
+    // Skipping a whole range wider than `usize` must not drop it from the iterator.
+    let mut i = (0u128..=1 << 70).union(1 << 80..=1 << 80).into_iter();
+    assert_eq!(i.nth(usize::MAX), Some(usize::MAX as u128));
+    let mut i = (0u128..=1 << 70).union(1 << 80..=1 << 80).into_iter();
+    assert_eq!(
+        i.nth_back(usize::MAX),
+        Some((1 << 70) - (usize::MAX as u128 - 1))
+    );
+}
+
+#[test]
+fn overflowing_count() {
+    assert_eq!((0u128..5).union(10..=1 << 70).count(), Some((1 << 70) - 4));
+    assert_eq!((0u128..5).union(10..15).into_iter().count(), 10);
+    assert_eq!(
+        (0u128..=usize::MAX as u128 - 1)
+            .to_inner()
+            .into_iter()
+            .count(),
+        usize::MAX
+    );
+}
+
+#[test]
+#[should_panic(expected = "element count overflowed a `usize`")]
+fn overflowing_range_count() {
+    (i128::MIN..=i128::MAX).to_inner().into_iter().count();
+}
+
+#[test]
+#[should_panic(expected = "element count overflowed a `usize`")]
+fn overflowing_range_len() {
+    (u128::MIN..=u128::MAX).to_inner().into_iter().len();
+}
+
+#[test]
+#[should_panic(expected = "element count overflowed a `usize`")]
+fn overflowing_set_count() {
+    (0u128..5).union(10..=1 << 70).into_iter().count();
+}
+
+#[test]
+#[should_panic(expected = "element count overflowed a `usize`")]
+fn overflowing_set_len() {
+    (0u128..5).union(10..=u128::MAX).into_iter().len();
+}
+
+#[test]
+fn chars() {
+    let r = ('\u{10FFFD}'..=char::MAX).to_inner();
+    assert_eq!(
+        r.into_iter().collect::<Vec<_>>(),
+        vec!['\u{10FFFD}', '\u{10FFFE}', char::MAX]
+    );
+    let mut i = r.into_iter();
+    assert_eq!(i.nth(2), Some(char::MAX));
+    assert_eq!(i.next(), None);
+    assert_eq!(r.into_iter().nth(3), None);
+
+    let r = (char::MIN..='\u{2}').to_inner();
+    assert_eq!(
+        r.into_iter().rev().collect::<Vec<_>>(),
+        vec!['\u{2}', '\u{1}', char::MIN]
+    );
+    assert_eq!(r.into_iter().nth_back(3), None);
+
+    let r = BoundedRange::new(
+        LowerBound::excluded(char::MAX),
+        UpperBound::included(char::MAX),
+    );
+    assert_eq!(r.into_iter().next(), None);
+    assert_eq!(
+        ('\u{D7FE}'..='\u{E001}')
+            .to_inner()
+            .into_iter()
+            .collect::<Vec<_>>(),
+        vec!['\u{D7FE}', '\u{D7FF}', '\u{E000}', '\u{E001}']
+    );
+
+    let r = ('\u{10FFFE}'..).to_inner();
+    assert_eq!(
+        r.into_iter().collect::<Vec<_>>(),
+        vec!['\u{10FFFE}', char::MAX]
+    );
+    let r = LowerBoundedRange::new(LowerBound::excluded(char::MAX));
+    assert_eq!(r.into_iter().next(), None);
+
+    let s = ('a'..='b').union('\u{10FFFE}'..);
+    assert_eq!(
+        s.into_iter().collect::<Vec<_>>(),
+        vec!['a', 'b', '\u{10FFFE}', char::MAX]
+    );
+}
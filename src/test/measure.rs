@@ -0,0 +1,124 @@
+use crate::{BoundedRange, BoundedSet, EmptyRange, LowerBound, Rangetools as _, UpperBound};
+use std::time::{Duration, Instant};
+
+macro_rules! count_full_width {
+    ($($t:ident)*) => {
+        $(
+            assert_eq!(
+                ($t::MIN..=$t::MAX).to_inner().count(),
+                Some(1u128 << $t::BITS),
+                stringify!($t)
+            );
+            assert_eq!(
+                ($t::MIN..$t::MAX).to_inner().count(),
+                Some((1u128 << $t::BITS) - 1),
+                stringify!($t)
+            );
+        )*
+    };
+}
+
+#[test]
+#[allow(clippy::reversed_empty_ranges)]
+fn count_bounded_range() {
+    assert_eq!((0..10).to_inner().count(), Some(10));
+    assert_eq!((0..=10).to_inner().count(), Some(11));
+    assert_eq!((-5..5).to_inner().count(), Some(10));
+    assert_eq!((3..3).to_inner().count(), Some(0));
+    assert_eq!((3..=2).to_inner().count(), Some(0));
+    assert_eq!((5..2).to_inner().count(), Some(0));
+}
+
+#[test]
+fn count_excluded_bounds() {
+    let r = BoundedRange::new(LowerBound::excluded(3), UpperBound::excluded(4));
+    assert_eq!(r.count(), Some(0));
+    let r = BoundedRange::new(LowerBound::excluded(3), UpperBound::included(4));
+    assert_eq!(r.count(), Some(1));
+    let r = BoundedRange::new(LowerBound::excluded(u8::MAX), UpperBound::included(u8::MAX));
+    assert_eq!(r.count(), Some(0));
+    let r = BoundedRange::new(LowerBound::included(i8::MIN), UpperBound::excluded(i8::MIN));
+    assert_eq!(r.count(), Some(0));
+}
+
+#[test]
+fn count_full_width_ranges() {
+    count_full_width!(u8 i8 u16 i16 u32 i32 u64 i64 usize isize);
+}
+
+#[test]
+fn count_overflowing_ranges() {
+    assert_eq!((u128::MIN..=u128::MAX).to_inner().count(), None);
+    assert_eq!((i128::MIN..=i128::MAX).to_inner().count(), None);
+    assert_eq!((0..=u128::from(u64::MAX)).to_inner().count(), Some(1 << 64));
+}
+
+#[test]
+fn count_ranges_wider_than_usize() {
+    assert_eq!((0u128..1 << 70).to_inner().count(), Some(1 << 70));
+    assert_eq!((u128::MIN..u128::MAX).to_inner().count(), Some(u128::MAX));
+    assert_eq!((i128::MIN..i128::MAX).to_inner().count(), Some(u128::MAX));
+    assert_eq!(
+        (-1i128 << 100..=1 << 100).to_inner().count(),
+        Some((1 << 101) + 1)
+    );
+    assert_eq!((1u128 << 70..=1 << 70).to_inner().count(), Some(1));
+}
+
+#[test]
+fn count_char() {
+    assert_eq!(('a'..='z').to_inner().count(), Some(26));
+    assert_eq!(
+        (char::MIN..=char::MAX).to_inner().count(),
+        Some(char::MAX as u128 + 1 - 0x800)
+    );
+    assert_eq!(('\u{D7FF}'..='\u{E000}').to_inner().count(), Some(2));
+}
+
+#[test]
+fn count_bounded_set() {
+    assert_eq!((0..10).union(20..=25).count(), Some(16));
+    assert_eq!(BoundedSet::<u8>::empty().count(), Some(0));
+    assert_eq!((i64::MIN..0).union(0..=i64::MAX).count(), Some(1u128 << 64));
+    assert_eq!(
+        (u128::MIN..5).union(10..=u128::MAX).count(),
+        Some(u128::MAX - 4)
+    );
+    assert_eq!((u128::MIN..5).union(5..=u128::MAX).count(), None);
+    assert_eq!(
+        (0u128..1 << 70).union(1 << 71..1 << 72).count(),
+        Some(3 << 70)
+    );
+    assert_eq!(EmptyRange::<u8>::new().count(), Some(0));
+}
+
+#[test]
+fn measure_floats() {
+    assert_eq!(BoundedRange::from(0.5..2.0).measure(), 1.5);
+    assert_eq!(BoundedRange::from(0.5f32..=2.0).measure(), 1.5);
+    assert_eq!(BoundedRange::from(2.0..0.5).measure(), 0.0);
+    assert_eq!(BoundedRange::from(1.0..1.0).measure(), 0.0);
+    assert_eq!(EmptyRange::<f32>::new().measure(), 0.0);
+}
+
+#[test]
+fn measure_durations() {
+    let secs = Duration::from_secs;
+    let s = (secs(0)..secs(3))
+        .union(secs(2)..secs(4))
+        .union(secs(10)..=secs(12));
+    assert_eq!(s.measure(), secs(6));
+    assert_eq!(BoundedSet::<Duration>::empty().measure(), Duration::ZERO);
+    assert_eq!(EmptyRange::<Duration>::new().measure(), Duration::ZERO);
+}
+
+#[test]
+fn measure_instants() {
+    let start = Instant::now();
+    let end = start + Duration::from_millis(250);
+    assert_eq!(
+        BoundedRange::from(start..end).measure(),
+        Duration::from_millis(250)
+    );
+    assert_eq!(BoundedRange::from(end..start).measure(), Duration::ZERO);
+}
@@ -1,6 +1,27 @@
+mod any_set;
+mod borrowed;
+mod bounds;
+mod collect;
 mod complement;
+mod components;
 mod conversions;
+mod difference;
+mod domain;
+mod equality;
+mod float;
+mod from_sorted_ranges;
 mod index;
 mod intersection;
 mod iterator;
+mod measure;
+mod mutation;
+mod neighbors;
+mod non_copy;
+mod normalize;
+mod operators;
+mod order_statistics;
+mod query;
+mod range_bounds;
+mod relations;
+mod symmetric_difference;
 mod union;
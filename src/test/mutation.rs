@@ -0,0 +1,283 @@
+use crate::{
+    BoundedRange, BoundedSet, EmptyRange, LowerBound, Rangetools as _, UnboundedSet, UpperBound,
+};
+
+#[test]
+fn bounded_set_insert() {
+    let mut s = BoundedSet::empty();
+    s.insert(5..7);
+    s.insert(0..2);
+    s.insert(1..=3);
+    s.insert(EmptyRange::new());
+    assert_eq!(s, (0..=3).union(5..7));
+    s.insert((10..12).union(14..16));
+    s.insert(6..=14);
+    assert_eq!(s, (0..=3).union(5..16));
+    assert_eq!(
+        s.into_iter().collect::<Vec<_>>(),
+        vec![0, 1, 2, 3, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]
+    );
+}
+
+#[test]
+fn bounded_set_remove() {
+    let mut s = (0..10).union(20..30).to_set();
+    s.remove(5..25);
+    assert_eq!(s, (0..5).union(25..30));
+    s.remove(0..1);
+    assert_eq!(s, (1..5).union(25..30));
+    s.remove(..3);
+    s.remove(28..);
+    assert_eq!(s, (3..5).union(25..28));
+    s.remove((3..4).union(26..27));
+    assert_eq!(s.into_iter().collect::<Vec<_>>(), vec![4, 25, 27]);
+
+    let mut s = (0..10).to_set();
+    s.remove(4..=4);
+    assert!(!s.contains(4));
+    assert!(s.contains(3));
+    assert!(s.contains(5));
+    s.remove(EmptyRange::new());
+    s.remove(7..7);
+    assert_eq!(
+        s.clone().into_iter().collect::<Vec<_>>(),
+        vec![0, 1, 2, 3, 5, 6, 7, 8, 9]
+    );
+    s.remove(-5..15);
+    assert!(s.is_empty());
+}
+
+#[test]
+fn bounded_set_remove_keeps_excluded_endpoints() {
+    let mut s = (0..10).to_set();
+    s.remove(BoundedRange::new(
+        LowerBound::excluded(3),
+        UpperBound::excluded(6),
+    ));
+    assert_eq!(s, (0..=3).union(6..10));
+}
+
+#[test]
+fn bounded_set_retain() {
+    let mut s = (0..2).union(5..10).union(20..21);
+    s.retain(|r| !r.contains(20));
+    assert_eq!(s, (0..2).union(5..10));
+    s.retain(|_| false);
+    assert!(s.is_empty());
+}
+
+#[test]
+fn lower_bounded_set() {
+    let mut s = (10..).to_set();
+    s.insert(0..2);
+    s.insert(8..=10);
+    assert_eq!(s, (0..2).union(8..));
+    s.remove(12..15);
+    assert_eq!(s, (0..2).union(8..12).union(15..));
+    s.remove(..1);
+    assert_eq!(s, (1..2).union(8..12).union(15..));
+    s.remove(5..16);
+    assert_eq!(s, (1..2).union(16..));
+    s.insert(3..);
+    assert_eq!(s, (1..2).union(3..));
+    s.retain(|_| false);
+    assert_eq!(s, (3..).to_set());
+}
+
+#[test]
+fn upper_bounded_set() {
+    let mut s = (..0).to_set();
+    s.insert(10..12);
+    s.insert(-1..3);
+    assert_eq!(s, (..3).union(10..12));
+    s.remove(-5..-3);
+    assert_eq!(s, (..-5).union(-3..3).union(10..12));
+    s.remove(11..);
+    assert_eq!(s, (..-5).union(-3..3).union(10..11));
+    s.remove(-10..0);
+    assert_eq!(s, (..-10).union(0..3).union(10..11));
+    s.insert(..20);
+    assert_eq!(s, (..20).to_set());
+}
+
+#[test]
+fn unbounded_set() {
+    let mut s = UnboundedSet::Full;
+    s.remove(0..10);
+    assert_eq!(s, (..0).union(10..));
+    s.insert(3..5);
+    assert_eq!(s, (..0).union(3..5).union(10..));
+    s.remove(-2..4);
+    assert_eq!(s, (..-2).union(4..5).union(10..));
+    s.remove(9..12);
+    assert_eq!(s, (..-2).union(4..5).union(12..));
+    s.insert(20..);
+    s.insert(..-10);
+    assert_eq!(s, (..-2).union(4..5).union(12..));
+    s.retain(|_| false);
+    assert_eq!(s, (..-2).union(12..));
+    s.insert(-3..=12);
+    assert_eq!(s, UnboundedSet::Full);
+    s.remove(EmptyRange::new());
+    assert_eq!(s, UnboundedSet::Full);
+}
+
+#[test]
+fn matches_difference_and_union() {
+    let base = (..-3).union(0..4).union(6..9).union(12..);
+    let operands = [(-5..1), (2..7), (3..13), (8..8), (-4..-2), (9..12)];
+    for r in operands {
+        let mut removed = base.clone();
+        removed.remove(r.clone());
+        assert_eq!(removed, base.clone().difference(r.clone()));
+
+        let mut inserted = base.clone();
+        inserted.insert(r.clone());
+        assert_eq!(inserted, base.clone().union(r));
+    }
+}
+
+#[test]
+fn matches_difference_and_union_for_each_set_type() {
+    let bounded = (-20..-15)
+        .union(-10..=-5)
+        .union(0..4)
+        .union(6..9)
+        .union(12..20);
+    let lower = bounded.clone().union(25..);
+    let upper = bounded.clone().union(..-25);
+    let unbounded = lower.clone().union(..-25);
+    let operands = [
+        BoundedRange::from(-30..-18),
+        BoundedRange::from(-5..=-5),
+        BoundedRange::new(LowerBound::excluded(-5), UpperBound::excluded(0)),
+        BoundedRange::new(LowerBound::excluded(3), UpperBound::included(6)),
+        BoundedRange::from(4..6),
+        BoundedRange::from(9..12),
+        BoundedRange::from(8..30),
+        BoundedRange::from(-27..-25),
+        BoundedRange::from(30..40),
+        BoundedRange::from(-40..40),
+    ];
+    for r in operands {
+        let mut s = bounded.clone();
+        s.insert(r);
+        assert_eq!(s, bounded.clone().union(r));
+        let mut s = bounded.clone();
+        s.remove(r);
+        assert_eq!(s, bounded.clone().difference(r));
+
+        let mut s = lower.clone();
+        s.insert(r);
+        assert_eq!(s, lower.clone().union(r));
+        let mut s = lower.clone();
+        s.remove(r);
+        assert_eq!(s, lower.clone().difference(r));
+
+        let mut s = upper.clone();
+        s.insert(r);
+        assert_eq!(s, upper.clone().union(r));
+        let mut s = upper.clone();
+        s.remove(r);
+        assert_eq!(s, upper.clone().difference(r));
+
+        let mut s = unbounded.clone();
+        s.insert(r);
+        assert_eq!(s, unbounded.clone().union(r));
+        let mut s = unbounded.clone();
+        s.remove(r);
+        assert_eq!(s, unbounded.clone().difference(r));
+    }
+    for start in [-30, -18, -7, 4, 7, 21, 26] {
+        let mut s = lower.clone();
+        s.insert(start..);
+        assert_eq!(s, lower.clone().union(start..));
+        let mut s = upper.clone();
+        s.remove(start..);
+        assert_eq!(s, upper.clone().difference(start..));
+        let mut s = bounded.clone();
+        s.remove(start..);
+        assert_eq!(s, bounded.clone().difference(start..));
+        let mut s = unbounded.clone();
+        s.insert(start..);
+        assert_eq!(s, unbounded.clone().union(start..));
+
+        let mut s = upper.clone();
+        s.insert(..=start);
+        assert_eq!(s, upper.clone().union(..=start));
+        let mut s = lower.clone();
+        s.remove(..=start);
+        assert_eq!(s, lower.clone().difference(..=start));
+        let mut s = bounded.clone();
+        s.remove(..=start);
+        assert_eq!(s, bounded.clone().difference(..=start));
+        let mut s = unbounded.clone();
+        s.insert(..=start);
+        assert_eq!(s, unbounded.clone().union(..=start));
+    }
+}
+
+#[test]
+fn many_single_range_inserts_and_removes() {
+    let mut s = BoundedSet::empty();
+    for i in (0..20_000).rev() {
+        s.insert(i * 10..i * 10 + 4);
+    }
+    assert_eq!(s.ranges().len(), 20_000);
+    for i in 0..20_000 {
+        s.remove(i * 10 + 1..i * 10 + 3);
+    }
+    assert_eq!(s.ranges().len(), 40_000);
+    for i in 0..20_000 {
+        s.insert(i * 10 + 1..i * 10 + 3);
+    }
+    assert_eq!(s, (0..20_000).map(|i| i * 10..i * 10 + 4).collect());
+
+    let mut s = UnboundedSet::Full;
+    for i in 0..20_000 {
+        s.remove(i * 10..i * 10 + 5);
+    }
+    assert_eq!(s.ranges().len(), 19_999);
+    s.insert(-1..200_000);
+    assert_eq!(s, UnboundedSet::Full);
+}
+
+#[test]
+#[allow(clippy::reversed_empty_ranges)]
+fn add_range_merges_neighbours() {
+    let mut s: BoundedSet<i32> = (0..10).map(|i| i * 10..i * 10 + 5).collect();
+    s.add_range(BoundedRange::from(-3..-1));
+    s.add_range(BoundedRange::from(12..13));
+    s.add_range(BoundedRange::from(25..=30));
+    s.add_range(BoundedRange::from(44..61));
+    s.add_range(BoundedRange::from(95..100));
+    s.add_range(BoundedRange::from(100..=100));
+    s.add_range(BoundedRange::from(7..3));
+    assert_eq!(
+        s,
+        (-3..-1)
+            .union(0..5)
+            .union(10..15)
+            .union(20..35)
+            .union(40..65)
+            .union(70..75)
+            .union(80..85)
+            .union(90..=100)
+    );
+}
+
+#[test]
+fn contains_large_set() {
+    let s: BoundedSet<i64> = (0..100_000).map(|i| i * 10..i * 10 + 4).collect();
+    assert!(s.contains(0));
+    assert!(s.contains(500_003));
+    assert!(!s.contains(500_004));
+    assert!(!s.contains(-1));
+    assert!(s.contains(999_993));
+    assert!(!s.contains(999_994));
+    let u = s.union(..-5).union(1_000_005..);
+    assert!(u.contains(-6));
+    assert!(!u.contains(-5));
+    assert!(u.contains(123_452));
+    assert!(!u.contains(123_456));
+}
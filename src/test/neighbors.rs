@@ -0,0 +1,146 @@
+use crate::{
+    BoundedRange, BoundedSet, Component, LowerBound, LowerBoundedRange, Rangetools as _,
+    UnboundedRange, UnboundedSet, UpperBound, UpperBoundedRange,
+};
+
+macro_rules! check_against_scan {
+    ($s:expr) => {{
+        let s = $s;
+        for x in i8::MIN..=i8::MAX {
+            let at_or_after = (x..=i8::MAX).find(|&y| s.contains(y));
+            let after = (x..=i8::MAX).skip(1).find(|&y| s.contains(y));
+            let at_or_before = (i8::MIN..=x).rev().find(|&y| s.contains(y));
+            let before = (i8::MIN..=x).rev().skip(1).find(|&y| s.contains(y));
+            assert_eq!(
+                s.next_at_or_after(x),
+                at_or_after,
+                "next_at_or_after({})",
+                x
+            );
+            assert_eq!(s.next_after(x), after, "next_after({})", x);
+            assert_eq!(
+                s.prev_at_or_before(x),
+                at_or_before,
+                "prev_at_or_before({})",
+                x
+            );
+            assert_eq!(s.prev_before(x), before, "prev_before({})", x);
+        }
+    }};
+}
+
+fn excluded(start: i8, end: i8) -> BoundedRange<i8> {
+    BoundedRange::new(LowerBound::excluded(start), UpperBound::excluded(end))
+}
+
+#[test]
+fn bounded_set_matches_scan() {
+    check_against_scan!((-100..-90)
+        .union(-5..=5)
+        .union(excluded(10, 20))
+        .union(120..=127));
+    check_against_scan!((-128..=-128).union(excluded(3, 4)).union(excluded(7, 9)));
+    check_against_scan!(BoundedSet::<i8>::empty());
+}
+
+#[test]
+fn lower_bounded_set_matches_scan() {
+    check_against_scan!((-100..-90).union(excluded(3, 4)).union(10..));
+    check_against_scan!(LowerBoundedRange::new(LowerBound::excluded(i8::MAX)).union(0..3));
+    check_against_scan!((i8::MIN..).to_set());
+}
+
+#[test]
+fn upper_bounded_set_matches_scan() {
+    check_against_scan!((..-100).union(excluded(3, 4)).union(10..=20));
+    check_against_scan!(UpperBoundedRange::new(UpperBound::excluded(i8::MIN)).union(0..3));
+    check_against_scan!((..=i8::MAX).to_set());
+}
+
+#[test]
+fn unbounded_set_matches_scan() {
+    check_against_scan!((..-100).union(-5..5).union(excluded(10, 11)).union(50..));
+    check_against_scan!((..i8::MIN)
+        .union(excluded(i8::MAX - 1, i8::MAX))
+        .union(i8::MAX..));
+    check_against_scan!(UnboundedSet::<i8>::Full);
+}
+
+#[test]
+fn domain_extremes() {
+    let s = (..=i32::MIN).union(i32::MAX..);
+    assert_eq!(s.next_after(i32::MIN), Some(i32::MAX));
+    assert_eq!(s.next_after(i32::MAX), None);
+    assert_eq!(s.prev_before(i32::MAX), Some(i32::MIN));
+    assert_eq!(s.prev_before(i32::MIN), None);
+
+    let s = (0..=u64::MAX).to_set();
+    assert_eq!(s.next_at_or_after(u64::MAX), Some(u64::MAX));
+    assert_eq!(s.next_after(u64::MAX), None);
+    assert_eq!(s.prev_before(0), None);
+
+    let s = ('a'..='c').union('x'..='z');
+    assert_eq!(s.next_after('c'), Some('x'));
+    assert_eq!(s.prev_before('x'), Some('c'));
+}
+
+#[test]
+fn bounded_set_range_containing() {
+    let s = (0..5).union(excluded(10, 20)).union(20..=25);
+    assert_eq!(s.range_containing(0), Some(BoundedRange::from(0..5)));
+    assert_eq!(s.range_containing(5), None);
+    assert_eq!(s.range_containing(10), None);
+    let merged = BoundedRange::new(LowerBound::excluded(10), UpperBound::included(25));
+    assert_eq!(s.range_containing(11), Some(merged));
+    assert_eq!(s.range_containing(20), Some(merged));
+    assert_eq!(s.range_containing(26), None);
+    assert_eq!(BoundedSet::<i8>::empty().range_containing(0), None);
+}
+
+#[test]
+fn range_containing_components() {
+    let s = (..0).union(5..10).union(20..);
+    assert_eq!(
+        s.range_containing(-1),
+        Some(Component::UpperBounded(UpperBoundedRange::from(..0)))
+    );
+    assert_eq!(
+        s.range_containing(5),
+        Some(Component::Bounded(BoundedRange::from(5..10)))
+    );
+    assert_eq!(
+        s.range_containing(20),
+        Some(Component::LowerBounded(LowerBoundedRange::from(20..)))
+    );
+    assert_eq!(s.range_containing(15), None);
+    assert_eq!(
+        UnboundedSet::<i8>::Full.range_containing(15),
+        Some(Component::Unbounded(UnboundedRange))
+    );
+
+    let s = (0..5).union(10..);
+    assert_eq!(
+        s.range_containing(12),
+        Some(Component::LowerBounded(LowerBoundedRange::from(10..)))
+    );
+    assert_eq!(s.range_containing(-1), None);
+
+    let s = (..0).union(5..10);
+    assert_eq!(
+        s.range_containing(7),
+        Some(Component::Bounded(BoundedRange::from(5..10)))
+    );
+    assert_eq!(s.range_containing(10), None);
+}
+
+#[test]
+fn range_containing_agrees_with_contains() {
+    let s = (..-100).union(-5..5).union(excluded(10, 11)).union(50..);
+    for x in i8::MIN..=i8::MAX {
+        let component = s.range_containing(x);
+        assert_eq!(component.is_some(), s.contains(x));
+        if let Some(component) = component {
+            assert!(component.contains(x));
+        }
+    }
+}
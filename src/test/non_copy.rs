@@ -0,0 +1,61 @@
+use crate::{BoundedRange, LowerBoundedRange, Rangetools as _, UnboundedSet};
+
+fn s(s: &str) -> String {
+    String::from(s)
+}
+
+#[test]
+fn string_ranges() {
+    let words = [s("apple"), s("d"), s("hello"), s("m"), s("zebra")];
+    let [apple, d, hello, m, zebra] = &words;
+
+    let r = (s("b")..s("m")).intersection(s("d")..);
+    assert_eq!(r, BoundedRange::from(s("d")..s("m")));
+    assert!(r.contains(hello));
+    assert!(r.contains(d));
+    assert!(r.contains(s("d")));
+    assert!(!r.contains(m));
+    assert!(!r.contains(apple));
+
+    let u = (s("a")..s("c")).union(s("x")..);
+    assert!(u.contains(apple));
+    assert!(u.contains(zebra));
+    assert!(!u.contains(m));
+    assert_eq!(u.lower_bounded_range(), &LowerBoundedRange::from(s("x")..));
+    assert_eq!(words.iter().filter(|w| u.contains(*w)).count(), 2);
+}
+
+#[test]
+fn string_sets() {
+    let set = (s("a")..s("f")).union(s("k")..s("p"));
+    let d = set.clone().difference(s("c")..s("m"));
+    assert_eq!(d, (s("a")..s("c")).union(s("m")..s("p")));
+    let (g, n) = (s("g"), s("n"));
+    assert_eq!(
+        d.range_containing(&n),
+        Some(BoundedRange::from(s("m")..s("p")))
+    );
+    assert_eq!(d.range_containing(g), None);
+
+    let c = set.clone().complement();
+    for (word, expected) in [("", true), ("b", false), ("h", true), ("p", true)] {
+        assert_eq!(c.contains(s(word)), expected, "{}", word);
+    }
+    assert!(c.complement().set_eq(set.clone()));
+    assert_eq!(
+        set.symmetric_difference(s("a")..s("p")),
+        (s("f")..s("k")).to_set()
+    );
+}
+
+#[test]
+fn vec_ranges() {
+    let r = (vec![1u8]..=vec![1, 5]).union(vec![2]..vec![3]);
+    let inside = [vec![1, 2, 3], vec![2, 200]];
+    let outside = [vec![1, 6], vec![3]];
+    assert!(inside.iter().all(|v| r.contains(v)));
+    assert!(!outside.iter().any(|v| r.contains(v)));
+    assert!(r.contains(vec![1]));
+    let full = r.clone().union(r.complement());
+    assert_eq!(full, UnboundedSet::Full);
+}
@@ -0,0 +1,202 @@
+use crate::{
+    BoundedRange, BoundedSet, LowerBound, LowerBoundedRange, Rangetools as _, TotalF64,
+    UnboundedSet, UpperBound, UpperBoundedRange,
+};
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+    time::Duration,
+};
+
+fn excluded(start: i8, end: i8) -> BoundedRange<i8> {
+    BoundedRange::new(LowerBound::excluded(start), UpperBound::excluded(end))
+}
+
+fn elements(s: &BoundedSet<i8>) -> Vec<i8> {
+    (i8::MIN..=i8::MAX).filter(|&x| s.contains(x)).collect()
+}
+
+fn hash<T: Hash>(t: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    t.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn touching_ranges_merge() {
+    assert_eq!((0..3).union(3..5), (0..5).to_set());
+    assert_eq!((3..5).union(0..3), (0..5).to_set());
+    assert_eq!(
+        BoundedRange::from(0..=3).union(excluded(3, 5)),
+        BoundedRange::new(LowerBound::included(0), UpperBound::excluded(5)).to_set()
+    );
+    assert_eq!((0..3).union(3..), (0..).to_set());
+    assert_eq!((..3).union(3..5), (..5).to_set());
+    assert_eq!((..3).union(3..), UnboundedSet::Full);
+    assert_eq!(
+        (..0).union(0..5).union(5..10).union(20..),
+        (..10).union(20..)
+    );
+    let secs = Duration::from_secs;
+    assert_eq!(
+        (secs(0)..secs(1)).union(secs(1)..=secs(2)),
+        (secs(0)..=secs(2)).to_set()
+    );
+}
+
+#[test]
+fn ranges_excluding_a_shared_point_stay_separate() {
+    let s = excluded(0, 3).union(excluded(3, 5));
+    assert_eq!(s.ranges().count(), 2);
+    assert!(!s.contains(3));
+    let s = (..3).union(excluded(3, 10));
+    assert!(!s.contains(3));
+    assert_eq!(s.ranges().count(), 1);
+}
+
+#[test]
+#[allow(clippy::reversed_empty_ranges)]
+fn normalize_bounded_range() {
+    assert_eq!(excluded(2, 6).normalize(), Some(BoundedRange::from(3..=5)));
+    assert_eq!(excluded(2, 3).normalize(), None);
+    assert_eq!(BoundedRange::from(5..2).normalize(), None);
+    assert_eq!(
+        BoundedRange::new(LowerBound::excluded(i8::MAX), UpperBound::included(i8::MAX)).normalize(),
+        None
+    );
+    assert_eq!(
+        BoundedRange::from(i8::MIN..i8::MAX).normalize(),
+        Some(BoundedRange::from(i8::MIN..=i8::MAX - 1))
+    );
+}
+
+#[test]
+fn normalize_unbounded_ranges() {
+    assert_eq!(
+        LowerBoundedRange::new(LowerBound::excluded(3)).normalize(),
+        LowerBoundedRange::from(4..)
+    );
+    let r = LowerBoundedRange::new(LowerBound::excluded(u8::MAX));
+    assert_eq!(r.normalize(), r);
+    assert_eq!(
+        UpperBoundedRange::new(UpperBound::excluded(3)).normalize(),
+        UpperBoundedRange::from(..=2)
+    );
+    let r = UpperBoundedRange::new(UpperBound::excluded(u8::MIN));
+    assert_eq!(r.normalize(), r);
+}
+
+#[test]
+fn normalized_equality_matches_elements() {
+    let sets = [
+        (0..=2).union(3..=5),
+        (0..6).to_set(),
+        excluded(-1, 6).to_set(),
+        (0..3).union(excluded(2, 6)),
+        (0..3).union(excluded(3, 6)),
+        (0..3).union(4..6),
+        excluded(10, 11).union(excluded(20, 21)),
+        BoundedSet::empty(),
+        (i8::MIN..=i8::MAX).to_set(),
+        (i8::MIN..0).union(0..=i8::MAX),
+        (i8::MIN..=-1).union(0..=i8::MAX),
+    ];
+    for a in sets.iter() {
+        for b in sets.iter() {
+            assert_eq!(
+                a.clone().normalize() == b.clone().normalize(),
+                elements(a) == elements(b),
+                "{:?} {:?}",
+                a,
+                b
+            );
+        }
+        assert_eq!(elements(&a.clone().normalize()), elements(a));
+    }
+}
+
+#[test]
+fn equality_matches_elements() {
+    let sets = [
+        (0..=2).union(3..=5),
+        (0..6).to_set(),
+        excluded(-1, 6).to_set(),
+        (0..3).union(excluded(3, 6)),
+        (0..3).union(4..6),
+        excluded(10, 11).union(excluded(20, 21)),
+        BoundedSet::empty(),
+        (i8::MIN..0).union(0..=i8::MAX),
+        (i8::MIN..=-1).union(0..=i8::MAX),
+    ];
+    for a in sets.iter() {
+        for b in sets.iter() {
+            assert_eq!(a == b, elements(a) == elements(b), "{:?} {:?}", a, b);
+            if a == b {
+                assert_eq!(hash(a), hash(b), "{:?} {:?}", a, b);
+            }
+        }
+    }
+}
+
+#[test]
+fn equality_of_unbounded_sets() {
+    assert_eq!((0..=2).union(3..=5).union(6..), (0..).to_set());
+    assert_eq!(excluded(0, 1).union(3..), (3..).to_set());
+    assert_eq!((..=2).union(3..=5), (..6).to_set());
+    assert_ne!((..=2).union(4..=5), (..6).to_set());
+    assert_eq!((..=2).union(3..), UnboundedSet::Full);
+    assert_eq!(hash(&(..=2).union(3..)), hash(&UnboundedSet::<i32>::Full));
+    assert_eq!((..=2).union(3..=5).union(7..), (..6).union(7..));
+    assert_ne!((..=2).union(3..=5).union(7..), (..6).union(8..));
+    assert_eq!(('a'..='c').union('d'..='f'), ('a'..'g').to_set());
+}
+
+#[test]
+fn dense_equality_keeps_bounds() {
+    let f = |x| TotalF64::new(x).unwrap();
+    let s = (f(0.0)..=f(2.0)).union(f(3.0)..=f(5.0));
+    assert_ne!(s, (f(0.0)..f(6.0)).to_set());
+    assert_eq!(s, (f(3.0)..=f(5.0)).union(f(0.0)..=f(2.0)));
+    let secs = Duration::from_secs;
+    assert_ne!((secs(0)..=secs(2)).to_set(), (secs(0)..secs(3)).to_set());
+}
+
+#[test]
+fn normalize_half_bounded_sets() {
+    assert_eq!((0..=2).union(3..=5).union(6..).normalize(), (0..).to_set());
+    assert_eq!(excluded(0, 1).union(3..).normalize(), (3..).to_set());
+    assert_eq!((..=2).union(3..=5).normalize(), (..=5).to_set());
+    assert_eq!((..0).union(excluded(4, 5)).normalize(), (..=-1).to_set());
+}
+
+#[test]
+fn normalize_unbounded_sets() {
+    assert_eq!((..=2).union(3..).normalize(), UnboundedSet::Full);
+    assert_eq!(
+        (..=2).union(3..=5).union(7..).normalize(),
+        (..=5).union(7..)
+    );
+    assert_eq!(
+        (..=2).union(3..=5).union(6..).normalize(),
+        UnboundedSet::Full
+    );
+    assert_eq!(
+        (..0).union(excluded(4, 5)).union(10..).normalize(),
+        (..=-1).union(10..)
+    );
+    assert_eq!(UnboundedSet::<i8>::Full.normalize(), UnboundedSet::Full);
+}
+
+#[test]
+fn normalize_chars() {
+    assert_eq!(
+        ('a'..='c').union('d'..='f').normalize(),
+        ('a'..='f').to_set()
+    );
+    assert_eq!(
+        ('\u{D7FF}'..='\u{D7FF}')
+            .union('\u{E000}'..='\u{E000}')
+            .normalize(),
+        ('\u{D7FF}'..='\u{E000}').to_set()
+    );
+}
@@ -0,0 +1,120 @@
+use crate::{EmptyRange, Rangetools as _, UnboundedRange};
+
+#[test]
+fn bounded_range() {
+    let r = (0..10).to_inner();
+    assert_eq!(r & (5..15), r.intersection(5..15));
+    assert_eq!(r | (20..30), r.union(20..30));
+    assert_eq!(r - (3..5), r.difference(3..5));
+    assert_eq!(r ^ (5..15), r.symmetric_difference(5..15));
+    assert_eq!(!r, r.complement());
+
+    let s = (r - (3..5)) | (20..=25);
+    assert_eq!(
+        s.into_iter().collect::<Vec<_>>(),
+        vec![0, 1, 2, 5, 6, 7, 8, 9, 20, 21, 22, 23, 24, 25]
+    );
+}
+
+#[test]
+fn bounded_set() {
+    let s = (0..3).union(5..8);
+    assert_eq!(s.clone() & (2..6), s.clone().intersection(2..6));
+    assert_eq!(s.clone() | (3..5), s.clone().union(3..5));
+    assert_eq!(s.clone() - (1..6), s.clone().difference(1..6));
+    assert_eq!(s.clone() ^ (2..6), s.clone().symmetric_difference(2..6));
+    assert_eq!(!s.clone(), s.clone().complement());
+
+    let s2 = (10..12).union(14..16);
+    assert_eq!(s.clone() | s2.clone(), s.clone().union(s2.clone()));
+    assert_eq!(s.clone() & s2.clone(), s.intersection(s2));
+}
+
+#[test]
+fn empty_range() {
+    let e = EmptyRange::<i32>::new();
+    assert_eq!(e & (0..5), e);
+    assert_eq!(e | (0..5), 0..5);
+    assert_eq!(e - (0..5), e);
+    assert_eq!(e ^ (0..5), 0..5);
+    assert_eq!(!e, UnboundedRange);
+}
+
+#[test]
+fn lower_bounded_range() {
+    let r = (5..).to_inner();
+    assert_eq!(r & (0..10), r.intersection(0..10));
+    assert_eq!(r | (0..2), r.union(0..2));
+    assert_eq!(r - (7..9), r.difference(7..9));
+    assert_eq!(r ^ (..7), r.symmetric_difference(..7));
+    assert_eq!(!r, r.complement());
+
+    let s = r - (7..9);
+    assert_eq!(
+        s.into_iter().take(5).collect::<Vec<_>>(),
+        vec![5, 6, 9, 10, 11]
+    );
+}
+
+#[test]
+fn lower_bounded_set() {
+    let s = (0..3).union(5..);
+    assert_eq!(s.clone() & (2..6), s.clone().intersection(2..6));
+    assert_eq!(s.clone() | (..-5), s.clone().union(..-5));
+    assert_eq!(s.clone() - (6..8), s.clone().difference(6..8));
+    assert_eq!(s.clone() ^ (1..), s.clone().symmetric_difference(1..));
+    assert_eq!(!s.clone(), s.complement());
+}
+
+#[test]
+fn unbounded_range() {
+    let r = UnboundedRange;
+    assert_eq!(r & (0..5), (0..5).to_inner());
+    assert_eq!(r | (0..5), UnboundedRange);
+    assert_eq!(r - (0..5), (0..5).complement());
+    assert_eq!(r ^ (..5), (..5).complement().to_set());
+    assert_eq!(r - EmptyRange::<i32>::new(), UnboundedRange);
+}
+
+#[test]
+fn unbounded_set() {
+    let s = (..0).union(5..);
+    assert_eq!(s.clone() & (-5..10), s.clone().intersection(-5..10));
+    assert_eq!(s.clone() | (1..3), s.clone().union(1..3));
+    assert_eq!(s.clone() - (7..9), s.clone().difference(7..9));
+    assert_eq!(s.clone() ^ (-5..10), s.clone().symmetric_difference(-5..10));
+    assert_eq!(!s.clone(), s.complement());
+}
+
+#[test]
+fn upper_bounded_range() {
+    let r = (..5).to_inner();
+    assert_eq!(r & (0..10), r.intersection(0..10));
+    assert_eq!(r | (7..9), r.union(7..9));
+    assert_eq!(r - (1..3), r.difference(1..3));
+    assert_eq!(r ^ (0..), r.symmetric_difference(0..));
+    assert_eq!(!r, r.complement());
+}
+
+#[test]
+fn upper_bounded_set() {
+    let s = (..0).union(5..8);
+    assert_eq!(s.clone() & (-5..6), s.clone().intersection(-5..6));
+    assert_eq!(s.clone() | (10..), s.clone().union(10..));
+    assert_eq!(s.clone() - (6..7), s.clone().difference(6..7));
+    assert_eq!(s.clone() ^ (..6), s.clone().symmetric_difference(..6));
+    assert_eq!(!s.clone(), s.complement());
+}
+
+#[test]
+fn chained() {
+    let a = (0..10).to_inner();
+    let b = (20..30).to_inner();
+    let c = (5..25).to_inner();
+    let s = (a | b) & !c;
+    assert_eq!(
+        s.into_iter().collect::<Vec<_>>(),
+        vec![0, 1, 2, 3, 4, 25, 26, 27, 28, 29]
+    );
+    assert_eq!((a | b) - c, (a | b) & !c);
+}
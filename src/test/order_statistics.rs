@@ -0,0 +1,163 @@
+use crate::{BoundedRange, BoundedSet, LowerBound, LowerBoundedRange, Rangetools as _, UpperBound};
+
+macro_rules! check_against_scan {
+    ($s:expr) => {{
+        let s = $s;
+        let stats = s.order_statistics();
+        let elements: Vec<i8> = (i8::MIN..=i8::MAX).filter(|&x| s.contains(x)).collect();
+        for x in i8::MIN..=i8::MAX {
+            let rank = elements.iter().filter(|&&y| y < x).count() as u128;
+            assert_eq!(stats.rank(x), Some(rank), "rank({})", x);
+            assert_eq!(s.rank(x), rank, "rank({})", x);
+        }
+        for k in 0..=elements.len() + 1 {
+            let k = k as u128;
+            let expected = elements.get(k as usize).copied();
+            assert_eq!(stats.select(k), expected, "select({})", k);
+            assert_eq!(s.select(k), expected, "select({})", k);
+        }
+    }};
+}
+
+fn excluded(start: i8, end: i8) -> BoundedRange<i8> {
+    BoundedRange::new(LowerBound::excluded(start), UpperBound::excluded(end))
+}
+
+#[test]
+fn bounded_set_matches_scan() {
+    check_against_scan!((-100..-90)
+        .union(-5..=5)
+        .union(excluded(10, 20))
+        .union(120..=127));
+    check_against_scan!((-128..=-128).union(excluded(3, 4)).union(excluded(7, 9)));
+    check_against_scan!(BoundedSet::<i8>::empty());
+}
+
+#[test]
+fn lower_bounded_set_matches_scan() {
+    check_against_scan!((-100..-90).union(excluded(3, 4)).union(10..));
+    check_against_scan!(LowerBoundedRange::new(LowerBound::excluded(i8::MAX)).union(0..3));
+    check_against_scan!((i8::MIN..).to_set());
+}
+
+#[test]
+fn select_inverts_rank() {
+    let s = (0i64..1_000)
+        .union(5_000..=6_000)
+        .union(1 << 40..(1 << 40) + 10);
+    let stats = s.order_statistics();
+    for k in [0, 999, 1_000, 2_000, 2_001, 2_010] {
+        if let Some(x) = stats.select(k) {
+            assert_eq!(stats.rank(x), Some(k));
+        }
+    }
+    assert_eq!(stats.select(2_010), Some((1 << 40) + 9));
+    assert_eq!(stats.select(2_011), None);
+}
+
+#[test]
+fn overflowing_counts() {
+    let s = (u128::MIN..5).union(10..=u128::MAX);
+    let stats = s.order_statistics();
+    assert_eq!(stats.rank(3), Some(3));
+    assert_eq!(stats.rank(20), Some(15));
+    assert_eq!(stats.rank(u128::MAX), Some(u128::MAX - 5));
+    assert_eq!(stats.select(7), Some(12));
+    assert_eq!(stats.select(u128::MAX - 5), Some(u128::MAX));
+    assert_eq!(stats.select(u128::MAX - 4), None);
+
+    let s = (i128::MIN..=i128::MAX).to_set();
+    let stats = s.order_statistics();
+    assert_eq!(stats.rank(i128::MAX), Some(u128::MAX));
+    assert_eq!(stats.select(u128::MAX), Some(i128::MAX));
+    assert_eq!(s.count(), None);
+
+    let s = (i128::MIN..0).union(0..=i128::MAX).union(5..);
+    let stats = s.order_statistics();
+    assert_eq!(stats.rank(i128::MIN), Some(0));
+    assert_eq!(stats.rank(0), Some(1 << 127));
+    assert_eq!(stats.select(3), Some(i128::MIN + 3));
+    assert_eq!(stats.select(1 << 127), Some(0));
+
+    let s = (0u8..).to_set();
+    let stats = s.order_statistics();
+    assert_eq!(stats.rank(u8::MAX), Some(255));
+    assert_eq!(stats.select(255), Some(u8::MAX));
+    assert_eq!(stats.select(256), None);
+}
+
+#[test]
+fn counts_wider_than_usize() {
+    let s = (0u128..1 << 70).to_set();
+    let stats = s.order_statistics();
+    assert_eq!(stats.select(1 << 65), Some(1 << 65));
+    assert_eq!(stats.rank(1 << 69), Some(1 << 69));
+
+    let s = (-1i128 << 100..0).union(1 << 100..);
+    let stats = s.order_statistics();
+    assert_eq!(stats.rank(0), Some(1 << 100));
+    assert_eq!(stats.rank((1 << 100) + 5), Some((1 << 100) + 5));
+    assert_eq!(stats.select((1 << 100) + 5), Some((1 << 100) + 5));
+    assert_eq!(stats.select(1 << 99), Some(-1 << 99));
+}
+
+#[test]
+fn char_sets() {
+    let s = ('a'..='c').union('\u{D7FE}'..='\u{E001}');
+    let stats = s.order_statistics();
+    assert_eq!(stats.rank('\u{E000}'), Some(5));
+    assert_eq!(stats.select(5), Some('\u{E000}'));
+    assert_eq!(stats.select(7), None);
+}
+
+#[test]
+fn set_methods() {
+    let s = (0..10).union(100..110).union(1000..1010);
+    assert_eq!(s.rank(-5), 0);
+    assert_eq!(s.rank(105), 15);
+    assert_eq!(s.rank(5000), 30);
+    assert_eq!(s.select(25), Some(1005));
+    assert_eq!(s.select(30), None);
+
+    let s = (0u128..5).union(10..);
+    assert_eq!(s.rank(u128::MAX), u128::MAX - 5);
+    assert_eq!(s.select(u128::MAX - 5), Some(u128::MAX));
+    assert_eq!(s.select(u128::MAX - 4), None);
+}
+
+#[test]
+fn cached_totals_follow_modifications() {
+    let mut s = (0..10).union(20..30).union(40..50);
+    assert_eq!(s.rank(45), 25);
+    s.insert(10..15);
+    assert_eq!(s.rank(45), 30);
+    s.remove(22..24);
+    assert_eq!(s.rank(45), 28);
+    assert_eq!(s.select(15), Some(20));
+    s.retain(|r| !r.contains(0));
+    assert_eq!(s.rank(45), 13);
+    s.extend([60..70, 0..1]);
+    assert_eq!(s.select(14), Some(45));
+    assert_eq!(s.rank(65), 24);
+    let t = s.clone();
+    s.insert(50..60);
+    assert_eq!(s.rank(65), 34);
+    assert_eq!(t.rank(65), 24);
+    let s = s.difference(62..64);
+    assert_eq!(s.rank(65), 32);
+    let s = s.intersection(..=66);
+    assert_eq!(s.select(32), Some(65));
+    assert_eq!(s.select(34), None);
+
+    let mut s = (0..10).union(20..);
+    assert_eq!(s.rank(25), 15);
+    s.insert(10..15);
+    assert_eq!(s.rank(25), 20);
+    s.remove(5..7);
+    assert_eq!(s.select(10), Some(12));
+    s.insert(16..);
+    assert_eq!(s.rank(25), 22);
+    assert_eq!(s.ranges().count(), 2);
+    s.remove(..2);
+    assert_eq!(s.rank(25), 20);
+}
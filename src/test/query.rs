@@ -0,0 +1,100 @@
+use crate::{
+    AnySet, BoundedRange, Component, EmptyRange, LowerBound, LowerBoundedRange, RangeQuery,
+    Rangetools as _, UnboundedRange, UpperBound, UpperBoundedRange,
+};
+
+/// Checks the `RangeQuery` methods of `r` against the elements of `expected`.
+fn check<R: RangeQuery<i8>>(r: R, expected: AnySet<i8>) {
+    for x in i8::MIN..=i8::MAX {
+        assert_eq!(r.contains(&x), expected.contains(x), "contains({})", x);
+    }
+    assert_eq!(r.lower_bound(), expected.lower_bound());
+    assert_eq!(r.upper_bound(), expected.upper_bound());
+    assert_eq!(
+        r.components().collect::<Vec<_>>(),
+        expected.components().collect::<Vec<_>>()
+    );
+    assert_eq!(r.components().len(), expected.components().count());
+}
+
+#[test]
+#[allow(clippy::reversed_empty_ranges)]
+fn std_ranges() {
+    check(3..7, AnySet::from(BoundedRange::from(3..7)));
+    check(3..=7, AnySet::from(BoundedRange::from(3..=7)));
+    check(3.., AnySet::from(LowerBoundedRange::from(3..)));
+    check(..7, AnySet::from(UpperBoundedRange::from(..7)));
+    check(..=7, AnySet::from(UpperBoundedRange::from(..=7)));
+    check(.., AnySet::from(UnboundedRange));
+    check(7..3, AnySet::from(EmptyRange::new()));
+
+    assert_eq!(
+        RangeQuery::lower_bound(&(3..7)),
+        Some(LowerBound::included(3))
+    );
+    assert_eq!(
+        RangeQuery::upper_bound(&(3..7)),
+        Some(UpperBound::excluded(7))
+    );
+    assert_eq!(RangeQuery::lower_bound(&(7..3)), None);
+    assert_eq!(RangeQuery::upper_bound(&(3..)), None);
+    assert_eq!(RangeQuery::<i8>::components(&(..)).count(), 1);
+}
+
+#[test]
+fn sets() {
+    let s = (-20..-10).union(0..5).union(10..);
+    check(s.clone(), AnySet::from(s.clone()));
+    assert_eq!(RangeQuery::lower_bound(&s), Some(LowerBound::included(-20)));
+    assert_eq!(RangeQuery::upper_bound(&s), None);
+    assert_eq!(
+        s.components().collect::<Vec<_>>(),
+        vec![
+            Component::Bounded(BoundedRange::from(-20..-10)),
+            Component::Bounded(BoundedRange::from(0..5)),
+            Component::LowerBounded(LowerBoundedRange::from(10..)),
+        ]
+    );
+    assert_eq!(
+        s.components().next_back(),
+        Some(Component::LowerBounded(LowerBoundedRange::from(10..)))
+    );
+
+    let s = (..-10).union(0..5).union(10..);
+    check(s.clone(), AnySet::from(s.clone()));
+    assert_eq!(
+        s.components().collect::<Vec<_>>(),
+        vec![
+            Component::UpperBounded(UpperBoundedRange::from(..-10)),
+            Component::Bounded(BoundedRange::from(0..5)),
+            Component::LowerBounded(LowerBoundedRange::from(10..)),
+        ]
+    );
+    let full = (..0).union(0..);
+    assert_eq!(
+        full.components().collect::<Vec<_>>(),
+        vec![Component::Unbounded(UnboundedRange)]
+    );
+
+    let s = (..-10).union(0..5);
+    check(s.clone(), AnySet::from(s.clone()));
+    assert_eq!(RangeQuery::upper_bound(&s), Some(UpperBound::excluded(5)));
+    assert_eq!(RangeQuery::lower_bound(&s), None);
+}
+
+#[test]
+fn components_agree_with_contains() {
+    let sets: Vec<AnySet<i8>> = vec![
+        (..-100).union(-5..5).union(50..).into(),
+        (1..2).union(4..8).into(),
+        (..3).into(),
+        EmptyRange::new().into(),
+    ];
+    for s in sets {
+        let components: Vec<_> = s.components().collect();
+        for x in i8::MIN..=i8::MAX {
+            let hits = components.iter().filter(|c| c.contains(x)).count();
+            assert_eq!(hits, usize::from(s.contains(x)), "{}", x);
+        }
+    }
+}
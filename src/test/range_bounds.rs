@@ -0,0 +1,136 @@
+use crate::{
+    AnySet, Bound, BoundedRange, LowerBound, LowerBoundedRange, RangeError, RangeQuery, Rangetools,
+    UnboundedRange, UpperBound, UpperBoundedRange,
+};
+use std::collections::BTreeSet;
+use std::ops::{Bound as StdBound, RangeBounds};
+
+fn collect<R: RangeBounds<i32>>(r: R) -> Vec<i32> {
+    let set: BTreeSet<i32> = (0..10).collect();
+    set.range(r).copied().collect()
+}
+
+#[test]
+fn range_bounds() {
+    assert_eq!(collect(BoundedRange::from(2..5)), vec![2, 3, 4]);
+    assert_eq!(collect(BoundedRange::from(2..=5)), vec![2, 3, 4, 5]);
+    let r = BoundedRange::new(LowerBound::excluded(2), UpperBound::excluded(5));
+    assert_eq!(collect(r), vec![3, 4]);
+    assert_eq!(collect(LowerBoundedRange::from(7..)), vec![7, 8, 9]);
+    assert_eq!(
+        collect(LowerBoundedRange::new(LowerBound::excluded(7))),
+        vec![8, 9]
+    );
+    assert_eq!(collect(UpperBoundedRange::from(..2)), vec![0, 1]);
+    assert_eq!(collect(UpperBoundedRange::from(..=2)), vec![0, 1, 2]);
+    assert_eq!(collect(UnboundedRange).len(), 10);
+
+    assert_eq!(r.start_bound(), StdBound::Excluded(&2));
+    assert_eq!(r.end_bound(), StdBound::Excluded(&5));
+    assert!(RangeBounds::contains(&r, &3));
+    assert!(!RangeBounds::contains(&r, &2));
+}
+
+#[test]
+fn std_collections() {
+    let mut v: Vec<_> = (0..10).collect();
+    let drained: Vec<_> = v.drain(BoundedRange::from(2..8)).collect();
+    assert_eq!(drained, vec![2, 3, 4, 5, 6, 7]);
+    assert_eq!(v, vec![0, 1, 8, 9]);
+
+    let mut s = String::from("hello world");
+    s.replace_range(LowerBoundedRange::new(LowerBound::excluded(4)), "!");
+    assert_eq!(s, "hello!");
+}
+
+#[test]
+fn bound_conversions() {
+    assert_eq!(StdBound::from(Bound::Included(3)), StdBound::Included(3));
+    assert_eq!(StdBound::from(Bound::Excluded(3)), StdBound::Excluded(3));
+    assert_eq!(
+        StdBound::from(LowerBound::excluded(3)),
+        StdBound::Excluded(3)
+    );
+    assert_eq!(
+        StdBound::from(UpperBound::included(3)),
+        StdBound::Included(3)
+    );
+
+    assert_eq!(
+        Bound::try_from(StdBound::Included(3)),
+        Ok(Bound::Included(3))
+    );
+    assert_eq!(
+        LowerBound::try_from(StdBound::Excluded(3)),
+        Ok(LowerBound::excluded(3))
+    );
+    assert_eq!(
+        UpperBound::try_from(StdBound::Excluded(3)),
+        Ok(UpperBound::excluded(3))
+    );
+    assert_eq!(
+        Bound::<i32>::try_from(StdBound::Unbounded),
+        Err(RangeError::Unbounded)
+    );
+    assert_eq!(
+        LowerBound::<i32>::try_from(StdBound::Unbounded),
+        Err(RangeError::Unbounded)
+    );
+    assert_eq!(
+        UpperBound::<i32>::try_from(StdBound::Unbounded),
+        Err(RangeError::Unbounded)
+    );
+    assert_eq!(RangeError::Unbounded.to_string(), "bound is unbounded");
+}
+
+#[test]
+fn bound_tuples() {
+    let t = (StdBound::Excluded(2), StdBound::Included(8));
+    let r = BoundedRange::new(LowerBound::excluded(2), UpperBound::included(8));
+    assert_eq!(t.to_inner(), AnySet::from(r));
+    assert_eq!(
+        (StdBound::Included(2), StdBound::Unbounded).to_inner(),
+        AnySet::from(LowerBoundedRange::from(2..))
+    );
+    assert_eq!(
+        (StdBound::Unbounded, StdBound::Excluded(2)).to_set(),
+        AnySet::from(UpperBoundedRange::from(..2))
+    );
+    assert_eq!(
+        (StdBound::<i32>::Unbounded, StdBound::Unbounded).to_inner(),
+        AnySet::from(UnboundedRange)
+    );
+    assert!(Rangetools::is_empty(&(
+        StdBound::Excluded(3),
+        StdBound::Excluded(3)
+    )));
+    assert!(!Rangetools::is_empty(&(
+        StdBound::Excluded(2),
+        StdBound::Unbounded
+    )));
+
+    assert_eq!((0..5).intersection(t), AnySet::from(r.intersection(0..5)));
+    assert_eq!(t.intersection(0..5), AnySet::from(r.intersection(0..5)));
+    assert_eq!(t.union(10..), AnySet::from(r.union(10..)));
+    assert_eq!(t.difference(..=5), AnySet::from(r.difference(..=5)));
+    assert_eq!(
+        (0..10).difference(t),
+        AnySet::from((0..=2).union(BoundedRange::new(
+            LowerBound::excluded(8),
+            UpperBound::excluded(10)
+        )))
+    );
+    assert_eq!(
+        t.symmetric_difference(t),
+        AnySet::from(r.symmetric_difference(r))
+    );
+    assert_eq!(t.complement(), AnySet::from(r.complement()));
+
+    assert!(RangeQuery::contains(&t, &8));
+    assert!(!RangeQuery::contains(&t, &2));
+    assert_eq!(RangeQuery::lower_bound(&t), Some(LowerBound::excluded(2)));
+    assert_eq!(RangeQuery::upper_bound(&t), Some(UpperBound::included(8)));
+    assert_eq!(RangeQuery::components(&t).count(), 1);
+    let empty = (StdBound::Excluded(2), StdBound::Excluded(2));
+    assert_eq!(RangeQuery::components(&empty).count(), 0);
+}
@@ -0,0 +1,129 @@
+use crate::{
+    BoundedRange, EmptyRange, LowerBound, Rangetools as _, TotalF64, UnboundedRange, UpperBound,
+};
+
+#[test]
+fn is_subset() {
+    assert!((2..4).is_subset(0..10));
+    assert!((2..4).is_subset(2..4));
+    assert!((2..=3).is_subset(2..4));
+    assert!(!(2..=4).is_subset(2..4));
+    assert!((2..4).is_subset(..));
+    assert!((2..4).is_subset((0..).union(..-5)));
+    assert!(!(2..4).is_subset((3..).union(..-5)));
+    assert!((5..).is_subset(0..));
+    assert!(!(0..).is_subset(5..));
+    assert!(!(0..).is_subset(..5));
+    assert!((..5).is_subset((..0).union(0..5)));
+    assert!((..).is_subset((..5).union(3..)));
+    assert!(!(..).is_subset((..3).union(5..)));
+    assert!((1..2).union(5..6).is_subset(0..10));
+    assert!(!(1..2).union(5..12).is_subset(0..10));
+    assert!((1..2).union(5..).is_subset((0..3).union(4..)));
+    assert!(EmptyRange::<i32>::new().is_subset(0..0));
+    assert!((3..3).is_subset(EmptyRange::new()));
+    assert!(!(3..4).is_subset(EmptyRange::new()));
+    assert!((3..4).is_subset(UnboundedRange));
+}
+
+#[test]
+fn is_superset() {
+    assert!((0..10).is_superset(2..4));
+    assert!(!(0..10).is_superset(5..15));
+    assert!((..).is_superset(3..5));
+    assert!((0..).union(..-5).is_superset(2..4));
+    assert!((0..10).union(20..).is_superset(25..30));
+    assert!((0..10).union(20..).is_superset((1..3).union(21..)));
+    assert!(!(0..10).union(20..).is_superset(..));
+    assert!((0..1).is_superset(EmptyRange::<i32>::new()));
+    assert!(!EmptyRange::<i32>::new().is_superset(0..1));
+}
+
+#[test]
+fn is_proper_subset() {
+    assert!((2..4).is_proper_subset(0..10));
+    assert!((2..4).is_proper_subset(2..=4));
+    assert!(!(2..4).is_proper_subset(2..4));
+    assert!(!(2..4).is_proper_subset(3..10));
+    assert!((5..).is_proper_subset(0..));
+    assert!(!(0..).is_proper_subset(0..));
+    assert!((0..5).union(10..).is_proper_subset(..));
+    assert!(EmptyRange::<i32>::new().is_proper_subset(0..1));
+    assert!(!EmptyRange::<i32>::new().is_proper_subset(EmptyRange::<i32>::new()));
+}
+
+#[test]
+#[allow(clippy::reversed_empty_ranges)]
+fn set_eq() {
+    assert!((0..5).set_eq(0..5));
+    assert!(!(0..5).set_eq(0..6));
+    assert!((0..5).union(3..10).set_eq(0..10));
+    assert!((0..10).set_eq((0..5).union(5..10)));
+    assert!(!(0..10).set_eq((0..5).union(6..10)));
+    assert!((..5).union(3..).set_eq(..));
+    assert!((..5).union(3..).set_eq(UnboundedRange));
+    assert!((0..).set_eq((0..5).union(5..)));
+    assert!((..0).union(5..).set_eq((0..5).complement()));
+    assert!(!(..0).union(5..).set_eq((0..6).complement()));
+    assert!((3..1).set_eq(EmptyRange::new()));
+    assert!(EmptyRange::<i32>::new().set_eq(2..2));
+    assert!(!EmptyRange::<i32>::new().set_eq(2..3));
+}
+
+#[test]
+fn integer_bounds() {
+    assert!((0..3).is_subset(0..=2));
+    assert!((0..=2).is_subset(0..3));
+    assert!((0..3).set_eq(0..=2));
+    assert!((0..=2).set_eq(0..3));
+    assert!(!(0..3).is_proper_subset(0..=2));
+    assert!((0..=2).is_proper_subset(0..=3));
+    assert!((0..3).is_superset(BoundedRange::new(
+        LowerBound::excluded(-1),
+        UpperBound::included(2)
+    )));
+    assert!((0..=2).union(3..=5).set_eq(0..6));
+    assert!((0..=2).union(4..=5).is_subset((0..3).union(4..6)));
+    assert!(!(0..=3).is_subset(0..3));
+    assert!((..=2).union(3..).set_eq(..));
+    assert!((5..).is_subset(BoundedRange::from(0..5).complement()));
+    assert!((2..2).is_subset(BoundedRange::new(
+        LowerBound::excluded(2),
+        UpperBound::excluded(3)
+    )));
+    assert!(
+        BoundedRange::new(LowerBound::excluded(2), UpperBound::excluded(3))
+            .set_eq(EmptyRange::new())
+    );
+    assert!(('a'..'d').set_eq('a'..='c'));
+}
+
+#[test]
+fn step_elements() {
+    assert!((0..3).is_subset(0..=2));
+    assert!((0..3).is_superset(0..=2));
+    assert!(!(0..3).is_proper_subset(0..=2));
+    assert!(('a'..'c').set_eq('a'..='b'));
+    assert!(('a'..'c').is_subset('a'..='b'));
+    assert!((250u8..=255).set_eq(BoundedRange::new(
+        LowerBound::excluded(249),
+        UpperBound::included(255)
+    )));
+    assert!((250u8..=255).is_subset(250u8..));
+    assert!(!(250u8..).is_subset(250u8..=255));
+    assert!((i8::MIN..0).union(0..=i8::MAX).set_eq(i8::MIN..=i8::MAX));
+    assert!(!(0..3).set_eq(0..=3));
+}
+
+#[test]
+fn dense_bounds() {
+    let f = |x| TotalF64::new(x).unwrap();
+    assert!(!(f(0.0)..f(3.0)).is_subset(f(0.0)..=f(2.0)));
+    assert!((f(0.0)..=f(2.0)).is_subset(f(0.0)..f(3.0)));
+    assert!(!(f(0.0)..f(3.0)).set_eq(f(0.0)..=f(2.0)));
+    assert!((f(0.0)..=f(2.0)).is_proper_subset(f(0.0)..f(3.0)));
+    assert!(
+        !BoundedRange::new(LowerBound::excluded(f(2.0)), UpperBound::excluded(f(3.0)))
+            .set_eq(EmptyRange::new())
+    );
+}
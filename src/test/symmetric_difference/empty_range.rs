@@ -0,0 +1,195 @@
+use crate::{EmptyRange, Rangetools as _};
+
+#[test]
+fn range() {
+    let d = EmptyRange::<i32>::new().symmetric_difference(2..6);
+    assert_eq!(d, 2..6);
+}
+
+#[test]
+fn range_from() {
+    let d = EmptyRange::<i32>::new().symmetric_difference(4..);
+    assert_eq!(d, 4..);
+}
+
+#[test]
+fn range_full() {
+    let d = EmptyRange::<i32>::new().symmetric_difference(..);
+    assert_eq!(d, ..);
+}
+
+#[test]
+fn range_inclusive() {
+    let d = EmptyRange::<i32>::new().symmetric_difference(2..=5);
+    assert_eq!(d, 2..=5);
+}
+
+#[test]
+fn range_to() {
+    let d = EmptyRange::<i32>::new().symmetric_difference(..4);
+    assert_eq!(d, ..4);
+}
+
+#[test]
+fn range_to_inclusive() {
+    let d = EmptyRange::<i32>::new().symmetric_difference(..=3);
+    assert_eq!(d, ..=3);
+}
+
+#[test]
+fn bounded_range() {
+    let d = EmptyRange::<i32>::new().symmetric_difference((1..8).intersection(2..6));
+    assert_eq!(d.into_iter().collect::<Vec<i32>>(), vec![2, 3, 4, 5]);
+    assert!(!d.contains(0));
+    assert!(!d.contains(1));
+    assert!(d.contains(2));
+    assert!(d.contains(3));
+    assert!(d.contains(4));
+    assert!(d.contains(5));
+    assert!(!d.contains(6));
+    assert!(!d.contains(7));
+    assert!(!d.contains(8));
+    assert!(!d.contains(9));
+    assert!(!d.is_empty());
+}
+
+#[test]
+fn lower_bounded_range() {
+    let d = EmptyRange::<i32>::new().symmetric_difference((4..).intersection(3..));
+    assert_eq!(
+        d.into_iter().take(5).collect::<Vec<_>>(),
+        vec![4, 5, 6, 7, 8]
+    );
+    assert!(!d.contains(0));
+    assert!(!d.contains(1));
+    assert!(!d.contains(2));
+    assert!(!d.contains(3));
+    assert!(d.contains(4));
+    assert!(d.contains(5));
+    assert!(d.contains(6));
+    assert!(d.contains(7));
+    assert!(d.contains(8));
+    assert!(d.contains(9));
+    assert!(!d.is_empty());
+}
+
+#[test]
+fn upper_bounded_range() {
+    let d = EmptyRange::<i32>::new().symmetric_difference((..4).intersection(..6));
+    assert!(d.contains(0));
+    assert!(d.contains(1));
+    assert!(d.contains(2));
+    assert!(d.contains(3));
+    assert!(!d.contains(4));
+    assert!(!d.contains(5));
+    assert!(!d.contains(6));
+    assert!(!d.contains(7));
+    assert!(!d.contains(8));
+    assert!(!d.contains(9));
+    assert!(!d.is_empty());
+}
+
+#[test]
+fn unbounded_range() {
+    let d = EmptyRange::<i32>::new().symmetric_difference((..).intersection(..));
+    assert!(d.contains(0));
+    assert!(d.contains(1));
+    assert!(d.contains(2));
+    assert!(d.contains(3));
+    assert!(d.contains(4));
+    assert!(d.contains(5));
+    assert!(d.contains(6));
+    assert!(d.contains(7));
+    assert!(d.contains(8));
+    assert!(d.contains(9));
+    assert!(!d.is_empty());
+}
+
+#[test]
+fn empty_range() {
+    let d = EmptyRange::<i32>::new().symmetric_difference(EmptyRange::<i32>::new());
+    assert_eq!(d.into_iter().collect::<Vec<i32>>(), vec![]);
+    assert!(!d.contains(0));
+    assert!(!d.contains(1));
+    assert!(!d.contains(2));
+    assert!(!d.contains(3));
+    assert!(!d.contains(4));
+    assert!(!d.contains(5));
+    assert!(!d.contains(6));
+    assert!(!d.contains(7));
+    assert!(!d.contains(8));
+    assert!(!d.contains(9));
+    assert!(d.is_empty());
+}
+
+#[test]
+fn bounded_set() {
+    let d = EmptyRange::<i32>::new().symmetric_difference((1..3).union(4..6));
+    assert_eq!(
+        d.clone().into_iter().collect::<Vec<i32>>(),
+        vec![1, 2, 4, 5]
+    );
+    assert!(!d.contains(0));
+    assert!(d.contains(1));
+    assert!(d.contains(2));
+    assert!(!d.contains(3));
+    assert!(d.contains(4));
+    assert!(d.contains(5));
+    assert!(!d.contains(6));
+    assert!(!d.contains(7));
+    assert!(!d.contains(8));
+    assert!(!d.contains(9));
+    assert!(!d.is_empty());
+}
+
+#[test]
+fn lower_bounded_set() {
+    let d = EmptyRange::<i32>::new().symmetric_difference((2..3).union(6..));
+    assert_eq!(
+        d.clone().into_iter().take(5).collect::<Vec<_>>(),
+        vec![2, 6, 7, 8, 9]
+    );
+    assert!(!d.contains(0));
+    assert!(!d.contains(1));
+    assert!(d.contains(2));
+    assert!(!d.contains(3));
+    assert!(!d.contains(4));
+    assert!(!d.contains(5));
+    assert!(d.contains(6));
+    assert!(d.contains(7));
+    assert!(d.contains(8));
+    assert!(d.contains(9));
+    assert!(!d.is_empty());
+}
+
+#[test]
+fn upper_bounded_set() {
+    let d = EmptyRange::<i32>::new().symmetric_difference((..2).union(4..6));
+    assert!(d.contains(0));
+    assert!(d.contains(1));
+    assert!(!d.contains(2));
+    assert!(!d.contains(3));
+    assert!(d.contains(4));
+    assert!(d.contains(5));
+    assert!(!d.contains(6));
+    assert!(!d.contains(7));
+    assert!(!d.contains(8));
+    assert!(!d.contains(9));
+    assert!(!d.is_empty());
+}
+
+#[test]
+fn unbounded_set() {
+    let d = EmptyRange::<i32>::new().symmetric_difference((..2).union(4..6).union(7..));
+    assert!(d.contains(0));
+    assert!(d.contains(1));
+    assert!(!d.contains(2));
+    assert!(!d.contains(3));
+    assert!(d.contains(4));
+    assert!(d.contains(5));
+    assert!(!d.contains(6));
+    assert!(d.contains(7));
+    assert!(d.contains(8));
+    assert!(d.contains(9));
+    assert!(!d.is_empty());
+}
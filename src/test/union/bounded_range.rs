@@ -54,6 +54,7 @@ fn range_full() {
 }
 
 #[test]
+#[allow(clippy::reversed_empty_ranges)]
 fn range_inclusive() {
     let r = (1..3).intersection(..);
     let u = r.union(5..=7);
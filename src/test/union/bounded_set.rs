@@ -1,4 +1,4 @@
-use crate::{EmptyRange, Rangetools as _};
+use crate::{BoundedSet, EmptyRange, Rangetools as _};
 
 #[test]
 fn range() {
@@ -49,6 +49,7 @@ fn range_full() {
 }
 
 #[test]
+#[allow(clippy::reversed_empty_ranges)]
 fn range_inclusive() {
     let s = (1..3).union(4..5);
     let u = s.union(4..=6);
@@ -238,3 +239,11 @@ fn unbounded_set() {
     assert!(u.contains(6));
     assert!(!u.is_empty());
 }
+
+#[test]
+fn large_sets() {
+    let a: BoundedSet<i64> = (0..100_000).map(|i| i * 10..i * 10 + 4).collect();
+    let b: BoundedSet<i64> = (0..100_000).map(|i| i * 10 + 2..i * 10 + 7).collect();
+    let expected: BoundedSet<i64> = (0..100_000).map(|i| i * 10..i * 10 + 7).collect();
+    assert_eq!(a.union(b), expected);
+}
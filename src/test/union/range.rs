@@ -53,6 +53,7 @@ fn range_full() {
 }
 
 #[test]
+#[allow(clippy::reversed_empty_ranges)]
 fn range_inclusive() {
     let u = (1..3).union(5..=7);
     assert_eq!(
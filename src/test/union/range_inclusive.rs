@@ -1,6 +1,7 @@
 use crate::{EmptyRange, Rangetools as _};
 
 #[test]
+#[allow(clippy::reversed_empty_ranges)]
 fn range() {
     let u = (0..=2).union(5..7);
     assert_eq!(
@@ -53,6 +54,7 @@ fn range_full() {
 }
 
 #[test]
+#[allow(clippy::reversed_empty_ranges)]
 fn range_inclusive() {
     let u = (1..=2).union(5..=7);
     assert_eq!(
@@ -103,6 +105,7 @@ fn range_to_inclusive() {
 }
 
 #[test]
+#[allow(clippy::reversed_empty_ranges)]
 fn bounded_range() {
     let r = (5..7).intersection(..);
     let u = (1..=2).union(r);
@@ -1,3 +1,4 @@
+use crate::{domain::domain, BoundedSet, Domain, Element, Rangetools};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -29,6 +30,26 @@ impl From<UnboundedRange> for std::ops::RangeFull {
     }
 }
 
+/// Allows an `UnboundedRange` to be used wherever the standard library accepts a range.
+///
+/// # Example
+/// ```
+/// use rangetools::UnboundedRange;
+///
+/// let mut v = vec![1, 2, 3];
+/// assert_eq!(v.drain(UnboundedRange).count(), 3);
+/// assert!(v.is_empty());
+/// ```
+impl<T> std::ops::RangeBounds<T> for UnboundedRange {
+    fn start_bound(&self) -> std::ops::Bound<&T> {
+        std::ops::Bound::Unbounded
+    }
+
+    fn end_bound(&self) -> std::ops::Bound<&T> {
+        std::ops::Bound::Unbounded
+    }
+}
+
 impl UnboundedRange {
     /// Constructs a new `UnboundedRange`.
     ///
@@ -60,4 +81,56 @@ impl UnboundedRange {
     pub fn contains<T>(&self, _: T) -> bool {
         true
     }
+
+    /// Returns the smallest range containing every element of the range, ie the range itself.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::UnboundedRange;
+    ///
+    /// assert_eq!(UnboundedRange.span(), UnboundedRange);
+    /// ```
+    #[doc(alias = "hull")]
+    pub fn span(&self) -> UnboundedRange {
+        *self
+    }
+
+    /// Returns true if the range contains every value of its element type, which is always the
+    /// case.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::UnboundedRange;
+    ///
+    /// assert!(UnboundedRange.is_full());
+    /// ```
+    pub fn is_full(&self) -> bool {
+        true
+    }
+
+    /// Returns the values of `T` not contained in the range, as a normalized set, which is
+    /// always empty.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{BoundedSet, UnboundedRange};
+    ///
+    /// assert_eq!(UnboundedRange.complement_in_domain::<u8>(), BoundedSet::empty());
+    /// ```
+    pub fn complement_in_domain<T: Element + Domain>(self) -> BoundedSet<T> {
+        BoundedSet::empty()
+    }
+
+    /// Returns the range as a normalized set over the values of `T`, ie every value from
+    /// `T::MIN` to `T::MAX`.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{Rangetools, UnboundedRange};
+    ///
+    /// assert_eq!(UnboundedRange.normalize_in_domain(), (0u8..=255).to_set());
+    /// ```
+    pub fn normalize_in_domain<T: Element + Domain>(self) -> BoundedSet<T> {
+        domain().to_set().normalize()
+    }
 }
@@ -1,9 +1,15 @@
 use crate::{
-    BoundedRange, BoundedSet, LowerBound, LowerBoundedRange, RangeIntersection, Rangetools,
-    UnboundedRange, UpperBound, UpperBoundedRange,
+    bounded_set::check_follows, domain::domain, merge, BoundedRange, BoundedSet, Component,
+    Components, Domain, Element, Gaps, LowerBound, LowerBoundedRange, LowerBoundedSet,
+    RangeDifference, RangeError, RangeQuery, RangeUnion, Ranges, Rangetools, Step, UnboundedRange,
+    UpperBound, UpperBoundedRange, UpperBoundedSet,
 };
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+use std::{
+    borrow::Borrow,
+    hash::{Hash, Hasher},
+};
 
 /// A set of ranges with ultimately no upper or lower bound.
 ///
@@ -19,17 +25,127 @@ use serde::{Deserialize, Serialize};
 /// assert!(!piecewise.contains(15));
 /// assert!(piecewise.contains(42));
 /// ```
-#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[derive(Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub struct PiecewiseUnboundedSet<T> {
-    /// Kept private to enforce the invariant that the ranges be non-empty and non-overlapping.
+    /// Kept private to enforce the invariant that the ranges be non-empty, non-overlapping and
+    /// non-touching.
     pub(crate) upper_bounded_range: UpperBoundedRange<T>,
     pub(crate) ranges: BoundedSet<T>,
     pub(crate) lower_bounded_range: LowerBoundedRange<T>,
 }
 
-impl<T: Copy + Ord> PiecewiseUnboundedSet<T> {
-    /// Returns true if the set contains `t`.
+// Compared and hashed in canonical form, so that sets containing the same elements are equal.
+impl<T: Element> PartialEq for PiecewiseUnboundedSet<T> {
+    fn eq(&self, other: &Self) -> bool {
+        merge::canonical(self).eq(merge::canonical(other))
+    }
+}
+
+impl<T: Element> Eq for PiecewiseUnboundedSet<T> {}
+
+impl<T: Element + Hash> Hash for PiecewiseUnboundedSet<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for piece in merge::canonical(self) {
+            piece.hash(state);
+        }
+    }
+}
+
+impl<T> PiecewiseUnboundedSet<T> {
+    /// Returns the leading unbounded component of the set.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{Rangetools, UnboundedSet, UpperBoundedRange};
+    ///
+    /// let s = (..0).union(5..7).union(10..);
+    /// if let UnboundedSet::Piecewise(p) = s {
+    ///     assert_eq!(p.upper_bounded_range(), &UpperBoundedRange::from(..0));
+    /// }
+    /// ```
+    pub fn upper_bounded_range(&self) -> &UpperBoundedRange<T> {
+        &self.upper_bounded_range
+    }
+
+    /// Returns an iterator over the disjoint bounded ranges making up the set, in ascending
+    /// order.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{BoundedRange, Rangetools, UnboundedSet};
+    ///
+    /// let s = (..0).union(5..7).union(10..);
+    /// if let UnboundedSet::Piecewise(p) = s {
+    ///     assert_eq!(p.ranges().copied().collect::<Vec<_>>(), vec![BoundedRange::from(5..7)]);
+    /// }
+    /// ```
+    pub fn ranges(&self) -> Ranges<'_, T> {
+        self.ranges.ranges()
+    }
+
+    /// Returns the trailing unbounded component of the set.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{LowerBoundedRange, Rangetools, UnboundedSet};
+    ///
+    /// let s = (..0).union(5..7).union(10..);
+    /// if let UnboundedSet::Piecewise(p) = s {
+    ///     assert_eq!(p.lower_bounded_range(), &LowerBoundedRange::from(10..));
+    /// }
+    /// ```
+    pub fn lower_bounded_range(&self) -> &LowerBoundedRange<T> {
+        &self.lower_bounded_range
+    }
+
+    /// Returns an iterator over all the disjoint components making up the set, in ascending
+    /// order, including both of its unbounded components.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{Rangetools, UnboundedSet};
+    ///
+    /// let s = (..0).union(5..7).union(10..);
+    /// if let UnboundedSet::Piecewise(p) = s {
+    ///     assert_eq!(p.components().len(), 3);
+    /// }
+    /// ```
+    pub fn components(&self) -> Components<'_, T>
+    where
+        T: Clone,
+    {
+        Components::new(
+            Some(Component::UpperBounded(self.upper_bounded_range.clone())),
+            self.ranges(),
+            Some(Component::LowerBounded(self.lower_bounded_range.clone())),
+        )
+    }
+
+    /// Retains only the bounded ranges making up the set for which the predicate returns true.
+    ///
+    /// The unbounded components are always kept.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{Rangetools, UnboundedSet};
+    ///
+    /// let s = (..0).union(5..10).union(20..);
+    /// if let UnboundedSet::Piecewise(mut p) = s {
+    ///     p.retain(|_| false);
+    ///     assert_eq!(UnboundedSet::Piecewise(p), (..0).union(20..));
+    /// }
+    /// ```
+    pub fn retain<F>(&mut self, f: F)
+    where
+        F: FnMut(&BoundedRange<T>) -> bool,
+    {
+        self.ranges.retain(f);
+    }
+}
+
+impl<T: Element> PiecewiseUnboundedSet<T> {
+    /// Returns true if the set contains `t`, which can be passed by value or by reference.
     ///
     /// # Example
     /// ```
@@ -37,34 +153,57 @@ impl<T: Copy + Ord> PiecewiseUnboundedSet<T> {
     ///
     /// let s = (..5).union(10..);
     /// assert!(s.contains(4));
-    /// assert!(!s.contains(7));
+    /// assert!(!s.contains(&7));
     /// assert!(s.contains(10));
     /// ```
-    pub fn contains(&self, t: T) -> bool {
+    pub fn contains<Q: Borrow<T>>(&self, t: Q) -> bool {
+        let t = t.borrow();
         self.upper_bounded_range.contains(t)
             || self.lower_bounded_range.contains(t)
             || self.ranges.contains(t)
     }
 
-    fn defragment(&mut self) {
-        if let Some(index) = self
-            .ranges
-            .ranges
-            .iter()
-            .position(|r| self.upper_bounded_range.intersects(*r))
-        {
-            let range = self.ranges.ranges.remove(index).unwrap();
-            self.upper_bounded_range.end = self.upper_bounded_range.end.max(range.end);
-            self.defragment();
-        } else if let Some(index) = self
-            .ranges
-            .ranges
-            .iter()
-            .position(|r| self.lower_bounded_range.intersects(*r))
-        {
-            let range = self.ranges.ranges.remove(index).unwrap();
-            self.lower_bounded_range.start = self.lower_bounded_range.start.min(range.start);
-            self.defragment();
+    /// Merges any ranges which overlap or touch the unbounded ranges into them, returning true
+    /// if the unbounded ranges then overlap or touch each other.
+    fn defragment(&mut self) -> bool {
+        let ranges = self.ranges.ranges_mut();
+        let end = &self.upper_bounded_range.end;
+        let count = ranges.partition_point(|range| merge::is_connected(end, &range.start));
+        if let Some(last) = ranges.drain(..count).next_back() {
+            if last.end > self.upper_bounded_range.end {
+                self.upper_bounded_range.end = last.end;
+            }
+        }
+        let start = &self.lower_bounded_range.start;
+        let index = ranges.partition_point(|range| !merge::is_connected(&range.end, start));
+        if let Some(first) = ranges.drain(index..).next() {
+            if first.start < self.lower_bounded_range.start {
+                self.lower_bounded_range.start = first.start;
+            }
+        }
+        merge::is_connected(
+            &self.upper_bounded_range.end,
+            &self.lower_bounded_range.start,
+        )
+    }
+
+    fn remove_range(&mut self, range: BoundedRange<T>) {
+        self.ranges.remove_range(range.clone());
+        if range.clone().intersects(self.upper_bounded_range.clone()) {
+            let end = self.upper_bounded_range.end.clone();
+            self.ranges.add_range(BoundedRange::new(
+                LowerBound(range.end.0.clone().flipped()),
+                end.clone(),
+            ));
+            self.upper_bounded_range.end = end.min(UpperBound(range.start.0.clone().flipped()));
+        }
+        if range.clone().intersects(self.lower_bounded_range.clone()) {
+            let start = self.lower_bounded_range.start.clone();
+            self.ranges.add_range(BoundedRange::new(
+                start.clone(),
+                UpperBound(range.start.0.flipped()),
+            ));
+            self.lower_bounded_range.start = start.max(LowerBound(range.end.0.flipped()));
         }
     }
 }
@@ -80,7 +219,7 @@ impl<T: Copy + Ord> PiecewiseUnboundedSet<T> {
 /// let full: UnboundedSet<_> = (..10).union(5..);
 /// assert_eq!(full, UnboundedSet::Full);
 /// ```
-#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[derive(Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub enum UnboundedSet<T> {
     /// Denotes an `UnboundedSet` containing all possible values of T.
@@ -89,13 +228,157 @@ pub enum UnboundedSet<T> {
     Piecewise(PiecewiseUnboundedSet<T>),
 }
 
+// Compared and hashed in canonical form, so that sets containing the same elements are equal.
+impl<T: Element> PartialEq for UnboundedSet<T> {
+    fn eq(&self, other: &Self) -> bool {
+        merge::canonical(self).eq(merge::canonical(other))
+    }
+}
+
+impl<T: Element> Eq for UnboundedSet<T> {}
+
+impl<T: Element + Hash> Hash for UnboundedSet<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for piece in merge::canonical(self) {
+            piece.hash(state);
+        }
+    }
+}
+
 impl<T> From<UnboundedRange> for UnboundedSet<T> {
     fn from(_: UnboundedRange) -> Self {
         Self::Full
     }
 }
 
-impl<T: Copy + Ord> UnboundedSet<T> {
+/// Succeeds if the set is [`UnboundedSet::Full`].
+///
+/// # Example
+/// ```
+/// use rangetools::{RangeError, Rangetools, UnboundedRange};
+///
+/// assert_eq!(UnboundedRange::try_from((..5).union(3..)), Ok(UnboundedRange));
+/// assert_eq!(UnboundedRange::try_from((..2).union(3..)), Err(RangeError::MultipleComponents));
+/// ```
+impl<T> TryFrom<UnboundedSet<T>> for UnboundedRange {
+    type Error = RangeError;
+    fn try_from(s: UnboundedSet<T>) -> Result<Self, Self::Error> {
+        match s {
+            UnboundedSet::Full => Ok(UnboundedRange),
+            UnboundedSet::Piecewise(_) => Err(RangeError::MultipleComponents),
+        }
+    }
+}
+
+impl<T> UnboundedSet<T> {
+    /// Returns the leading unbounded component of the set, or `None` if the set is full.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{Rangetools, UnboundedSet, UpperBoundedRange};
+    ///
+    /// let s = (..0).union(5..7).union(10..);
+    /// assert_eq!(s.upper_bounded_range(), Some(&UpperBoundedRange::from(..0)));
+    /// assert_eq!(UnboundedSet::<i32>::Full.upper_bounded_range(), None);
+    /// ```
+    pub fn upper_bounded_range(&self) -> Option<&UpperBoundedRange<T>> {
+        match self {
+            Self::Full => None,
+            Self::Piecewise(p) => Some(p.upper_bounded_range()),
+        }
+    }
+
+    /// Returns an iterator over the disjoint bounded ranges making up the set, in ascending
+    /// order.
+    ///
+    /// The set's unbounded components are not included, and can be accessed with
+    /// [`upper_bounded_range`](Self::upper_bounded_range) and
+    /// [`lower_bounded_range`](Self::lower_bounded_range). To iterate over every component,
+    /// including the unbounded ones, use [`components`](Self::components).
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{BoundedRange, Rangetools, UnboundedSet};
+    ///
+    /// let s = (..0).union(5..7).union(10..);
+    /// assert_eq!(s.ranges().copied().collect::<Vec<_>>(), vec![BoundedRange::from(5..7)]);
+    /// assert_eq!(UnboundedSet::<i32>::Full.ranges().count(), 0);
+    /// ```
+    pub fn ranges(&self) -> Ranges<'_, T> {
+        match self {
+            Self::Full => Ranges::empty(),
+            Self::Piecewise(p) => p.ranges(),
+        }
+    }
+
+    /// Returns the trailing unbounded component of the set, or `None` if the set is full.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{LowerBoundedRange, Rangetools, UnboundedSet};
+    ///
+    /// let s = (..0).union(5..7).union(10..);
+    /// assert_eq!(s.lower_bounded_range(), Some(&LowerBoundedRange::from(10..)));
+    /// assert_eq!(UnboundedSet::<i32>::Full.lower_bounded_range(), None);
+    /// ```
+    pub fn lower_bounded_range(&self) -> Option<&LowerBoundedRange<T>> {
+        match self {
+            Self::Full => None,
+            Self::Piecewise(p) => Some(p.lower_bounded_range()),
+        }
+    }
+
+    /// Returns an iterator over all the disjoint components making up the set, in ascending
+    /// order, including its unbounded ones.
+    ///
+    /// A full set has a single [`Component::Unbounded`] component.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{Component, Rangetools, UnboundedRange, UnboundedSet};
+    ///
+    /// let s = (..0).union(5..7).union(10..);
+    /// assert_eq!(s.components().len(), 3);
+    /// let full = UnboundedSet::<i32>::Full;
+    /// assert_eq!(full.components().collect::<Vec<_>>(), vec![Component::Unbounded(UnboundedRange)]);
+    /// ```
+    pub fn components(&self) -> Components<'_, T>
+    where
+        T: Clone,
+    {
+        match self {
+            Self::Full => Components::new(
+                Some(Component::Unbounded(UnboundedRange)),
+                Ranges::empty(),
+                None,
+            ),
+            Self::Piecewise(p) => p.components(),
+        }
+    }
+
+    /// Retains only the bounded ranges making up the set for which the predicate returns true.
+    ///
+    /// The unbounded components are always kept, and a full set is left unchanged.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::Rangetools;
+    ///
+    /// let mut s = (..0).union(5..10).union(12..14).union(20..);
+    /// s.retain(|r| r.contains(13));
+    /// assert_eq!(s, (..0).union(12..14).union(20..));
+    /// ```
+    pub fn retain<F>(&mut self, f: F)
+    where
+        F: FnMut(&BoundedRange<T>) -> bool,
+    {
+        if let Self::Piecewise(p) = self {
+            p.retain(f);
+        }
+    }
+}
+
+impl<T: Element> UnboundedSet<T> {
     /// Construct an `UnboundedSet` from an `UpperBoundedRange` and a `LowerBoundedRange`.
     ///
     /// # Example
@@ -108,20 +391,102 @@ impl<T: Copy + Ord> UnboundedSet<T> {
         upper_bounded_range: UpperBoundedRange<T>,
         lower_bounded_range: LowerBoundedRange<T>,
     ) -> Self {
-        if RangeIntersection::intersection(upper_bounded_range, lower_bounded_range).is_empty() {
+        if BoundedRange::between(&upper_bounded_range.end, &lower_bounded_range.start).is_empty() {
+            Self::Full
+        } else {
             Self::Piecewise(PiecewiseUnboundedSet {
                 upper_bounded_range,
                 ranges: BoundedSet::empty(),
                 lower_bounded_range,
             })
-        } else {
-            Self::Full
         }
     }
+
+    /// Constructs a set from an initial upper-bounded range, bounded ranges and a final
+    /// lower-bounded range which are already sorted and disjoint, in linear time.
+    ///
+    /// Fails at the first component which is empty, starts no later than the component before
+    /// it, or overlaps or touches the component before it. The error holds the index of that
+    /// component, with the upper-bounded range coming first and the lower-bounded range last.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{RangeError, Rangetools, UnboundedSet};
+    ///
+    /// let s = UnboundedSet::from_sorted_ranges(..0, [2..4, 5..7], 10..);
+    /// assert_eq!(s, Ok((..0).union(2..4).union(5..7).union(10..)));
+    ///
+    /// let s = UnboundedSet::from_sorted_ranges(..0, [2..4, -2..-1], 10..);
+    /// assert_eq!(s, Err(RangeError::Unsorted { index: 2 }));
+    /// let s = UnboundedSet::from_sorted_ranges(..0, [2..4, 5..7], 6..);
+    /// assert_eq!(s, Err(RangeError::Overlapping { index: 3 }));
+    /// ```
+    pub fn from_sorted_ranges<U, I, L>(
+        upper_bounded_range: U,
+        ranges: I,
+        lower_bounded_range: L,
+    ) -> Result<Self, RangeError>
+    where
+        U: Rangetools<Inner = UpperBoundedRange<T>>,
+        I: IntoIterator,
+        I::Item: Rangetools<Inner = BoundedRange<T>>,
+        L: Rangetools<Inner = LowerBoundedRange<T>>,
+    {
+        let upper_bounded_range = upper_bounded_range.to_inner();
+        let ranges = BoundedSet::from_sorted_ranges_after(Some(&upper_bounded_range.end), ranges)?;
+        let lower_bounded_range = lower_bounded_range.to_inner();
+        let (previous_start, previous_end) = match ranges.ranges.back() {
+            Some(last) => (Some(&last.start), &last.end),
+            None => (None, &upper_bounded_range.end),
+        };
+        check_follows(
+            previous_start,
+            previous_end,
+            &lower_bounded_range.start,
+            ranges.ranges.len() + 1,
+        )?;
+        Ok(Self::Piecewise(PiecewiseUnboundedSet {
+            upper_bounded_range,
+            ranges,
+            lower_bounded_range,
+        }))
+    }
+
+    /// Constructs a set from an initial upper-bounded range, bounded ranges and a final
+    /// lower-bounded range which are already sorted and disjoint, without checking them.
+    ///
+    /// The components must meet the conditions checked by
+    /// [`from_sorted_ranges`](Self::from_sorted_ranges). Otherwise the results of operations
+    /// on the set are unspecified.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{Rangetools, UnboundedSet};
+    ///
+    /// let s = UnboundedSet::from_sorted_ranges_unchecked(..0, [2..4], 10..);
+    /// assert_eq!(s, (..0).union(2..4).union(10..));
+    /// ```
+    pub fn from_sorted_ranges_unchecked<U, I, L>(
+        upper_bounded_range: U,
+        ranges: I,
+        lower_bounded_range: L,
+    ) -> Self
+    where
+        U: Rangetools<Inner = UpperBoundedRange<T>>,
+        I: IntoIterator,
+        I::Item: Rangetools<Inner = BoundedRange<T>>,
+        L: Rangetools<Inner = LowerBoundedRange<T>>,
+    {
+        Self::Piecewise(PiecewiseUnboundedSet {
+            upper_bounded_range: upper_bounded_range.to_inner(),
+            ranges: BoundedSet::from_sorted_ranges_unchecked(ranges),
+            lower_bounded_range: lower_bounded_range.to_inner(),
+        })
+    }
 }
 
-impl<T: Copy + Ord> UnboundedSet<T> {
-    /// Returns true if the set contains `t`.
+impl<T: Element> UnboundedSet<T> {
+    /// Returns true if the set contains `t`, which can be passed by value or by reference.
     ///
     /// # Example
     /// ```
@@ -129,69 +494,368 @@ impl<T: Copy + Ord> UnboundedSet<T> {
     ///
     /// let s = UnboundedSet::Full;
     /// assert!(s.contains(4));
-    /// assert!(s.contains(7));
+    /// assert!(s.contains(&7));
     /// assert!(s.contains(10));
     /// ```
-    pub fn contains(&self, t: T) -> bool {
+    pub fn contains<Q: Borrow<T>>(&self, t: Q) -> bool {
         match self {
             Self::Full => true,
             Self::Piecewise(p) => p.contains(t),
         }
     }
-    fn map_piecewise(&mut self, f: impl FnOnce(&mut PiecewiseUnboundedSet<T>)) {
+
+    /// Returns the component of the set which contains `t`, if any.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{BoundedRange, Component, Rangetools, UnboundedRange, UnboundedSet};
+    ///
+    /// let s = (..0).union(5..10).union(20..);
+    /// assert_eq!(s.range_containing(7), Some(Component::Bounded(BoundedRange::from(5..10))));
+    /// assert_eq!(s.range_containing(15), None);
+    /// assert_eq!(
+    ///     UnboundedSet::Full.range_containing(15),
+    ///     Some(Component::Unbounded(UnboundedRange))
+    /// );
+    /// ```
+    pub fn range_containing<Q: Borrow<T>>(&self, t: Q) -> Option<Component<T>> {
+        let t = t.borrow();
         match self {
-            Self::Full => {}
-            Self::Piecewise(p) => f(p),
+            Self::Full => Some(Component::Unbounded(UnboundedRange)),
+            Self::Piecewise(p) => {
+                if p.upper_bounded_range.contains(t) {
+                    Some(Component::UpperBounded(p.upper_bounded_range.clone()))
+                } else if p.lower_bounded_range.contains(t) {
+                    Some(Component::LowerBounded(p.lower_bounded_range.clone()))
+                } else {
+                    p.ranges.range_containing(t).map(Component::Bounded)
+                }
+            }
         }
     }
-    fn defragment(&mut self) {
-        self.map_piecewise(PiecewiseUnboundedSet::defragment);
-        if let Self::Piecewise(PiecewiseUnboundedSet {
-            upper_bounded_range,
-            lower_bounded_range,
-            ..
-        }) = self
-        {
-            if upper_bounded_range.intersects(*lower_bounded_range) {
-                *self = Self::Full
+
+    /// Returns an iterator over the gaps between the components of the set, in ascending
+    /// order.
+    ///
+    /// Together, the gaps make up the complement of the set.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{BoundedRange, Rangetools};
+    ///
+    /// let s = (..0).union(5..7).union(10..);
+    /// let gaps: Vec<_> = s.gaps().collect();
+    /// assert_eq!(gaps, vec![BoundedRange::from(0..5), BoundedRange::from(7..10)]);
+    /// ```
+    pub fn gaps(&self) -> Gaps<'_, T> {
+        match self {
+            Self::Full => Gaps::new(None, Ranges::empty(), None),
+            Self::Piecewise(p) => Gaps::new(
+                Some(p.upper_bounded_range.end.clone()),
+                p.ranges(),
+                Some(p.lower_bounded_range.start.clone()),
+            ),
+        }
+    }
+
+    /// Returns the smallest range containing every element of the set, which is always
+    /// unbounded.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{Rangetools, UnboundedRange};
+    ///
+    /// let s = (..0).union(10..);
+    /// assert_eq!(s.span(), UnboundedRange);
+    /// ```
+    #[doc(alias = "hull")]
+    pub fn span(&self) -> UnboundedRange {
+        UnboundedRange
+    }
+    pub(crate) fn remove_range(&mut self, range: BoundedRange<T>) {
+        match self {
+            Self::Full => {
+                if !range.is_empty() {
+                    *self = Self::new(
+                        UpperBoundedRange::new(UpperBound(range.start.0.flipped())),
+                        LowerBoundedRange::new(LowerBound(range.end.0.flipped())),
+                    );
+                }
             }
+            Self::Piecewise(p) => p.remove_range(range),
         }
     }
 
-    pub(crate) fn add_range(&mut self, range: BoundedRange<T>) {
-        self.map_piecewise(|p| p.ranges.add_range(range));
-        self.defragment();
+    /// Adds `range` to the set in place, merging it with any components it overlaps or touches.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{Rangetools, UnboundedSet};
+    ///
+    /// let mut s = (..0).union(10..);
+    /// s.insert(3..5);
+    /// assert_eq!(s, (..0).union(3..5).union(10..));
+    /// s.insert(-5..12);
+    /// assert_eq!(s, UnboundedSet::Full);
+    /// ```
+    pub fn insert<R>(&mut self, range: R)
+    where
+        R: Rangetools,
+        R::Set: RangeQuery<T>,
+        Self: RangeUnion<R, R::Set, Output = Self>,
+    {
+        let set = range.to_set();
+        let components = RangeQuery::components(&set);
+        let p = match self {
+            Self::Full => return,
+            Self::Piecewise(p) => p,
+        };
+        if components.len() > 1 {
+            let pieces: Vec<_> = components.map(Component::into_bounds).collect();
+            *self = merge::union_owned((&*self).into(), &pieces).into();
+            return;
+        }
+        for component in components {
+            match component {
+                Component::Bounded(r) => p.ranges.add_range(r),
+                Component::LowerBounded(r) => {
+                    if r.start < p.lower_bounded_range.start {
+                        p.lower_bounded_range.start = r.start;
+                    }
+                }
+                Component::UpperBounded(r) => {
+                    if r.end > p.upper_bounded_range.end {
+                        p.upper_bounded_range.end = r.end;
+                    }
+                }
+                Component::Unbounded(_) => {
+                    *self = Self::Full;
+                    return;
+                }
+            }
+            if p.defragment() {
+                *self = Self::Full;
+                return;
+            }
+        }
     }
-    pub(crate) fn add_lower_bounded_range(&mut self, range: LowerBoundedRange<T>) {
-        self.map_piecewise(|p| {
-            p.lower_bounded_range.start = LowerBound::min(p.lower_bounded_range.start, range.start);
-        });
-        self.defragment();
+
+    /// Removes all elements of `range` from the set in place, splitting any components it
+    /// partially overlaps.
+    ///
+    /// Only ranges whose difference from an `UnboundedSet` is still an `UnboundedSet` can be
+    /// removed, so removing an unbounded range of any kind is a compile error.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{Rangetools, UnboundedSet};
+    ///
+    /// let mut s = UnboundedSet::Full;
+    /// s.remove(0..10);
+    /// s.remove(3..=4);
+    /// assert_eq!(s, (..0).union(10..));
+    /// ```
+    pub fn remove<R>(&mut self, range: R)
+    where
+        R: Rangetools,
+        R::Set: RangeQuery<T>,
+        Self: RangeDifference<R, R::Inner, Output = Self>,
+    {
+        let set = range.to_set();
+        let components = RangeQuery::components(&set);
+        if components.len() > 1 {
+            let pieces: Vec<_> = components.map(Component::into_bounds).collect();
+            *self = merge::difference_owned((&*self).into(), &pieces).into();
+        } else {
+            for component in components {
+                match component {
+                    Component::Bounded(r) => self.remove_range(r),
+                    _ => unreachable!("only bounded ranges can be removed from an `UnboundedSet`"),
+                }
+            }
+        }
     }
-    pub(crate) fn add_upper_bounded_range(&mut self, range: UpperBoundedRange<T>) {
-        self.map_piecewise(|p| {
-            p.upper_bounded_range.end = UpperBound::max(p.upper_bounded_range.end, range.end);
-        });
-        self.defragment();
+}
+
+impl<T: Element + Step> UnboundedSet<T> {
+    /// Returns the smallest element of the set which is greater than or equal to `t`.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::Rangetools;
+    ///
+    /// let s = (..0).union(5..10).union(20..);
+    /// assert_eq!(s.next_at_or_after(-42), Some(-42));
+    /// assert_eq!(s.next_at_or_after(12), Some(20));
+    /// ```
+    pub fn next_at_or_after(&self, t: T) -> Option<T> {
+        match self {
+            Self::Full => Some(t),
+            Self::Piecewise(p) => {
+                if p.upper_bounded_range.contains(&t) || p.lower_bounded_range.contains(&t) {
+                    Some(t)
+                } else {
+                    p.ranges
+                        .next_at_or_after(t)
+                        .or_else(|| p.lower_bounded_range.first())
+                }
+            }
+        }
     }
-    pub(crate) fn add_set(&mut self, set: BoundedSet<T>) {
-        for range in set.ranges.into_iter() {
-            self.add_range(range);
+
+    /// Returns the smallest element of the set which is strictly greater than `t`.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::Rangetools;
+    ///
+    /// let s = (..0).union(5..10).union(20..);
+    /// assert_eq!(s.next_after(-1), Some(5));
+    /// assert_eq!(s.next_after(i32::MAX), None);
+    /// ```
+    pub fn next_after(&self, t: T) -> Option<T> {
+        Step::forward_checked(t, 1).and_then(|t| self.next_at_or_after(t))
+    }
+
+    /// Returns the largest element of the set which is less than or equal to `t`.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::Rangetools;
+    ///
+    /// let s = (..0).union(5..10).union(20..);
+    /// assert_eq!(s.prev_at_or_before(42), Some(42));
+    /// assert_eq!(s.prev_at_or_before(12), Some(9));
+    /// ```
+    pub fn prev_at_or_before(&self, t: T) -> Option<T> {
+        match self {
+            Self::Full => Some(t),
+            Self::Piecewise(p) => {
+                if p.upper_bounded_range.contains(&t) || p.lower_bounded_range.contains(&t) {
+                    Some(t)
+                } else {
+                    p.ranges
+                        .prev_at_or_before(t)
+                        .or_else(|| p.upper_bounded_range.last())
+                }
+            }
         }
     }
-    pub(crate) fn add_unbounded_set(&mut self, range: UnboundedSet<T>) {
-        match range {
-            Self::Piecewise(PiecewiseUnboundedSet {
-                lower_bounded_range,
-                ranges,
-                upper_bounded_range,
-            }) => {
-                self.add_lower_bounded_range(lower_bounded_range);
-                self.add_upper_bounded_range(upper_bounded_range);
-                self.add_set(ranges);
-                self.defragment();
+
+    /// Returns the largest element of the set which is strictly less than `t`.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::Rangetools;
+    ///
+    /// let s = (..0).union(5..10).union(20..);
+    /// assert_eq!(s.prev_before(5), Some(-1));
+    /// assert_eq!(s.prev_before(i32::MIN), None);
+    /// ```
+    pub fn prev_before(&self, t: T) -> Option<T> {
+        Step::backward_checked(t, 1).and_then(|t| self.prev_at_or_before(t))
+    }
+
+    /// Returns the set in canonical form: every range has its bounds included, ranges without
+    /// elements are dropped, and ranges with no elements between them are merged, leaving
+    /// [`UnboundedSet::Full`] if there are no gaps.
+    ///
+    /// Two normalized sets are equal exactly when they contain the same elements.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{Rangetools, UnboundedSet};
+    ///
+    /// let s = (..=2).union(3..=5).union(8..);
+    /// assert_eq!(s.normalize(), (..=5).union(8..));
+    /// assert_eq!((..=2).union(3..).normalize(), UnboundedSet::Full);
+    /// ```
+    pub fn normalize(self) -> Self {
+        match self {
+            Self::Full => Self::Full,
+            Self::Piecewise(p) => {
+                let LowerBoundedSet {
+                    ranges,
+                    lower_bounded_range,
+                } = LowerBoundedSet {
+                    ranges: p.ranges,
+                    lower_bounded_range: p.lower_bounded_range,
+                }
+                .normalize();
+                let UpperBoundedSet {
+                    upper_bounded_range,
+                    ranges,
+                } = UpperBoundedSet {
+                    upper_bounded_range: p.upper_bounded_range,
+                    ranges,
+                }
+                .normalize();
+                if BoundedRange::is_contiguous(&upper_bounded_range.end, &lower_bounded_range.start)
+                {
+                    Self::Full
+                } else {
+                    Self::Piecewise(PiecewiseUnboundedSet {
+                        upper_bounded_range,
+                        ranges,
+                        lower_bounded_range,
+                    })
+                }
             }
-            Self::Full => *self = Self::Full,
         }
     }
 }
+
+impl<T: Element + Domain> UnboundedSet<T> {
+    /// Returns true if the set contains every value of `T`.
+    ///
+    /// Unlike checking for [`UnboundedSet::Full`], this also recognises sets whose gaps only
+    /// lie below `T::MIN` or above `T::MAX`.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{LowerBound, LowerBoundedRange, Rangetools};
+    ///
+    /// assert!((..=10u8).union(11..).is_full());
+    /// let s = (..=10u8).union(LowerBoundedRange::new(LowerBound::excluded(255)));
+    /// assert!(!s.is_full());
+    /// assert!((..=255u8).union(LowerBoundedRange::new(LowerBound::excluded(255))).is_full());
+    /// ```
+    pub fn is_full(&self) -> bool {
+        self.clone().complement_in_domain().ranges.is_empty()
+    }
+
+    /// Returns the values of `T` not contained in the set, as a normalized set.
+    ///
+    /// The complement of an `UnboundedSet` is already bounded, so this is the same as
+    /// [`complement`](crate::Rangetools::complement) followed by
+    /// [`normalize`](BoundedSet::normalize).
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::Rangetools;
+    ///
+    /// let c = (..10u8).union(20..).complement_in_domain();
+    /// assert_eq!(c, (10..=19).to_set());
+    /// ```
+    pub fn complement_in_domain(self) -> BoundedSet<T> {
+        let complement: BoundedSet<T> = self.complement();
+        complement.normalize()
+    }
+
+    /// Returns the set as a normalized set over the values of `T`, treating it as though it
+    /// started at `T::MIN` and ended at `T::MAX`.
+    ///
+    /// Sets over the same domain compare equal exactly when they contain the same elements,
+    /// whatever the range or set type they were built from.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::Rangetools;
+    ///
+    /// let s = (..5u8).union(250..);
+    /// assert_eq!(s.normalize_in_domain(), (0..=4).union(250..=255));
+    /// ```
+    pub fn normalize_in_domain(self) -> BoundedSet<T> {
+        domain().intersection(self).normalize()
+    }
+}
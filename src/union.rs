@@ -1,4 +1,4 @@
-use crate::Rangetools;
+use crate::{merge, AnySet, Component, Element, RangeQuery, Rangetools};
 
 /// Helper trait for performing range unions.
 ///
@@ -12,3 +12,64 @@ pub trait RangeUnion<Rhs, RhsSet> {
     where
         Rhs: Rangetools<Set = RhsSet>;
 }
+
+/// Returns the union of all of the ranges or sets in `ranges`, or `None` if there are none.
+///
+/// The output is the tightest set type able to hold the union of any number of items, ie the
+/// type of [`.to_set()`](crate::Rangetools::to_set) on an item. The items are combined in
+/// balanced pairs, so each component takes part in `O(log n)` merges. To combine items of
+/// different types, use [`union_all_any`].
+///
+/// # Example
+/// ```
+/// use rangetools::{union_all, Rangetools};
+///
+/// let s = union_all(vec![0..3, 10..12, 2..5]).unwrap();
+/// assert_eq!(s, (0..5).union(10..12));
+///
+/// let s = union_all([(..0).union(5..), (..2).union(8..)]).unwrap();
+/// assert_eq!(s, (..2).union(5..));
+///
+/// assert_eq!(union_all(Vec::<std::ops::Range<i32>>::new()), None);
+/// ```
+pub fn union_all<I, R>(ranges: I) -> Option<R::Set>
+where
+    I: IntoIterator<Item = R>,
+    R: Rangetools,
+    R::Set: Rangetools<Set = R::Set> + RangeUnion<R::Set, R::Set, Output = R::Set>,
+{
+    merge::reduce_balanced(
+        ranges.into_iter().map(Rangetools::to_set),
+        RangeUnion::union,
+    )
+}
+
+/// Returns the union of all of the ranges or sets in `ranges`, which can be of different types
+/// by passing them as [`AnySet`]s.
+///
+/// The components of every item are sorted together once and then merged in a single pass, so
+/// this takes `O(n log n)` time in the total number of components. The union of no items is
+/// empty.
+///
+/// # Example
+/// ```
+/// use rangetools::{union_all_any, AnySet, BoundedSet, Rangetools};
+///
+/// let s = union_all_any([AnySet::from(..0), (5..8).union(10..).into(), (-3..6).into()]);
+/// assert_eq!(s, AnySet::from((..8).union(10..)));
+///
+/// assert_eq!(union_all_any(Vec::<AnySet<i32>>::new()), BoundedSet::empty().into());
+/// ```
+pub fn union_all_any<T, I>(ranges: I) -> AnySet<T>
+where
+    T: Element,
+    I: IntoIterator,
+    I::Item: Into<AnySet<T>>,
+{
+    let mut pieces = Vec::new();
+    for r in ranges {
+        let s: AnySet<T> = r.into();
+        pieces.extend(RangeQuery::components(&s).map(Component::into_bounds));
+    }
+    merge::union_all(pieces).into()
+}
@@ -1,6 +1,10 @@
-use crate::{Bound, Step, UpperBound};
+use crate::{
+    domain::domain, Bound, BoundedSet, Domain, Element, LowerBoundedRange, RangeError, Rangetools,
+    Step, UpperBound,
+};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+use std::borrow::Borrow;
 
 /// A range only bounded above (either inclusive or exclusive).
 ///
@@ -37,31 +41,53 @@ impl<T> From<std::ops::RangeToInclusive<T>> for UpperBoundedRange<T> {
     }
 }
 
-impl<T> From<UpperBoundedRange<T>> for std::ops::RangeTo<T>
+impl<T> TryFrom<UpperBoundedRange<T>> for std::ops::RangeTo<T>
 where
-    T: Copy + Step,
+    T: Clone + Step,
 {
-    fn from(r: UpperBoundedRange<T>) -> Self {
+    type Error = RangeError;
+    fn try_from(r: UpperBoundedRange<T>) -> Result<Self, Self::Error> {
         match r.end.to_bound() {
-            Bound::Excluded(t) => ..t,
-            Bound::Included(t) => ..Step::forward(t, 1),
+            Bound::Excluded(t) => Ok(..t),
+            Bound::Included(t) => Ok(..Step::forward_checked(t, 1).ok_or(RangeError::Overflow)?),
         }
     }
 }
 
-impl<T> From<UpperBoundedRange<T>> for std::ops::RangeToInclusive<T>
+impl<T> TryFrom<UpperBoundedRange<T>> for std::ops::RangeToInclusive<T>
 where
-    T: Copy + Step,
+    T: Clone + Step,
 {
-    fn from(r: UpperBoundedRange<T>) -> Self {
+    type Error = RangeError;
+    fn try_from(r: UpperBoundedRange<T>) -> Result<Self, Self::Error> {
         match r.end.to_bound() {
-            Bound::Excluded(t) => ..=Step::backward(t, 1),
-            Bound::Included(t) => ..=t,
+            Bound::Excluded(t) => Ok(..=Step::backward_checked(t, 1).ok_or(RangeError::Overflow)?),
+            Bound::Included(t) => Ok(..=t),
         }
     }
 }
 
-impl<T: Copy + Ord> UpperBoundedRange<T> {
+/// Allows an `UpperBoundedRange` to be used wherever the standard library accepts a range.
+///
+/// # Example
+/// ```
+/// use rangetools::UpperBoundedRange;
+///
+/// let mut s = String::from("hello world");
+/// s.replace_range(UpperBoundedRange::from(..5), "goodbye");
+/// assert_eq!(s, "goodbye world");
+/// ```
+impl<T> std::ops::RangeBounds<T> for UpperBoundedRange<T> {
+    fn start_bound(&self) -> std::ops::Bound<&T> {
+        std::ops::Bound::Unbounded
+    }
+
+    fn end_bound(&self) -> std::ops::Bound<&T> {
+        self.end.0.as_ref().into()
+    }
+}
+
+impl<T: Element> UpperBoundedRange<T> {
     /// Constructs a new `UpperBoundedRange` from an upper bound.
     ///
     /// # Example
@@ -75,7 +101,7 @@ impl<T: Copy + Ord> UpperBoundedRange<T> {
         Self { end }
     }
 
-    /// Returns true if the range contains `t`.
+    /// Returns true if the range contains `t`, which can be passed by value or by reference.
     ///
     /// # Example
     /// ```
@@ -83,12 +109,125 @@ impl<T: Copy + Ord> UpperBoundedRange<T> {
     ///
     /// let i = (..5).intersection(..10);
     /// assert!(i.contains(4));
-    /// assert!(!i.contains(9));
+    /// assert!(!i.contains(&9));
     /// ```
-    pub fn contains(&self, t: T) -> bool {
-        match self.end.0 {
+    pub fn contains<Q: Borrow<T>>(&self, t: Q) -> bool {
+        let t = t.borrow();
+        match &self.end.0 {
             Bound::Excluded(x) => t < x,
             Bound::Included(i) => t <= i,
         }
     }
+
+    /// Returns the smallest range containing every element of the range, ie the range itself.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{Rangetools, UpperBoundedRange};
+    ///
+    /// assert_eq!((..5).to_inner().span(), UpperBoundedRange::from(..5));
+    /// ```
+    #[doc(alias = "hull")]
+    pub fn span(&self) -> UpperBoundedRange<T> {
+        self.clone()
+    }
+
+    /// Returns the upper bound of the range.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{Rangetools, UpperBound};
+    ///
+    /// assert_eq!((..5).to_inner().upper_bound(), UpperBound::excluded(5));
+    /// ```
+    #[doc(alias = "supremum")]
+    pub fn upper_bound(&self) -> UpperBound<T> {
+        self.end.clone()
+    }
+}
+
+impl<T: Element + Step> UpperBoundedRange<T> {
+    /// Returns the largest element of the range, or `None` if the range has no elements.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::Rangetools;
+    ///
+    /// assert_eq!((..5).to_inner().last(), Some(4));
+    /// assert_eq!((..u8::MIN).to_inner().last(), None);
+    /// ```
+    pub fn last(&self) -> Option<T> {
+        match &self.end.0 {
+            Bound::Excluded(t) => Step::backward_checked(t.clone(), 1),
+            Bound::Included(t) => Some(t.clone()),
+        }
+    }
+
+    /// Returns the range in canonical form, with an included upper bound.
+    ///
+    /// A range excluding the smallest value of `T` has no elements but can't be represented as
+    /// empty, so is returned unchanged.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{UpperBound, UpperBoundedRange};
+    ///
+    /// let r = UpperBoundedRange::new(UpperBound::excluded(5));
+    /// assert_eq!(r.normalize(), UpperBoundedRange::from(..=4));
+    /// ```
+    pub fn normalize(self) -> Self {
+        match self.last() {
+            Some(last) => Self::new(UpperBound::included(last)),
+            None => self,
+        }
+    }
+}
+
+impl<T: Element + Domain> UpperBoundedRange<T> {
+    /// Returns true if the range contains every value of `T`, ie if it ends at `T::MAX`.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::Rangetools;
+    ///
+    /// assert!((..=255u8).to_inner().is_full());
+    /// assert!(!(..255u8).to_inner().is_full());
+    /// ```
+    pub fn is_full(&self) -> bool {
+        self.last() == Some(T::MAX)
+    }
+
+    /// Returns the values of `T` not contained in the range, as a normalized set.
+    ///
+    /// Unlike [`complement`](crate::Rangetools::complement), the result is bounded above by
+    /// `T::MAX`.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::Rangetools;
+    ///
+    /// assert!((..=255u8).to_inner().complement_in_domain().is_empty());
+    /// assert_eq!((..=10u8).to_inner().complement_in_domain(), (11..=255).to_set());
+    /// ```
+    pub fn complement_in_domain(self) -> BoundedSet<T> {
+        let complement: LowerBoundedRange<T> = self.complement();
+        domain().intersection(complement).to_set().normalize()
+    }
+
+    /// Returns the range as a normalized set over the values of `T`, treating it as though it
+    /// started at `T::MIN`.
+    ///
+    /// Sets over the same domain compare equal exactly when they contain the same elements,
+    /// whatever the range or set type they were built from.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::Rangetools;
+    ///
+    /// let r = (..5u8).to_inner();
+    /// assert_eq!(r.normalize_in_domain(), (0..=4).to_set());
+    /// ```
+    pub fn normalize_in_domain(self) -> BoundedSet<T> {
+        domain().intersection(self).to_set().normalize()
+    }
 }
@@ -1,6 +1,14 @@
-use crate::{BoundedRange, BoundedSet, Rangetools, UpperBound, UpperBoundedRange};
+use crate::{
+    domain::domain, merge, BoundedRange, BoundedSet, Component, Components, Domain, Element, Gaps,
+    LowerBound, LowerBoundedSet, RangeDifference, RangeError, RangeQuery, RangeUnion, Ranges,
+    Rangetools, Step, UpperBound, UpperBoundedRange,
+};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+use std::{
+    borrow::Borrow,
+    hash::{Hash, Hasher},
+};
 
 /// A set of ranges with a finite upper bound but no lower bound.
 ///
@@ -11,14 +19,32 @@ use serde::{Deserialize, Serialize};
 ///
 /// let s: UpperBoundedSet<_> = (10..20).union(..5);
 /// ```
-#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+#[derive(Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub struct UpperBoundedSet<T> {
-    /// Kept private to enforce the invariant that the ranges be non-empty and non-overlapping.
+    /// Kept private to enforce the invariant that the ranges be non-empty, non-overlapping and
+    /// non-touching.
     pub(crate) upper_bounded_range: UpperBoundedRange<T>,
     pub(crate) ranges: BoundedSet<T>,
 }
 
+// Compared and hashed in canonical form, so that sets containing the same elements are equal.
+impl<T: Element> PartialEq for UpperBoundedSet<T> {
+    fn eq(&self, other: &Self) -> bool {
+        merge::canonical(self).eq(merge::canonical(other))
+    }
+}
+
+impl<T: Element> Eq for UpperBoundedSet<T> {}
+
+impl<T: Element + Hash> Hash for UpperBoundedSet<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for piece in merge::canonical(self) {
+            piece.hash(state);
+        }
+    }
+}
+
 impl<T> From<UpperBoundedRange<T>> for UpperBoundedSet<T> {
     fn from(upper_bounded_range: UpperBoundedRange<T>) -> Self {
         Self {
@@ -28,21 +54,116 @@ impl<T> From<UpperBoundedRange<T>> for UpperBoundedSet<T> {
     }
 }
 
-impl<T: Copy + Ord> UpperBoundedSet<T> {
+/// Succeeds if the set has no bounded ranges after its upper-bounded range.
+///
+/// # Example
+/// ```
+/// use rangetools::{RangeError, Rangetools, UpperBoundedRange};
+///
+/// assert_eq!(UpperBoundedRange::try_from((..5).union(3..8)), Ok(UpperBoundedRange::from(..8)));
+/// assert_eq!(UpperBoundedRange::try_from((..2).union(3..5)), Err(RangeError::MultipleComponents));
+/// ```
+impl<T> TryFrom<UpperBoundedSet<T>> for UpperBoundedRange<T> {
+    type Error = RangeError;
+    fn try_from(s: UpperBoundedSet<T>) -> Result<Self, Self::Error> {
+        if s.ranges.ranges.is_empty() {
+            Ok(s.upper_bounded_range)
+        } else {
+            Err(RangeError::MultipleComponents)
+        }
+    }
+}
+
+impl<T> UpperBoundedSet<T> {
+    /// Returns the leading unbounded component of the set.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{Rangetools, UpperBoundedRange};
+    ///
+    /// let s = (..0).union(5..7);
+    /// assert_eq!(s.upper_bounded_range(), &UpperBoundedRange::from(..0));
+    /// ```
+    pub fn upper_bounded_range(&self) -> &UpperBoundedRange<T> {
+        &self.upper_bounded_range
+    }
+
+    /// Returns an iterator over the disjoint bounded ranges making up the set, in ascending
+    /// order.
+    ///
+    /// The set's unbounded component is not included, and can be accessed with
+    /// [`upper_bounded_range`](Self::upper_bounded_range). To iterate over every component,
+    /// including the unbounded one, use [`components`](Self::components).
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{BoundedRange, Rangetools};
+    ///
+    /// let s = (..0).union(5..7).union(10..12);
+    /// let ranges: Vec<_> = s.ranges().copied().collect();
+    /// assert_eq!(ranges, vec![BoundedRange::from(5..7), BoundedRange::from(10..12)]);
+    /// ```
+    pub fn ranges(&self) -> Ranges<'_, T> {
+        self.ranges.ranges()
+    }
+
+    /// Returns an iterator over all the disjoint components making up the set, in ascending
+    /// order, starting with its leading unbounded component.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{BoundedRange, Component, Rangetools, UpperBoundedRange};
+    ///
+    /// let s = (..0).union(5..7);
+    /// let components: Vec<_> = s.components().collect();
+    /// assert_eq!(
+    ///     components,
+    ///     vec![
+    ///         Component::UpperBounded(UpperBoundedRange::from(..0)),
+    ///         Component::Bounded(BoundedRange::from(5..7)),
+    ///     ]
+    /// );
+    /// ```
+    pub fn components(&self) -> Components<'_, T>
+    where
+        T: Clone,
+    {
+        let head = Component::UpperBounded(self.upper_bounded_range.clone());
+        Components::new(Some(head), self.ranges(), None)
+    }
+
+    /// Retains only the bounded ranges making up the set for which the predicate returns true.
+    ///
+    /// The leading upper-bounded range is always kept, since removing it would leave a
+    /// [`BoundedSet`].
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{BoundedRange, Rangetools};
+    ///
+    /// let mut s = (..0).union(5..10).union(20..22);
+    /// s.retain(|r| r.contains(7));
+    /// assert_eq!(s, (..0).union(5..10));
+    /// ```
+    pub fn retain<F>(&mut self, f: F)
+    where
+        F: FnMut(&BoundedRange<T>) -> bool,
+    {
+        self.ranges.retain(f);
+    }
+}
+
+impl<T: Element> UpperBoundedSet<T> {
     fn defragment(&mut self) {
-        while !self.ranges.is_empty() {
-            if self
-                .ranges
-                .ranges
-                .front()
-                .unwrap()
-                .intersection(self.upper_bounded_range)
-                .is_empty()
-            {
-                return;
-            } else {
-                let range = self.ranges.ranges.pop_front().unwrap();
-                self.upper_bounded_range.end = self.upper_bounded_range.end.max(range.end);
+        let end = &self.upper_bounded_range.end;
+        let count = self
+            .ranges
+            .ranges
+            .partition_point(|range| merge::is_connected(end, &range.start));
+        if count > 0 {
+            let last = self.ranges.ranges_mut().drain(..count).next_back().unwrap();
+            if last.end > self.upper_bounded_range.end {
+                self.upper_bounded_range.end = last.end;
             }
         }
     }
@@ -50,17 +171,176 @@ impl<T: Copy + Ord> UpperBoundedSet<T> {
         self.ranges.add_range(range);
         self.defragment();
     }
-    pub(crate) fn add_upper_bounded_range(&mut self, range: UpperBoundedRange<T>) {
-        self.upper_bounded_range.end = UpperBound::max(self.upper_bounded_range.end, range.end);
-        self.defragment();
-    }
     pub(crate) fn add_set(&mut self, set: BoundedSet<T>) {
         for range in set.ranges {
             self.add_range(range);
         }
     }
+    pub(crate) fn remove_range(&mut self, range: BoundedRange<T>) {
+        self.remove_between(range.start, Some(range.end));
+    }
 
-    /// Returns true if the set contains `t`.
+    /// Removes the values between `start` and `end`, where a missing end is unbounded.
+    fn remove_between(&mut self, start: LowerBound<T>, end: Option<UpperBound<T>>) {
+        if merge::is_empty(Some(&start), end.as_ref()) {
+            return;
+        }
+        self.ranges.remove_between(Some(start.clone()), end.clone());
+        let head = &mut self.upper_bounded_range;
+        if !merge::is_empty(Some(&start), Some(&head.end)) {
+            if let Some(end) = end {
+                let after = BoundedRange::new(LowerBound(end.0.flipped()), head.end.clone());
+                if !after.is_empty_by_bounds() {
+                    self.ranges.ranges_mut().push_front(after);
+                }
+            }
+            let end = UpperBound(start.0.flipped());
+            if end < head.end {
+                head.end = end;
+            }
+        }
+    }
+
+    /// Constructs a set from an initial upper-bounded range and bounded ranges which are
+    /// already sorted and disjoint, in linear time.
+    ///
+    /// Fails at the first component which is empty, starts no later than the component before
+    /// it, or overlaps or touches the component before it. The error holds the index of that
+    /// component, with the upper-bounded range coming first.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{RangeError, Rangetools, UpperBoundedSet};
+    ///
+    /// let s = UpperBoundedSet::from_sorted_ranges(..0, [2..4, 5..7]);
+    /// assert_eq!(s, Ok((..0).union(2..4).union(5..7)));
+    ///
+    /// let s = UpperBoundedSet::from_sorted_ranges(..0, [2..4, 7..5]);
+    /// assert_eq!(s, Err(RangeError::EmptyComponent { index: 2 }));
+    /// ```
+    pub fn from_sorted_ranges<R, I>(upper_bounded_range: R, ranges: I) -> Result<Self, RangeError>
+    where
+        R: Rangetools<Inner = UpperBoundedRange<T>>,
+        I: IntoIterator,
+        I::Item: Rangetools<Inner = BoundedRange<T>>,
+    {
+        let upper_bounded_range = upper_bounded_range.to_inner();
+        let ranges = BoundedSet::from_sorted_ranges_after(Some(&upper_bounded_range.end), ranges)?;
+        Ok(Self {
+            upper_bounded_range,
+            ranges,
+        })
+    }
+
+    /// Constructs a set from an initial upper-bounded range and bounded ranges which are
+    /// already sorted and disjoint, without checking them.
+    ///
+    /// The components must meet the conditions checked by
+    /// [`from_sorted_ranges`](Self::from_sorted_ranges). Otherwise the results of operations
+    /// on the set are unspecified.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{Rangetools, UpperBoundedSet};
+    ///
+    /// let s = UpperBoundedSet::from_sorted_ranges_unchecked(..0, [2..4, 5..7]);
+    /// assert_eq!(s, (..0).union(2..4).union(5..7));
+    /// ```
+    pub fn from_sorted_ranges_unchecked<R, I>(upper_bounded_range: R, ranges: I) -> Self
+    where
+        R: Rangetools<Inner = UpperBoundedRange<T>>,
+        I: IntoIterator,
+        I::Item: Rangetools<Inner = BoundedRange<T>>,
+    {
+        Self {
+            upper_bounded_range: upper_bounded_range.to_inner(),
+            ranges: BoundedSet::from_sorted_ranges_unchecked(ranges),
+        }
+    }
+
+    /// Adds `range` to the set in place, merging it with any components it overlaps or touches.
+    ///
+    /// Only ranges whose union with an `UpperBoundedSet` is still an `UpperBoundedSet` can be
+    /// inserted, so inserting a lower-bounded range is a compile error.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::Rangetools;
+    ///
+    /// let mut s = (..0).to_set();
+    /// s.insert(10..12);
+    /// s.insert(..5);
+    /// assert_eq!(s, (..5).union(10..12));
+    /// ```
+    pub fn insert<R>(&mut self, range: R)
+    where
+        R: Rangetools,
+        R::Set: RangeQuery<T>,
+        Self: RangeUnion<R, R::Set, Output = Self>,
+    {
+        let set = range.to_set();
+        let components = RangeQuery::components(&set);
+        if components.len() > 1 {
+            let pieces: Vec<_> = components.map(Component::into_bounds).collect();
+            *self = merge::union_owned((&*self).into(), &pieces).into();
+        } else {
+            for component in components {
+                match component {
+                    Component::Bounded(r) => self.add_range(r),
+                    Component::UpperBounded(r) => {
+                        if r.end > self.upper_bounded_range.end {
+                            self.upper_bounded_range.end = r.end;
+                        }
+                        self.defragment();
+                    }
+                    _ => unreachable!(
+                        "only ranges bounded above can be inserted into an `UpperBoundedSet`"
+                    ),
+                }
+            }
+        }
+    }
+
+    /// Removes all elements of `range` from the set in place, splitting any components it
+    /// partially overlaps.
+    ///
+    /// Only ranges whose difference from an `UpperBoundedSet` is still an `UpperBoundedSet`
+    /// can be removed, so removing an upper-bounded range (which would leave a [`BoundedSet`])
+    /// is a compile error.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::Rangetools;
+    ///
+    /// let mut s = (..10).to_set();
+    /// s.remove(3..5);
+    /// s.remove(9..);
+    /// assert_eq!(s, (..3).union(5..9));
+    /// ```
+    pub fn remove<R>(&mut self, range: R)
+    where
+        R: Rangetools,
+        R::Set: RangeQuery<T>,
+        Self: RangeDifference<R, R::Inner, Output = Self>,
+    {
+        let set = range.to_set();
+        let components = RangeQuery::components(&set);
+        if components.len() > 1 {
+            let pieces: Vec<_> = components.map(Component::into_bounds).collect();
+            *self = merge::difference_owned((&*self).into(), &pieces).into();
+        } else {
+            for component in components {
+                match component.into_bounds() {
+                    (Some(start), end) => self.remove_between(start, end),
+                    _ => unreachable!(
+                        "only ranges bounded below can be removed from an `UpperBoundedSet`"
+                    ),
+                }
+            }
+        }
+    }
+
+    /// Returns true if the set contains `t`, which can be passed by value or by reference.
     ///
     /// # Example
     /// ```
@@ -68,9 +348,245 @@ impl<T: Copy + Ord> UpperBoundedSet<T> {
     ///
     /// let s = (10..20).union(..5);
     /// assert!(s.contains(0));
-    /// assert!(!s.contains(42));
+    /// assert!(!s.contains(&42));
     /// ```
-    pub fn contains(&self, t: T) -> bool {
+    pub fn contains<Q: Borrow<T>>(&self, t: Q) -> bool {
+        let t = t.borrow();
         self.upper_bounded_range.contains(t) || self.ranges.contains(t)
     }
+
+    /// Returns the component of the set which contains `t`, if any.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{BoundedRange, Component, Rangetools, UpperBoundedRange};
+    ///
+    /// let s = (..0).union(5..10);
+    /// assert_eq!(s.range_containing(7), Some(Component::Bounded(BoundedRange::from(5..10))));
+    /// assert_eq!(
+    ///     s.range_containing(-42),
+    ///     Some(Component::UpperBounded(UpperBoundedRange::from(..0)))
+    /// );
+    /// assert_eq!(s.range_containing(3), None);
+    /// ```
+    pub fn range_containing<Q: Borrow<T>>(&self, t: Q) -> Option<Component<T>> {
+        let t = t.borrow();
+        if self.upper_bounded_range.contains(t) {
+            Some(Component::UpperBounded(self.upper_bounded_range.clone()))
+        } else {
+            self.ranges.range_containing(t).map(Component::Bounded)
+        }
+    }
+
+    /// Returns an iterator over the gaps between the components of the set, in ascending
+    /// order.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{BoundedRange, Rangetools};
+    ///
+    /// let s = (..0).union(5..7).union(10..12);
+    /// let gaps: Vec<_> = s.gaps().collect();
+    /// assert_eq!(gaps, vec![BoundedRange::from(0..5), BoundedRange::from(7..10)]);
+    /// ```
+    pub fn gaps(&self) -> Gaps<'_, T> {
+        Gaps::new(
+            Some(self.upper_bounded_range.end.clone()),
+            self.ranges(),
+            None,
+        )
+    }
+
+    /// Returns the smallest range containing every element of the set.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{Rangetools, UpperBoundedRange};
+    ///
+    /// let s = (..0).union(5..=7);
+    /// assert_eq!(s.span(), UpperBoundedRange::from(..=7));
+    /// ```
+    #[doc(alias = "hull")]
+    pub fn span(&self) -> UpperBoundedRange<T> {
+        UpperBoundedRange::new(self.upper_bound())
+    }
+
+    /// Returns the upper bound of the set.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::{Rangetools, UpperBound};
+    ///
+    /// let s = (..0).union(5..=7);
+    /// assert_eq!(s.upper_bound(), UpperBound::included(7));
+    /// ```
+    #[doc(alias = "supremum")]
+    pub fn upper_bound(&self) -> UpperBound<T> {
+        self.ranges
+            .ranges
+            .back()
+            .map_or(&self.upper_bounded_range.end, |r| &r.end)
+            .clone()
+    }
+}
+
+impl<T: Element + Step> UpperBoundedSet<T> {
+    /// Returns the largest element of the set, or `None` if the set has no elements.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::Rangetools;
+    ///
+    /// let s = (..0).union(5..=7);
+    /// assert_eq!(s.last(), Some(7));
+    /// ```
+    pub fn last(&self) -> Option<T> {
+        self.ranges
+            .last()
+            .or_else(|| self.upper_bounded_range.last())
+    }
+
+    /// Returns the smallest element of the set which is greater than or equal to `t`.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::Rangetools;
+    ///
+    /// let s = (..0).union(5..10);
+    /// assert_eq!(s.next_at_or_after(-42), Some(-42));
+    /// assert_eq!(s.next_at_or_after(3), Some(5));
+    /// assert_eq!(s.next_at_or_after(10), None);
+    /// ```
+    pub fn next_at_or_after(&self, t: T) -> Option<T> {
+        if self.upper_bounded_range.contains(&t) {
+            Some(t)
+        } else {
+            self.ranges.next_at_or_after(t)
+        }
+    }
+
+    /// Returns the smallest element of the set which is strictly greater than `t`.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::Rangetools;
+    ///
+    /// let s = (..0).union(5..10);
+    /// assert_eq!(s.next_after(-1), Some(5));
+    /// ```
+    pub fn next_after(&self, t: T) -> Option<T> {
+        Step::forward_checked(t, 1).and_then(|t| self.next_at_or_after(t))
+    }
+
+    /// Returns the largest element of the set which is less than or equal to `t`.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::Rangetools;
+    ///
+    /// let s = (..0).union(5..10);
+    /// assert_eq!(s.prev_at_or_before(42), Some(9));
+    /// assert_eq!(s.prev_at_or_before(3), Some(-1));
+    /// ```
+    pub fn prev_at_or_before(&self, t: T) -> Option<T> {
+        self.ranges.prev_at_or_before(t.clone()).or_else(|| {
+            if self.upper_bounded_range.contains(&t) {
+                Some(t)
+            } else {
+                self.upper_bounded_range.last()
+            }
+        })
+    }
+
+    /// Returns the largest element of the set which is strictly less than `t`.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::Rangetools;
+    ///
+    /// let s = (..0).union(5..10);
+    /// assert_eq!(s.prev_before(5), Some(-1));
+    /// ```
+    pub fn prev_before(&self, t: T) -> Option<T> {
+        Step::backward_checked(t, 1).and_then(|t| self.prev_at_or_before(t))
+    }
+
+    /// Returns the set in canonical form: every range has its bounds included, ranges without
+    /// elements are dropped, and ranges with no elements between them are merged.
+    ///
+    /// Two normalized sets are equal exactly when they contain the same elements.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::Rangetools;
+    ///
+    /// let s = (..=2).union(3..=5).union(8..10);
+    /// assert_eq!(s.normalize(), (..=5).union(8..=9));
+    /// ```
+    pub fn normalize(self) -> Self {
+        let mut ranges = self.ranges.normalize();
+        let mut upper_bounded_range = self.upper_bounded_range.normalize();
+        while let Some(range) = ranges.ranges.front() {
+            if !BoundedRange::is_contiguous(&upper_bounded_range.end, &range.start) {
+                break;
+            }
+            let range = ranges.ranges_mut().pop_front().unwrap();
+            if range.end > upper_bounded_range.end {
+                upper_bounded_range.end = range.end;
+            }
+        }
+        Self {
+            upper_bounded_range,
+            ranges,
+        }
+    }
+}
+
+impl<T: Element + Domain> UpperBoundedSet<T> {
+    /// Returns true if the set contains every value of `T`.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::Rangetools;
+    ///
+    /// assert!((..=10u8).union(11..=255).is_full());
+    /// assert!(!(..=10u8).union(11..255).is_full());
+    /// ```
+    pub fn is_full(&self) -> bool {
+        self.clone().complement_in_domain().ranges.is_empty()
+    }
+
+    /// Returns the values of `T` not contained in the set, as a normalized set.
+    ///
+    /// Unlike [`complement`](crate::Rangetools::complement), the result is bounded above by
+    /// `T::MAX`.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::Rangetools;
+    ///
+    /// let c = (..10u8).union(20..=250).complement_in_domain();
+    /// assert_eq!(c, (10..=19).union(251..=255));
+    /// ```
+    pub fn complement_in_domain(self) -> BoundedSet<T> {
+        let complement: LowerBoundedSet<T> = self.complement();
+        domain().intersection(complement).normalize()
+    }
+
+    /// Returns the set as a normalized set over the values of `T`, treating it as though it
+    /// started at `T::MIN`.
+    ///
+    /// Sets over the same domain compare equal exactly when they contain the same elements,
+    /// whatever the range or set type they were built from.
+    ///
+    /// # Example
+    /// ```
+    /// use rangetools::Rangetools;
+    ///
+    /// let s = (..=5u8).union(250..=255);
+    /// assert_eq!(s.normalize_in_domain(), (0..=5).union(250..=255));
+    /// ```
+    pub fn normalize_in_domain(self) -> BoundedSet<T> {
+        domain().intersection(self).normalize()
+    }
 }